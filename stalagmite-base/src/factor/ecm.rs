@@ -0,0 +1,214 @@
+use crate::factor::prime_cache::get_nth_prime_using_cache;
+use crate::is_prime::is_prime;
+use malachite::Natural;
+use malachite::base::num::arithmetic::traits::{
+    Gcd, ModAdd, ModInverse, ModMul, ModSquare, ModSub, Parity,
+};
+use malachite::base::num::logic::traits::BitIterable;
+
+// Number of curves tried by `ecm` before giving up.
+const ECM_CURVES: usize = 64;
+
+// Seed used by `ecm` for curve selection.
+const ECM_DEFAULT_SEED: u64 = 0x9E3779B97F4A7C15;
+
+// A point on a Montgomery curve By^2 = x^3 + Ax^2 + x in projective (X : Z)
+// coordinates. The y coordinate is not needed for the ladder.
+#[derive(Debug, Clone)]
+struct Point {
+    x: Natural,
+    z: Natural,
+}
+
+// A Montgomery curve modulo n, represented by (A + 2)/4.
+struct Curve<'a> {
+    n: &'a Natural,
+    a24: Natural,
+}
+
+impl Curve<'_> {
+    // Return 2P.
+    fn double(&self, p: &Point) -> Point {
+        let n = self.n;
+        let sum = (&p.x).mod_add(&p.z, n).mod_square(n);
+        let diff = (&p.x).mod_sub(&p.z, n).mod_square(n);
+        let t = (&sum).mod_sub(&diff, n);
+        Point {
+            x: (&sum).mod_mul(&diff, n),
+            z: (&t).mod_mul(diff.mod_add((&self.a24).mod_mul(&t, n), n), n),
+        }
+    }
+
+    // Return P + Q given the difference P - Q.
+    fn add(&self, p: &Point, q: &Point, diff: &Point) -> Point {
+        let n = self.n;
+        let u = (&p.x).mod_sub(&p.z, n).mod_mul((&q.x).mod_add(&q.z, n), n);
+        let v = (&p.x).mod_add(&p.z, n).mod_mul((&q.x).mod_sub(&q.z, n), n);
+        Point {
+            x: (&diff.z).mod_mul((&u).mod_add(&v, n).mod_square(n), n),
+            z: (&diff.x).mod_mul(u.mod_sub(v, n).mod_square(n), n),
+        }
+    }
+
+    // Return kP for k >= 1 with the Montgomery ladder.
+    fn mul(&self, p: &Point, k: u64) -> Point {
+        let mut r0 = p.clone();
+        let mut r1 = self.double(p);
+        for bit in Natural::from(k).bits().rev().skip(1) {
+            if bit {
+                r0 = self.add(&r1, &r0, p);
+                r1 = self.double(&r1);
+            } else {
+                r1 = self.add(&r1, &r0, p);
+                r0 = self.double(&r0);
+            }
+        }
+        r0
+    }
+}
+
+// Suyama's parametrization: for sigma > 5 let u = sigma^2 - 5 and v = 4sigma,
+// then the point (u^3 : v^3) lies on the curve with
+// (A + 2)/4 = (v - u)^3 (3u + v) / (16 u^3 v). If 16 u^3 v is not invertible
+// modulo n the gcd is returned as an error instead.
+fn select_curve<'a>(n: &'a Natural, sigma: &Natural) -> Result<(Curve<'a>, Point), Natural> {
+    let sigma = sigma % n;
+    let u = (&sigma).mod_square(n).mod_sub(Natural::from(5u32) % n, n);
+    let v = (&sigma).mod_mul(Natural::from(4u32) % n, n);
+    let u3 = (&u).mod_square(n).mod_mul(&u, n);
+    let v3 = (&v).mod_square(n).mod_mul(&v, n);
+
+    let vu = (&v).mod_sub(&u, n);
+    let num = (&vu).mod_square(n).mod_mul(&vu, n)
+        .mod_mul((&u).mod_mul(Natural::from(3u32) % n, n).mod_add(&v, n), n);
+    let den = (&u3).mod_mul(&v, n).mod_mul(Natural::from(16u32) % n, n);
+
+    match (&den).mod_inverse(n) {
+        Some(inv) => Ok((
+            Curve { n, a24: num.mod_mul(inv, n) },
+            Point { x: u3, z: v3 },
+        )),
+        None => Err(den.gcd(n)),
+    }
+}
+
+/// Run stage 1 of Lenstra's elliptic curve method on the Montgomery curve
+/// selected by `sigma > 5` with Suyama's parametrization. The starting point
+/// is multiplied by every prime power up to `b1`, and a factor is found if the
+/// order of the curve modulo some prime factor of `n` is `b1`-smooth.
+///
+/// Returns `None` if this curve does not reveal a nontrivial factor.
+/// `n` should be odd and composite.
+pub fn ecm_stage_1(n: &Natural, b1: u64, sigma: &Natural) -> Option<Natural> {
+    let (curve, mut point) = match select_curve(n, sigma) {
+        Ok(curve) => curve,
+        Err(g) => return if g != 1u32 && g != *n { Some(g) } else { None },
+    };
+
+    let mut i = 0;
+    loop {
+        let p = get_nth_prime_using_cache(i);
+        if p > b1 {
+            break;
+        }
+        // multiply by the largest power of p not exceeding b1
+        let mut q = p;
+        while q <= b1 / p {
+            q *= p;
+        }
+        point = curve.mul(&point, q);
+        i += 1;
+    }
+
+    let g = point.z.gcd(n);
+    if g != 1u32 && g != *n { Some(g) } else { None }
+}
+
+/// Search for a nontrivial factor of `n` with stage 1 of the elliptic curve
+/// method, trying `curves` curves whose parameters are generated from `seed`.
+/// The same seed always tries the same curves. Returns `None` if `n` is prime
+/// or less than four, or if no curve found a factor.
+pub fn ecm_with_seed(n: &Natural, b1: u64, curves: usize, seed: u64) -> Option<Natural> {
+    if *n < 4u32 || is_prime(n.clone()) {
+        return None;
+    } else if n.even() {
+        return Some(Natural::from(2u32));
+    }
+
+    // splitmix64 sequence of curve parameters
+    let mut state = seed;
+    for _ in 0..curves {
+        state = state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^= z >> 31;
+
+        let sigma = Natural::from(6u32) + Natural::from(z >> 1);
+        if let Some(d) = ecm_stage_1(n, b1, &sigma) {
+            return Some(d);
+        }
+    }
+    None
+}
+
+/// Search for a nontrivial factor of `n` with stage 1 of Lenstra's elliptic
+/// curve method with smoothness bound `b1`. ECM finds factors by their size
+/// rather than the size of `n`, so it is suited to mid-size factors that are
+/// missed by trial division and Pollard rho. Returns `None` if `n` is prime or
+/// less than four, or if no factor was found, in which case a larger `b1`
+/// should be tried.
+///
+/// Curves are chosen from a fixed seed; use [`ecm_with_seed`] to vary them.
+pub fn ecm(n: &Natural, b1: u64) -> Option<Natural> {
+    ecm_with_seed(n, b1, ECM_CURVES, ECM_DEFAULT_SEED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use malachite::base::num::arithmetic::traits::DivisibleBy;
+
+    #[test]
+    fn test_ladder_matches_repeated_addition() {
+        let n = Natural::from(1000003u32);
+        let (curve, p) = select_curve(&n, &Natural::from(11u32)).unwrap();
+        // compare x-coordinates (X/Z) of kP computed by the ladder and by
+        // repeated differential addition
+        let affine = |q: &Point| (&q.x).mod_mul((&q.z).mod_inverse(&n).unwrap(), &n);
+        let mut prev = p.clone();
+        let mut cur = curve.double(&p);
+        for k in 2..40u64 {
+            assert_eq!(affine(&curve.mul(&p, k)), affine(&cur), "{}", k);
+            let next = curve.add(&cur, &p, &prev);
+            prev = cur;
+            cur = next;
+        }
+        assert_eq!(affine(&curve.mul(&p, 1)), affine(&p));
+    }
+
+    #[test]
+    fn test_ecm_semiprime() {
+        // 40-bit semiprime
+        let p = Natural::from(1000003u32);
+        let q = Natural::from(1000033u32);
+        let n = &p * &q;
+        let d = ecm(&n, 2000).unwrap();
+        assert!(d == p || d == q);
+    }
+
+    #[test]
+    fn test_ecm_with_seed_reproducible() {
+        let n = Natural::from(1000003u32) * Natural::from(4294967291u64);
+        let d = ecm_with_seed(&n, 5000, 200, 42).unwrap();
+        assert!((&n).divisible_by(&d) && d != 1u32 && d != n);
+        assert_eq!(ecm_with_seed(&n, 5000, 200, 42), Some(d));
+    }
+
+    #[test]
+    fn test_ecm_trivial() {
+        assert_eq!(ecm(&Natural::from(1000003u32), 1000), None);
+        assert_eq!(ecm(&Natural::from(3u32), 1000), None);
+        assert_eq!(ecm(&Natural::from(1000u32), 1000), Some(Natural::from(2u32)));
+    }
+}
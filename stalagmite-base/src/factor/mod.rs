@@ -1,10 +1,22 @@
 use crate::factor::trial_division::trial_range::factor_trial_range;
-use crate::factored::FactoredNatural;
+use crate::factored::{FactoredNatural, FactoredZZElem};
+use crate::integer::ZZElem;
+use crate::is_prime::is_prime;
+use crate::natural::is_perfect_power;
 
 use malachite::Natural;
+use malachite::base::num::arithmetic::traits::{DivExact, Gcd, Parity, Square};
+use malachite::base::num::basic::traits::One;
+use std::cmp::min;
+
+pub mod ecm;
 pub mod prime_cache;
+pub mod small;
 pub mod trial_division;
 
+pub use ecm::ecm;
+pub use small::factor_small_integer;
+
 // Redefine malachite::base::num::factorization::traits::Factor
 pub trait Factor {
     type FACTORS;
@@ -15,25 +27,155 @@ pub trait Factor {
 impl Factor for Natural {
     type FACTORS = FactoredNatural;
 
+    /// Return the complete prime factorization of `self`, which is left
+    /// unchanged. Panics if `self` is zero.
     fn factor(&mut self) -> FactoredNatural {
         // FLINT does this in batches of 1000. If trial division finds a factor
         // it continues to the next 1000, otherwise switch to non-trial factoring.
-        let mut factors = factor_trial_range(self, 0, 3512);
-
-        // factor_no_trial:
-        // 1. check if prime
-        // 2. check if perfect power
-        //     - is_perfect_power modifies n in place
-        //     - call factor_no_trial on base (may not be prime)
-        // 3. factor smooth (ECM)
-        // 4. check if remaining cofactor is perfect power (FLINT qsieve cant factor perfect powers)
-        //     - if so, continue
-        //     - otherwise call qsieve
-        // 5. call factor_no_trial on each FACTOR, since they might not be prime
-
-        //factors *= factor_no_trial(n);
-        factors.unwrap()
+        let mut cofactor = self.clone();
+        let mut factors = factor_trial_range(&mut cofactor, 0, 3512)
+            .expect("Cannot factor zero");
+
+        // TODO: factor smooth (ECM) and qsieve for cofactors with only large
+        // prime factors.
+        factors *= factor_no_trial(cofactor);
+        factors
+    }
+}
+
+impl Factor for ZZElem {
+    type FACTORS = FactoredZZElem;
+
+    /// Return the complete prime factorization of `self`, which is left
+    /// unchanged. Negative values get the factor `-1` with exponent one, so
+    /// `-1` factors as just `-1` and `1` has the empty factorization. Panics
+    /// if `self` is zero.
+    fn factor(&mut self) -> FactoredZZElem {
+        if *self == 0 {
+            panic!("Cannot factor zero");
+        }
+
+        let mut factors = FactoredZZElem::new();
+        if *self < 0 {
+            factors.insert(ZZElem::from(-1), 1);
+        }
+        if *self == 1 || *self == -1 {
+            return factors;
+        }
+        for (p, exp) in self.unsigned_abs_ref().clone().factor().factors {
+            factors.insert(ZZElem::from(p), exp);
+        }
+        factors
+    }
+}
+
+// Factor n, assuming small prime factors have already been removed by trial
+// division:
+// 1. check if prime
+// 2. check if perfect power, then factor the base (which may not be prime)
+// 3. split n with Pollard-Brent and factor both parts, since they might not
+//    be prime
+fn factor_no_trial(n: Natural) -> FactoredNatural {
+    let mut factors = FactoredNatural::new();
+    if n == 1u32 {
+        return factors;
+    }
+
+    if is_prime(n.clone()) {
+        factors.insert(n, 1);
+        return factors;
+    }
+
+    if let Some((base, exp)) = is_perfect_power(&n) {
+        for (p, e) in factor_no_trial(base).factors {
+            factors.insert(p, e * exp as u64);
+        }
+        return factors;
     }
+
+    let mut c = 1u64;
+    let d = loop {
+        if let Some(d) = factor_pollard_brent_single(&n, &Natural::from(c)) {
+            break d;
+        }
+        c += 1;
+    };
+    let cofactor = (&n).div_exact(&d);
+    factors *= factor_no_trial(d);
+    factors *= factor_no_trial(cofactor);
+    factors
+}
+
+// Number of iteration constants c tried by `pollard_brent` before giving up.
+const POLLARD_BRENT_TRIES: u64 = 16;
+
+/// Find a nontrivial factor of `n` using Brent's variant of Pollard rho with
+/// batched gcds, trying the iterations `x -> x^2 + c` for several `c`.
+/// Returns `None` if `n` is prime or less than four, or if every `c` failed,
+/// in which case a larger search is needed.
+///
+/// The factor found is not necessarily prime, and rho finds small factors
+/// much faster than large ones.
+pub fn pollard_brent(n: &Natural) -> Option<Natural> {
+    if *n < 4u32 || is_prime(n.clone()) {
+        return None;
+    } else if n.even() {
+        return Some(Natural::from(2u32));
+    }
+    (1..=POLLARD_BRENT_TRIES).find_map(|c| factor_pollard_brent_single(n, &Natural::from(c)))
+}
+
+// Find a nontrivial factor of the composite n using Brent's variant of
+// Pollard rho with the iteration x -> x^2 + c. Returns None if the cycle
+// closes without finding a factor, in which case another c should be tried.
+fn factor_pollard_brent_single(n: &Natural, c: &Natural) -> Option<Natural> {
+    // number of steps between gcd computations
+    const BATCH: u64 = 128;
+    const MAX_ITERS: u64 = 1 << 24;
+
+    let f = |y: &Natural| ((y.square()) + c) % n;
+    let diff = |a: &Natural, b: &Natural| if a > b { a - b } else { b - a };
+
+    let mut y = Natural::from(2u32);
+    let mut x = y.clone();
+    let mut ys = y.clone();
+    let mut q = Natural::ONE;
+    let mut g = Natural::ONE;
+    let mut r = 1u64;
+
+    while g == 1u32 {
+        x = y.clone();
+        for _ in 0..r {
+            y = f(&y);
+        }
+        let mut k = 0;
+        while k < r && g == 1u32 {
+            ys = y.clone();
+            for _ in 0..min(BATCH, r - k) {
+                y = f(&y);
+                q = (q * diff(&x, &y)) % n;
+            }
+            g = (&q).gcd(n);
+            k += BATCH;
+        }
+        r <<= 1;
+        if r > MAX_ITERS {
+            return None;
+        }
+    }
+
+    // the batched gcd overshot, backtrack one step at a time
+    if g == *n {
+        loop {
+            ys = f(&ys);
+            g = diff(&x, &ys).gcd(n);
+            if g != 1u32 {
+                break;
+            }
+        }
+    }
+
+    if g == *n { None } else { Some(g) }
 }
 
 /*
@@ -47,15 +189,119 @@ fn factor_smooth() {}
 fn factor_pp1() {}
 fn factor_refine() {}
 
-fn factor_pollard_brent_single() {}
-fn factor_pollard_brent() {}
-
-fn factor_ecm_double() {}
-fn factor_ecm_add() {}
-fn factor_ecm_mul_montogomery_ladder() {}
-fn factor_ecm_select_curve() {}
-fn factor_ecm_stage_1() {}
 fn factor_ecm_stage_2() {}
-fn factor_ecm() {}
 
 */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factored::Eval;
+    use malachite::base::num::arithmetic::traits::Pow;
+
+    fn mersenne(p: u64) -> Natural {
+        (Natural::ONE << p) - Natural::ONE
+    }
+
+    #[test]
+    fn test_factor_semiprime() {
+        let factors = Natural::from(10403u32).factor();
+        assert_eq!(factors.len(), 2);
+        assert_eq!(factors.get(&Natural::from(101u32)), Some(&1));
+        assert_eq!(factors.get(&Natural::from(103u32)), Some(&1));
+    }
+
+    #[test]
+    fn test_factor_prime_power() {
+        let factors = Natural::from(3u32).pow(7).factor();
+        assert_eq!(factors.len(), 1);
+        assert_eq!(factors.get(&Natural::from(3u32)), Some(&7));
+
+        // large prime power, found by the perfect power check
+        let p = mersenne(61);
+        let factors = (&p).pow(3).factor();
+        assert_eq!(factors.len(), 1);
+        assert_eq!(factors.get(&p), Some(&3));
+    }
+
+    #[test]
+    fn test_factor_large_cofactor() {
+        // prime factors beyond the trial division range, found by Pollard rho
+        let p = mersenne(61);
+        let n = &p * Natural::from(1000003u32) * Natural::from(1000033u32) * Natural::from(12u32);
+        let factors = n.clone().factor();
+        assert_eq!(factors.len(), 5);
+        assert_eq!(factors.get(&Natural::from(2u32)), Some(&2));
+        assert_eq!(factors.get(&Natural::from(3u32)), Some(&1));
+        assert_eq!(factors.get(&Natural::from(1000003u32)), Some(&1));
+        assert_eq!(factors.get(&Natural::from(1000033u32)), Some(&1));
+        assert_eq!(factors.get(&p), Some(&1));
+        assert_eq!(factors.eval(), n);
+    }
+
+    #[test]
+    fn test_factor_integer() {
+        let factors = ZZElem::from(-360).factor();
+        assert_eq!(factors.len(), 4);
+        assert_eq!(factors.get(&ZZElem::from(-1)), Some(&1));
+        assert_eq!(factors.get(&ZZElem::from(2)), Some(&3));
+        assert_eq!(factors.get(&ZZElem::from(3)), Some(&2));
+        assert_eq!(factors.get(&ZZElem::from(5)), Some(&1));
+        assert_eq!(factors.expand(), ZZElem::from(-360));
+
+        let factors = ZZElem::from(360).factor();
+        assert_eq!(factors.len(), 3);
+        assert_eq!(factors.get(&ZZElem::from(-1)), None);
+        assert_eq!(factors.eval(), ZZElem::from(360));
+    }
+
+    #[test]
+    fn test_factor_integer_units() {
+        assert!(ZZElem::from(1).factor().is_empty());
+        let factors = ZZElem::from(-1).factor();
+        assert_eq!(factors.len(), 1);
+        assert_eq!(factors.expand(), ZZElem::from(-1));
+
+        let factors = ZZElem::from(-7).factor();
+        assert_eq!(factors.len(), 2);
+        assert_eq!(factors.get(&ZZElem::from(7)), Some(&1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot factor zero")]
+    fn test_factor_integer_zero() {
+        ZZElem::from(0).factor();
+    }
+
+    #[test]
+    fn test_pollard_brent() {
+        let n = Natural::from(10403u32);
+        let d = pollard_brent(&n).unwrap();
+        assert!(d == 101u32 || d == 103u32);
+        assert_eq!(&d * (&n).div_exact(&d), n);
+
+        let p = mersenne(31);
+        let q = mersenne(61);
+        let n = &p * &q;
+        let d = pollard_brent(&n).unwrap();
+        assert!(d == p || d == q);
+
+        assert_eq!(pollard_brent(&Natural::from(1000u32)), Some(Natural::from(2u32)));
+        assert_eq!(pollard_brent(&Natural::from(49u32)), Some(Natural::from(7u32)));
+    }
+
+    #[test]
+    fn test_pollard_brent_prime() {
+        assert_eq!(pollard_brent(&Natural::from(3u32)), None);
+        assert_eq!(pollard_brent(&Natural::from(1000003u32)), None);
+        assert_eq!(pollard_brent(&mersenne(61)), None);
+        assert_eq!(pollard_brent(&Natural::ONE), None);
+    }
+
+    #[test]
+    fn test_factor_small() {
+        assert!(Natural::from(1u32).factor().is_empty());
+        let factors = Natural::from(2u32).factor();
+        assert_eq!(factors.get(&Natural::from(2u32)), Some(&1));
+    }
+}
@@ -5,6 +5,9 @@ use std::sync::{OnceLock, RwLock};
 // Initialize with small primes
 pub static PRIME_CACHE: OnceLock<RwLock<Vec<u64>>> = OnceLock::new();
 
+// Length of each block of numbers sieved when extending the cache.
+const SIEVE_SEGMENT_SIZE: u64 = 1 << 16;
+
 pub fn get_prime_cache() -> &'static RwLock<Vec<u64>> {
     PRIME_CACHE.get_or_init(|| {
         RwLock::new(vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]) // Start with first 10 primes
@@ -30,14 +33,73 @@ pub fn ensure_primes_computed(count: usize) {
     extend_cache_to(&mut cache, count);
 }
 
+/// Extend a cache of consecutive primes to `target_size` primes with a
+/// segmented sieve of Eratosthenes.
 pub fn extend_cache_to(cache: &mut Vec<u64>, target_size: usize) {
-    let mut candidate = cache.last().unwrap() + 2; // Start from next odd number
-
+    let mut lo = cache.last().unwrap() + 1;
     while cache.len() < target_size {
-        if is_prime_using_cache(candidate, cache) {
-            cache.push(candidate);
+        // Size the segment from the prime number theorem so that small
+        // extensions don't sieve a whole block. A segment may contain no
+        // primes at all, so continue from its end rather than the last prime.
+        let last = *cache.last().unwrap();
+        let needed = (target_size - cache.len()) as u64;
+        let estimate = needed * (64 - lo.leading_zeros() as u64);
+        let hi = (lo + estimate.clamp(64, SIEVE_SEGMENT_SIZE)).min(last.saturating_mul(last));
+        sieve_segment(cache, lo, hi);
+        lo = hi;
+    }
+    cache.truncate(target_size);
+}
+
+/// Ensure every prime below `limit` is in the cache.
+pub fn ensure_primes_below(limit: u64) {
+    let cache = get_prime_cache().read().unwrap();
+    if cache.last().unwrap() + 1 >= limit {
+        return; // Already have enough
+    }
+    drop(cache);
+
+    let mut cache = get_prime_cache().write().unwrap();
+
+    // Double-check after acquiring write lock
+    if cache.last().unwrap() + 1 >= limit {
+        return;
+    }
+
+    extend_cache_below(&mut cache, limit);
+}
+
+/// Extend a cache of consecutive primes with all primes below `limit`,
+/// sieving segments of at most `SIEVE_SEGMENT_SIZE` numbers.
+pub fn extend_cache_below(cache: &mut Vec<u64>, limit: u64) {
+    let mut lo = cache.last().unwrap() + 1;
+    while lo < limit {
+        // A segment is sieved by the cached primes, so it may not extend
+        // past the square of the largest one.
+        let last = *cache.last().unwrap();
+        let hi = limit.min(lo + SIEVE_SEGMENT_SIZE).min(last.saturating_mul(last));
+        sieve_segment(cache, lo, hi);
+        lo = hi;
+    }
+}
+
+// Append the primes in lo..hi to the cache with a sieve of Eratosthenes. The
+// cache must hold every prime below lo, and below sqrt(hi) in particular.
+fn sieve_segment(cache: &mut Vec<u64>, lo: u64, hi: u64) {
+    let mut composite = vec![false; (hi - lo) as usize];
+    for &p in cache.iter() {
+        if p.saturating_mul(p) >= hi {
+            break;
+        }
+        let start = (p * p).max(lo.div_ceil(p) * p);
+        for m in (start..hi).step_by(p as usize) {
+            composite[(m - lo) as usize] = true;
+        }
+    }
+    for (i, &c) in composite.iter().enumerate() {
+        if !c && lo + i as u64 >= 2 {
+            cache.push(lo + i as u64);
         }
-        candidate += 2; // Only check odd numbers
     }
 }
 
@@ -46,7 +108,7 @@ pub fn is_prime_using_cache(n: u64, cache: &[u64]) -> bool {
         return false;
     }
     if n % 2 == 0 {
-        return false;
+        return n == 2;
     }
 
     let sqrt_n = (n as f64).sqrt() as u64;
@@ -78,6 +140,15 @@ pub fn get_primes_using_cache<const N: usize>(start: usize, num_primes: usize) -
     res
 }
 
+/// Return all primes strictly less than `limit`, extending the cache if
+/// necessary.
+pub fn primes_below(limit: u64) -> Vec<u64> {
+    ensure_primes_below(limit);
+    let cache = get_prime_cache().read().unwrap();
+    let end = cache.partition_point(|&p| p < limit);
+    cache[..end].to_vec()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,8 +202,7 @@ mod tests {
     fn test_is_prime_using_cache() {
         let cache = get_prime_cache().read().unwrap();
 
-        // Note: is_prime_using_cache returns false for 2 because it checks n % 2 == 0
-        assert!(!is_prime_using_cache(2, &cache));
+        assert!(is_prime_using_cache(2, &cache));
         assert!(is_prime_using_cache(3, &cache));
         assert!(is_prime_using_cache(5, &cache));
         assert!(is_prime_using_cache(31, &cache));
@@ -147,6 +217,16 @@ mod tests {
         assert!(!is_prime_using_cache(1, &cache));
     }
 
+    #[test]
+    fn test_is_prime_using_cache_regressions() {
+        let cache = get_prime_cache().read().unwrap();
+        assert!(is_prime_using_cache(2, &cache));
+        assert!(is_prime_using_cache(3, &cache));
+        assert!(!is_prime_using_cache(4, &cache));
+        assert!(is_prime_using_cache(97, &cache));
+        assert!(!is_prime_using_cache(98, &cache));
+    }
+
     #[test]
     fn test_extend_cache_to() {
         let mut test_cache = vec![2, 3, 5, 7, 11];
@@ -158,6 +238,18 @@ mod tests {
         assert_eq!(test_cache[7], 19);
         assert_eq!(test_cache[8], 23);
         assert_eq!(test_cache[9], 29);
+
+        let mut test_cache = vec![2];
+        extend_cache_to(&mut test_cache, 1000);
+        assert_eq!(test_cache.len(), 1000);
+        assert_eq!(test_cache[999], 7919);
+        assert!(test_cache.iter().all(|&p| is_prime_using_cache(p, &test_cache[..168])));
+
+        // 31397 is followed by a gap of 72, wider than the smallest segment
+        let mut test_cache = vec![2];
+        extend_cache_to(&mut test_cache, 3512);
+        assert_eq!(test_cache[3384], 31397);
+        assert_eq!(test_cache[3385], 31469);
     }
 
     #[test]
@@ -189,4 +281,35 @@ mod tests {
         assert_eq!(cache[49], 229);
         assert_eq!(cache[99], 541);
     }
+
+    #[test]
+    fn test_primes_below() {
+        assert_eq!(primes_below(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(primes_below(29), vec![2, 3, 5, 7, 11, 13, 17, 19, 23]);
+        assert_eq!(primes_below(3), vec![2]);
+        assert!(primes_below(2).is_empty());
+        assert!(primes_below(0).is_empty());
+
+        let primes = primes_below(200_000);
+        assert_eq!(primes.len(), 17984);
+        assert_eq!(*primes.last().unwrap(), 199999);
+        assert!(primes.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_extend_cache_below() {
+        let mut test_cache = vec![2, 3];
+        extend_cache_below(&mut test_cache, 100);
+        assert_eq!(test_cache.len(), 25);
+        assert_eq!(test_cache[24], 97);
+
+        // no primes between 114 and 126
+        let mut test_cache = test_cache.clone();
+        extend_cache_below(&mut test_cache, 114);
+        let len = test_cache.len();
+        extend_cache_below(&mut test_cache, 127);
+        assert_eq!(test_cache.len(), len);
+        extend_cache_below(&mut test_cache, 128);
+        assert_eq!(*test_cache.last().unwrap(), 127);
+    }
 }
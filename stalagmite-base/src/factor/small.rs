@@ -0,0 +1,198 @@
+use crate::factor::prime_cache::{ensure_primes_computed, get_prime_cache};
+use crate::factored::FactoredNatural;
+use malachite::Natural;
+use malachite::base::num::arithmetic::traits::{CheckedSqrt, Gcd};
+use malachite::base::num::factorization::traits::IsPrime;
+use std::cmp::min;
+
+// Number of primes tried by trial division before switching to Pollard rho,
+// covering the primes below 1000.
+const SMALL_TRIAL_PRIMES: usize = 168;
+
+/// Return the complete prime factorization of a word-sized `n`, using
+/// trial division by the small primes and then Brent's variant of Pollard
+/// rho on the cofactor, all in machine arithmetic. Panics if `n` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_base::factor::factor_small_integer;
+/// use malachite::Natural;
+///
+/// // 2^32 - 1 = 3 * 5 * 17 * 257 * 65537
+/// let factors = factor_small_integer(u32::MAX as u64);
+/// assert_eq!(factors.len(), 5);
+/// assert_eq!(factors.get(&Natural::from(65537u32)), Some(&1));
+/// ```
+pub fn factor_small_integer(mut n: u64) -> FactoredNatural {
+    if n == 0 {
+        panic!("Cannot factor zero");
+    }
+
+    let mut factors = FactoredNatural::new();
+    let twos = n.trailing_zeros();
+    if twos != 0 {
+        factors.insert(Natural::from(2u32), twos as u64);
+        n >>= twos;
+    }
+
+    ensure_primes_computed(SMALL_TRIAL_PRIMES);
+    let cache = get_prime_cache().read().unwrap();
+    let mut bound = 2;
+    for &p in &cache[1..SMALL_TRIAL_PRIMES] {
+        bound = p;
+        if p * p > n {
+            break;
+        }
+        if n.is_multiple_of(p) {
+            let mut exp = 0;
+            while n.is_multiple_of(p) {
+                n /= p;
+                exp += 1;
+            }
+            factors.insert(Natural::from(p), exp);
+        }
+    }
+    drop(cache);
+
+    // A cofactor with no factor up to the bound is prime below bound^2
+    if n != 1 && n < bound * bound {
+        factors.insert(Natural::from(n), 1);
+    } else {
+        factor_small_no_trial(n, 1, &mut factors);
+    }
+    factors
+}
+
+// Multiply factors by n^exp, where n has no small prime factors.
+fn factor_small_no_trial(n: u64, exp: u64, factors: &mut FactoredNatural) {
+    if n == 1 {
+        return;
+    }
+
+    if n.is_prime() {
+        *factors.entry(Natural::from(n)).or_insert(0) += exp;
+        return;
+    }
+
+    // rho is slow to split squares of primes
+    if let Some(root) = n.checked_sqrt() {
+        factor_small_no_trial(root, 2 * exp, factors);
+        return;
+    }
+
+    let d = (1..).find_map(|c| pollard_brent_small(n, c)).unwrap();
+    factor_small_no_trial(d, exp, factors);
+    factor_small_no_trial(n / d, exp, factors);
+}
+
+#[inline]
+fn mul_mod(a: u64, b: u64, n: u64) -> u64 {
+    ((a as u128 * b as u128) % n as u128) as u64
+}
+
+// Find a nontrivial factor of the odd composite n using Brent's variant of
+// Pollard rho with the iteration x -> x^2 + c, as in `pollard_brent`. Returns
+// None if the cycle closes without finding a factor.
+fn pollard_brent_small(n: u64, c: u64) -> Option<u64> {
+    // number of steps between gcd computations
+    const BATCH: u64 = 128;
+    const MAX_ITERS: u64 = 1 << 24;
+
+    let f = |y: u64| ((mul_mod(y, y, n) as u128 + c as u128) % n as u128) as u64;
+
+    let mut y = 2;
+    let mut x;
+    let mut ys = y;
+    let mut q = 1;
+    let mut g = 1;
+    let mut r = 1u64;
+
+    loop {
+        x = y;
+        for _ in 0..r {
+            y = f(y);
+        }
+        let mut k = 0;
+        while k < r && g == 1 {
+            ys = y;
+            for _ in 0..min(BATCH, r - k) {
+                y = f(y);
+                q = mul_mod(q, x.abs_diff(y), n);
+            }
+            g = q.gcd(n);
+            k += BATCH;
+        }
+        r <<= 1;
+        if g != 1 {
+            break;
+        } else if r > MAX_ITERS {
+            return None;
+        }
+    }
+
+    // the batched gcd overshot, backtrack one step at a time
+    if g == n {
+        loop {
+            ys = f(ys);
+            g = x.abs_diff(ys).gcd(n);
+            if g != 1 {
+                break;
+            }
+        }
+    }
+
+    if g == n { None } else { Some(g) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::factor_no_trial;
+    use crate::factored::Eval;
+
+    #[test]
+    fn test_factor_small_integer_near_u32_max() {
+        let max = u32::MAX as u64;
+        for n in (max - 200)..=(max + 200) {
+            let factors = factor_small_integer(n);
+            assert_eq!(factors.clone().eval(), Natural::from(n));
+            assert_eq!(factors.factors, factor_no_trial(Natural::from(n)).factors, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_factor_small_integer() {
+        assert!(factor_small_integer(1).is_empty());
+        assert_eq!(factor_small_integer(2).get(&Natural::from(2u32)), Some(&1));
+        assert_eq!(factor_small_integer(1 << 63).get(&Natural::from(2u32)), Some(&63));
+
+        // semiprimes and squares of primes beyond the trial division bound
+        let p = 4294967291u64;
+        let q = 4294967279u64;
+        let factors = factor_small_integer(p * q);
+        assert_eq!(factors.len(), 2);
+        assert_eq!(factors.get(&Natural::from(p)), Some(&1));
+        assert_eq!(factors.get(&Natural::from(q)), Some(&1));
+        let factors = factor_small_integer(p * p);
+        assert_eq!(factors.len(), 1);
+        assert_eq!(factors.get(&Natural::from(p)), Some(&2));
+
+        // 1009^3 * 1013 has a repeated factor found by rho
+        let factors = factor_small_integer(1009 * 1009 * 1009 * 1013);
+        assert_eq!(factors.get(&Natural::from(1009u32)), Some(&3));
+        assert_eq!(factors.get(&Natural::from(1013u32)), Some(&1));
+
+        for n in (u64::MAX - 100)..=u64::MAX {
+            let factors = factor_small_integer(n);
+            assert_eq!(factors.clone().eval(), Natural::from(n));
+            assert!(factors.keys().all(|p| crate::is_prime::is_prime(p.clone())));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot factor zero")]
+    fn test_factor_small_integer_zero() {
+        factor_small_integer(0);
+    }
+}
@@ -46,7 +46,6 @@ pub fn factor_trial(n: &mut Natural, num_primes: usize) -> Option<FactoredNatura
     // gcd tree will have expanded the prime cache up to num_primes
     let cache = get_prime_cache().read().unwrap();
 
-    println!("{n} {found:?}");
     // remove powers of p for each p found using the gcd tree
     for i in found {
         // don't need to ensure the cache is expanded, so we access it directly
@@ -136,51 +135,49 @@ mod tests {
     use super::*;
     use malachite::base::num::basic::traits::{One, Zero};
 
-    #[test]
-    fn test_factor_trial_small_primes() {
-        assert_eq!(factor_trial(&ZZElem::from(2), 0, 5), Some(0)); // 2 is prime at index 0
-        assert_eq!(factor_trial(&ZZElem::from(3), 0, 5), Some(1)); // 3 is prime at index 1
-        assert_eq!(factor_trial(&ZZElem::from(5), 0, 5), Some(2)); // 5 is prime at index 2
-        assert_eq!(factor_trial(&ZZElem::from(7), 0, 5), Some(3)); // 7 is prime at index 3
+    fn mersenne(p: u64) -> Natural {
+        (Natural::ONE << p) - Natural::ONE
     }
 
     #[test]
-    fn test_factor_trial_composite_numbers() {
-        assert_eq!(factor_trial(&ZZElem::from(4), 0, 10), Some(0)); // 4 = 2^2
-        assert_eq!(factor_trial(&ZZElem::from(6), 0, 10), Some(0)); // 6 = 2 * 3
-        assert_eq!(factor_trial(&ZZElem::from(9), 0, 10), Some(1)); // 9 = 3^2
-        assert_eq!(factor_trial(&ZZElem::from(15), 0, 10), Some(1)); // 15 = 3 * 5
-        assert_eq!(factor_trial(&ZZElem::from(25), 0, 10), Some(2)); // 25 = 5^2
-    }
-
-    #[test]
-    fn test_factor_trial_special_cases() {
-        assert_eq!(factor_trial(&ZZElem::ZERO, 0, 5), Some(0)); // 0 is divisible by any prime
-        assert_eq!(factor_trial(&ZZElem::ONE, 0, 5), None); // 1 has no prime factors
-    }
+    fn test_factor_trial_small() {
+        for (n, p, exp) in [(2u32, 2u32, 1u64), (7, 7, 1), (4, 2, 2), (9, 3, 2), (25, 5, 2)] {
+            let factors = factor_trial(&mut Natural::from(n), 10).unwrap();
+            assert_eq!(factors.len(), 1);
+            assert_eq!(factors.get(&Natural::from(p)), Some(&exp));
+        }
 
-    #[test]
-    fn test_factor_trial_negative_numbers() {
-        assert_eq!(factor_trial(&ZZElem::from(-6), 0, 10), Some(0)); // -6 = -1 * 2 * 3
-        assert_eq!(factor_trial(&ZZElem::from(-15), 0, 10), Some(1)); // -15 = -1 * 3 * 5
+        let factors = factor_trial(&mut Natural::from(15u32), 10).unwrap();
+        assert_eq!(factors.get(&Natural::from(3u32)), Some(&1));
+        assert_eq!(factors.get(&Natural::from(5u32)), Some(&1));
     }
 
     #[test]
-    fn test_factor_trial_range_limits() {
-        assert_eq!(factor_trial(&ZZElem::from(6), 1, 5), Some(1)); // Skip 2, find 3 at index 1
-        assert_eq!(factor_trial(&ZZElem::from(10), 2, 3), Some(2)); // Only check index 2 (prime 5)
+    fn test_factor_trial_special_cases() {
+        let mut n = Natural::ZERO;
+        assert!(factor_trial(&mut n, 5).is_none());
+        let mut n = Natural::ONE;
+        assert!(factor_trial(&mut n, 5).unwrap().is_empty());
     }
 
     #[test]
-    fn test_factor_trial_invalid_range() {
-        assert_eq!(factor_trial(&ZZElem::from(6), 5, 3), None); // start >= stop
-        assert_eq!(factor_trial(&ZZElem::from(6), 5, 5), None); // start == stop
+    fn test_factor_trial_large() {
+        // 360 = 2^3 * 3^2 * 5, the large prime is left as cofactor
+        let mut n = Natural::from(360u32) * mersenne(127);
+        let factors = factor_trial(&mut n, 10).unwrap();
+        assert_eq!(factors.len(), 3);
+        assert_eq!(factors.get(&Natural::from(2u32)), Some(&3));
+        assert_eq!(factors.get(&Natural::from(3u32)), Some(&2));
+        assert_eq!(factors.get(&Natural::from(5u32)), Some(&1));
+        assert_eq!(n, mersenne(127));
     }
 
     #[test]
     fn test_factor_trial_no_factors_in_range() {
-        // Test with prime numbers larger than our range
-        assert_eq!(factor_trial(&ZZElem::from(31), 0, 10), None); // 31 is prime, not in first 10 primes
-        assert_eq!(factor_trial(&ZZElem::from(37), 0, 10), None); // 37 is prime, not in first 10 primes
+        // 31 * (2^89 - 1) has no prime factors among the first 10 primes
+        let mut n = Natural::from(31u32) * mersenne(89);
+        let factors = factor_trial(&mut n, 10).unwrap();
+        assert!(factors.is_empty());
+        assert_eq!(n, Natural::from(31u32) * mersenne(89));
     }
 }
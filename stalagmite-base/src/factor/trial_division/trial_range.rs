@@ -1,15 +1,19 @@
 use crate::factor::prime_cache::get_nth_prime_using_cache;
+use crate::factor::small::factor_small_integer;
 use crate::factored::FactoredNatural;
+use crate::natural::is_small;
 use malachite::Natural;
 use malachite::base::num::arithmetic::traits::{
     DivExactAssign, DivisibleBy, Square,
 };
-use malachite::base::num::basic::traits::Two;
-use malachite::base::num::factorization::traits::Factor;
+use malachite::base::num::basic::traits::{One, Two};
 use std::cmp::{max, min};
 
 /// Factors a ZZElem using trial division within a specified prime range.
 /// Returns the (potentially partial) factorization and remaining cofactor.
+///
+/// Inputs that fit in a single limb ignore the prime range: they are factored
+/// completely with machine arithmetic and `n` is set to one.
 pub fn factor_trial_range(
     n: &mut Natural,
     start: usize,
@@ -19,16 +23,15 @@ pub fn factor_trial_range(
         return None;
     }
 
-    let mut factors = FactoredNatural::new();
-
-    if n.limb_count() == 1 {
-        let fac = n.to_limbs_asc()[0].factor();
-        for (p, exp) in fac {
-            factors.insert(Natural::from(p), exp as u64);
-        }
-        return Some(factors);
+    // word-sized inputs are factored completely with machine arithmetic
+    if is_small(n) {
+        let small = u64::try_from(&*n).unwrap();
+        *n = Natural::ONE;
+        return Some(factor_small_integer(small));
     }
 
+    let mut factors = FactoredNatural::new();
+
     // factor out powers of two
     if start == 0 {
         if let Some(exp) = n.trailing_zeros() {
@@ -163,75 +166,116 @@ pub fn remove_power_ascending(n: &mut Natural, p: Natural) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use malachite::base::num::basic::traits::{One, Zero};
+    use malachite::base::num::arithmetic::traits::Pow;
+    use malachite::base::num::basic::traits::Zero;
+
+    fn mersenne(p: u64) -> Natural {
+        (Natural::ONE << p) - Natural::ONE
+    }
 
     #[test]
     fn test_factor_trial_range_basic() {
-        let (factors, cofactor) = factor_trial_range(ZZElem::from(12), 0, 10);
+        let mut n = Natural::from(12u32);
+        let factors = factor_trial_range(&mut n, 0, 10).unwrap();
 
         // 12 = 2^2 * 3
-        assert_eq!(factors.get(&ZZElem::from(2)), Some(&2u32));
-        assert_eq!(factors.get(&ZZElem::from(3)), Some(&1u32));
-        assert_eq!(cofactor, ZZElem::ONE);
+        assert_eq!(factors.get(&Natural::from(2u32)), Some(&2));
+        assert_eq!(factors.get(&Natural::from(3u32)), Some(&1));
+        assert_eq!(n, Natural::ONE);
+    }
+
+    #[test]
+    fn test_factor_trial_range_360() {
+        let mut n = Natural::from(360u32);
+        let factors = factor_trial_range(&mut n, 0, 100).unwrap();
+
+        // 360 = 2^3 * 3^2 * 5
+        assert_eq!(factors.len(), 3);
+        assert_eq!(factors.get(&Natural::from(2u32)), Some(&3));
+        assert_eq!(factors.get(&Natural::from(3u32)), Some(&2));
+        assert_eq!(factors.get(&Natural::from(5u32)), Some(&1));
+        assert_eq!(n, Natural::ONE);
+
+        // same for a multi-limb input, leaving the large prime as cofactor
+        let mut n = Natural::from(360u32) * mersenne(127);
+        let factors = factor_trial_range(&mut n, 0, 100).unwrap();
+        assert_eq!(factors.len(), 3);
+        assert_eq!(factors.get(&Natural::from(2u32)), Some(&3));
+        assert_eq!(factors.get(&Natural::from(3u32)), Some(&2));
+        assert_eq!(factors.get(&Natural::from(5u32)), Some(&1));
+        assert_eq!(n, mersenne(127));
     }
 
     #[test]
     fn test_factor_trial_range_power_of_two() {
-        let (factors, cofactor) = factor_trial_range(ZZElem::from(8), 0, 10);
+        let mut n = Natural::from(8u32);
+        let factors = factor_trial_range(&mut n, 0, 10).unwrap();
 
         // 8 = 2^3
-        assert_eq!(factors.get(&ZZElem::from(2)), Some(&3u32));
-        assert_eq!(cofactor, ZZElem::ONE);
+        assert_eq!(factors.get(&Natural::from(2u32)), Some(&3));
+        assert_eq!(n, Natural::ONE);
     }
 
     #[test]
     fn test_factor_trial_range_prime() {
-        let (factors, cofactor) = factor_trial_range(ZZElem::from(7), 0, 10);
+        let mut n = Natural::from(7u32);
+        let factors = factor_trial_range(&mut n, 0, 10).unwrap();
 
         // 7 is prime
-        assert_eq!(factors.get(&ZZElem::from(7)), Some(&1u32));
-        assert_eq!(cofactor, ZZElem::ONE);
+        assert_eq!(factors.get(&Natural::from(7u32)), Some(&1));
+        assert_eq!(n, Natural::ONE);
     }
 
     #[test]
     fn test_factor_trial_range_special_cases() {
-        let (factors, cofactor) = factor_trial_range(ZZElem::ONE, 0, 10);
-        assert!(factors.is_empty());
-        assert_eq!(cofactor, ZZElem::ONE);
-
-        let (factors, cofactor) = factor_trial_range(ZZElem::ZERO, 0, 10);
+        let mut n = Natural::ONE;
+        let factors = factor_trial_range(&mut n, 0, 10).unwrap();
         assert!(factors.is_empty());
-        assert_eq!(cofactor, ZZElem::ZERO);
-    }
-
-    #[test]
-    fn test_factor_trial_range_negative() {
-        let (factors, cofactor) = factor_trial_range(ZZElem::from(-6), 0, 10);
+        assert_eq!(n, Natural::ONE);
 
-        // -6 = -1 * 2 * 3
-        assert_eq!(factors.get(&ZZElem::from(2)), Some(&1u32));
-        assert_eq!(factors.get(&ZZElem::from(3)), Some(&1u32));
-        assert_eq!(cofactor, ZZElem::from(-1));
+        let mut n = Natural::ZERO;
+        assert!(factor_trial_range(&mut n, 0, 10).is_none());
+        assert_eq!(n, Natural::ZERO);
     }
 
     #[test]
     fn test_factor_trial_range_partial_factorization() {
         // Use a number with a large prime factor outside our range
-        let (factors, cofactor) = factor_trial_range(ZZElem::from(6 * 101), 0, 10);
-
-        // Should factor out 2 and 3, leaving 101 as cofactor
-        assert_eq!(factors.get(&ZZElem::from(2)), Some(&1u32));
-        assert_eq!(factors.get(&ZZElem::from(3)), Some(&1u32));
-        assert_eq!(cofactor, ZZElem::from(101));
+        let mut n = Natural::from(6u32 * 101) * mersenne(89);
+        let factors = factor_trial_range(&mut n, 0, 10).unwrap();
+
+        // Should factor out 2 and 3, leaving 101 * (2^89 - 1) as cofactor
+        assert_eq!(factors.len(), 2);
+        assert_eq!(factors.get(&Natural::from(2u32)), Some(&1));
+        assert_eq!(factors.get(&Natural::from(3u32)), Some(&1));
+        assert_eq!(n, Natural::from(101u32) * mersenne(89));
     }
 
     #[test]
     fn test_factor_trial_range_skip_start() {
-        let (factors, cofactor) = factor_trial_range(ZZElem::from(6), 1, 5);
+        let mut n = Natural::from(6u32) * mersenne(89);
+        let factors = factor_trial_range(&mut n, 1, 5).unwrap();
 
         // Skip factor 2, should only find 3
-        assert!(factors.get(&ZZElem::from(2)).is_none());
-        assert_eq!(factors.get(&ZZElem::from(3)), Some(&1u32));
-        assert_eq!(cofactor, ZZElem::from(2)); // 2 remains as cofactor
+        assert!(factors.get(&Natural::from(2u32)).is_none());
+        assert_eq!(factors.get(&Natural::from(3u32)), Some(&1));
+        assert_eq!(n, Natural::from(2u32) * mersenne(89)); // 2 remains in the cofactor
+
+        // word-sized inputs are factored completely whatever the range
+        let mut n = Natural::from(6u32 * 1000003);
+        let factors = factor_trial_range(&mut n, 1, 1).unwrap();
+        assert_eq!(factors.get(&Natural::from(2u32)), Some(&1));
+        assert_eq!(factors.get(&Natural::from(1000003u32)), Some(&1));
+        assert_eq!(n, Natural::ONE);
+    }
+
+    #[test]
+    fn test_remove_power() {
+        let mut n = Natural::from(3u32).pow(20) * Natural::from(10u32);
+        assert_eq!(remove_power(&mut n, Natural::from(3u32)), Some(20));
+        assert_eq!(n, Natural::from(10u32));
+        assert_eq!(remove_power(&mut n, Natural::from(7u32)), Some(0));
+        let mut n = Natural::ZERO;
+        assert_eq!(remove_power(&mut n, Natural::from(7u32)), None);
     }
 }
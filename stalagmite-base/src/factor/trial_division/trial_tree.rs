@@ -201,7 +201,7 @@ mod tests {
 
         // Check that first layer has been built with products
         assert!(!tree.tree[0].is_empty());
-        for entry in &tree.tree[0] {
+        for entry in tree.tree[0].iter().take(3512 / (LIMB_BITS / 16)) {
             assert!(*entry > Natural::ZERO);
         }
     }
@@ -216,17 +216,17 @@ mod tests {
     #[test]
     fn test_factor_trial_tree_small_numbers() {
         // Test with small composite numbers
-        let factors = factor_trial_tree(Natural::from(6u32), 10).unwrap();
+        let factors = factor_trial_tree(&Natural::from(6u32), 10).unwrap();
         assert!(!factors.is_empty());
 
-        let factors = factor_trial_tree(Natural::from(12u32), 10).unwrap();
+        let factors = factor_trial_tree(&Natural::from(12u32), 10).unwrap();
         assert!(!factors.is_empty());
     }
 
     #[test]
     fn test_factor_trial_tree_prime() {
         // Test with a small prime
-        let factors = factor_trial_tree(Natural::from(7u32), 10).unwrap();
+        let factors = factor_trial_tree(&Natural::from(7u32), 10).unwrap();
         assert_eq!(factors.len(), 1);
         assert_eq!(factors[0], 3); // 7 is the 4th prime (index 3)
     }
@@ -234,17 +234,17 @@ mod tests {
     #[test]
     fn test_factor_trial_tree_special_cases() {
         // Test edge cases
-        let factors = factor_trial_tree(Natural::ZERO, 10);
+        let factors = factor_trial_tree(&Natural::ZERO, 10);
         assert!(factors.is_none());
 
-        let factors = factor_trial_tree(Natural::ONE, 10).unwrap();
+        let factors = factor_trial_tree(&Natural::ONE, 10).unwrap();
         assert!(factors.is_empty());
     }
 
     #[test]
     fn test_factor_trial_tree_power_of_two() {
         // Test with power of 2
-        let factors = factor_trial_tree(Natural::from(8u32), 10).unwrap();
+        let factors = factor_trial_tree(&Natural::from(8u32), 10).unwrap();
         assert!(!factors.is_empty());
         assert_eq!(factors[0], 0); // 2 is at index 0
     }
@@ -2,8 +2,9 @@ use crate::integer::ZZElem;
 use crate::rational::QQElem;
 use malachite::Natural;
 use std::collections::{HashMap, hash_map::Entry};
+use std::fmt;
 //use std::cmp::Eq;
-use malachite::base::num::arithmetic::traits::Pow;
+use malachite::base::num::arithmetic::traits::{DivExact, Pow};
 use malachite::base::num::basic::integers::PrimitiveInt;
 use malachite::base::num::basic::traits::One;
 use std::hash::Hash;
@@ -142,6 +143,184 @@ pub type FactoredNatural = FactoredElem<Natural, u64>;
 pub type FactoredZZElem = FactoredElem<ZZElem, u64>;
 pub type FactoredQQElem = FactoredElem<QQElem, i64>;
 
+impl FactoredNatural {
+    /// Multiply out the factorization, returning the product of each base
+    /// raised to its exponent. The empty factorization expands to one.
+    pub fn expand(&self) -> Natural {
+        self.factors
+            .iter()
+            .fold(Natural::ONE, |acc, (fac, exp)| acc * fac.pow(*exp))
+    }
+
+    /// Euler's totient of the expanded value, computed as the product of
+    /// `p^(e-1) * (p - 1)` over the factorization. All bases are assumed to
+    /// be prime.
+    pub fn euler_phi(&self) -> Natural {
+        self.factors
+            .iter()
+            .filter(|(_, exp)| **exp != 0)
+            .fold(Natural::ONE, |acc, (p, exp)| {
+                acc * p.pow(exp - 1) * (p - Natural::ONE)
+            })
+    }
+
+    /// Return the radical of the expanded value, the product of the distinct
+    /// prime bases. This is its largest squarefree divisor. The empty
+    /// factorization has radical one.
+    pub fn radical(&self) -> Natural {
+        self.factors
+            .iter()
+            .filter(|(_, exp)| **exp != 0)
+            .fold(Natural::ONE, |acc, (p, _)| acc * p)
+    }
+
+    /// Return the Möbius function of the expanded value: zero if some prime
+    /// appears with exponent greater than one, otherwise `(-1)^k` for `k`
+    /// distinct primes. All bases are assumed to be prime.
+    pub fn moebius(&self) -> i8 {
+        let mut result = 1;
+        for exp in self.factors.values() {
+            match exp {
+                0 => {}
+                1 => result = -result,
+                _ => return 0,
+            }
+        }
+        result
+    }
+
+    /// Return the divisor function `sigma_k`, the sum of the `k`-th powers of
+    /// the divisors of the expanded value, computed as the product of
+    /// `(p^((e+1)k) - 1) / (p^k - 1)` over the factorization. `sigma(0)` is
+    /// the number of divisors and `sigma(1)` their sum. All bases are assumed
+    /// to be prime.
+    pub fn sigma(&self, k: u32) -> Natural {
+        if k == 0 {
+            return self.divisor_count();
+        }
+        self.factors
+            .iter()
+            .fold(Natural::ONE, |acc, (p, exp)| {
+                let pk = p.pow(u64::from(k));
+                acc * ((&pk).pow(exp + 1) - Natural::ONE).div_exact(pk - Natural::ONE)
+            })
+    }
+
+    /// Return all divisors of the expanded value in ascending order.
+    pub fn divisors(&self) -> Vec<Natural> {
+        let mut divisors = vec![Natural::ONE];
+        for (p, exp) in self.factors.iter() {
+            let mut powers = Vec::with_capacity(divisors.len() * (*exp as usize));
+            let mut pk = Natural::ONE;
+            for _ in 0..*exp {
+                pk *= p;
+                powers.extend(divisors.iter().map(|d| d * &pk));
+            }
+            divisors.extend(powers);
+        }
+        divisors.sort_unstable();
+        divisors
+    }
+
+    /// Return the number of divisors of the expanded value, the product of
+    /// `e + 1` over all exponents `e`.
+    pub fn divisor_count(&self) -> Natural {
+        self.factors
+            .values()
+            .fold(Natural::ONE, |acc, exp| acc * Natural::from(exp + 1))
+    }
+}
+
+impl FactoredZZElem {
+    /// Multiply out the factorization, returning the product of each base
+    /// raised to its exponent. The empty factorization expands to one and a
+    /// `-1` factor with odd exponent gives a negative result.
+    pub fn expand(&self) -> ZZElem {
+        self.factors
+            .iter()
+            .fold(ZZElem::ONE, |acc, (fac, exp)| acc * fac.pow(*exp))
+    }
+
+    /// Return the divisor function `sigma_k` of the absolute value of the
+    /// expanded value. The sign factor `-1` is ignored. See
+    /// `FactoredNatural::sigma`.
+    pub fn sigma(&self, k: u32) -> Natural {
+        self.factors
+            .iter()
+            .filter(|(p, _)| **p != -1)
+            .map(|(p, exp)| (p.unsigned_abs_ref().clone(), *exp))
+            .collect::<FactoredNatural>()
+            .sigma(k)
+    }
+
+    /// Euler's totient of the absolute value of the expanded value, computed
+    /// as the product of `p^(e-1) * (p - 1)` over the factorization. All bases
+    /// other than the sign factor `-1`, which is ignored, are assumed to be
+    /// prime.
+    pub fn euler_phi(&self) -> Natural {
+        self.factors
+            .iter()
+            .filter(|(p, exp)| **exp != 0 && **p != -1)
+            .fold(Natural::ONE, |acc, (p, exp)| {
+                let p = p.unsigned_abs_ref();
+                acc * p.pow(exp - 1) * (p - Natural::ONE)
+            })
+    }
+}
+
+// Write the factors with nonzero exponent as `p^e` joined by ` * ` in
+// ascending order of base, omitting exponents of one. The empty product is
+// written as `1`.
+fn fmt_factors<T: Ord + fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    factors: &HashMap<T, u64>,
+) -> fmt::Result {
+    let mut sorted: Vec<_> = factors.iter().filter(|(_, exp)| **exp != 0).collect();
+    if sorted.is_empty() {
+        return write!(f, "1");
+    }
+    sorted.sort_unstable_by(|a, b| a.0.cmp(b.0));
+    for (i, (p, exp)) in sorted.into_iter().enumerate() {
+        if i > 0 {
+            write!(f, " * ")?;
+        }
+        if *exp == 1 {
+            write!(f, "{}", p)?;
+        } else {
+            write!(f, "{}^{}", p, exp)?;
+        }
+    }
+    Ok(())
+}
+
+/// Display the factorization as a product of prime powers in ascending
+/// order, for example `2^3 * 3^2 * 5`. The empty factorization is `1`.
+impl fmt::Display for FactoredNatural {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_factors(f, &self.factors)
+    }
+}
+
+/// Display the factorization as a product of prime powers in ascending
+/// order, with a leading `-1` when the sign factor has odd exponent, for
+/// example `-1 * 2^3 * 3^2 * 5`. The empty factorization is `1`.
+impl fmt::Display for FactoredZZElem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let minus_one = ZZElem::from(-1);
+        let negative = self.factors.get(&minus_one).is_some_and(|exp| exp % 2 == 1);
+        let mut factors = self.factors.clone();
+        factors.remove(&minus_one);
+        if negative {
+            write!(f, "-1")?;
+            if factors.values().all(|exp| *exp == 0) {
+                return Ok(());
+            }
+            write!(f, " * ")?;
+        }
+        fmt_factors(f, &factors)
+    }
+}
+
 pub trait Eval {
     type Output;
     fn eval(self) -> Self::Output;
@@ -173,3 +352,133 @@ impl Eval for FactoredQQElem {
             .fold(QQElem::ONE, |acc, (fac, exp)| acc * fac.pow(exp))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Factor;
+    use malachite::base::num::basic::traits::Zero;
+
+    #[test]
+    fn test_expand_round_trip() {
+        let n = Natural::from(360u32);
+        let factors = n.clone().factor();
+        assert_eq!(factors.expand(), n);
+        assert_eq!(factors.eval(), n);
+    }
+
+    #[test]
+    fn test_expand_empty() {
+        assert_eq!(FactoredNatural::new().expand(), Natural::ONE);
+        assert_eq!(FactoredZZElem::new().expand(), ZZElem::ONE);
+    }
+
+    #[test]
+    fn test_expand_negative() {
+        let factors = FactoredZZElem::from([
+            (ZZElem::from(-1), 1),
+            (ZZElem::from(2), 3),
+            (ZZElem::from(3), 2),
+            (ZZElem::from(5), 1),
+        ]);
+        assert_eq!(factors.expand(), ZZElem::from(-360));
+
+        let factors = FactoredZZElem::from([(ZZElem::from(-1), 2), (ZZElem::from(7), 1)]);
+        assert_eq!(factors.expand(), ZZElem::from(7));
+    }
+
+    #[test]
+    fn test_divisors() {
+        let factors = Natural::from(12u32).factor();
+        let expected: Vec<Natural> = [1u32, 2, 3, 4, 6, 12].into_iter().map(Natural::from).collect();
+        assert_eq!(factors.divisors(), expected);
+        assert_eq!(factors.divisor_count(), 6u32);
+
+        let factors = Natural::from(360u32).factor();
+        assert_eq!(factors.divisors().len(), 24);
+        assert_eq!(factors.divisor_count(), 24u32);
+
+        assert_eq!(FactoredNatural::new().divisors(), vec![Natural::ONE]);
+        assert_eq!(FactoredNatural::new().divisor_count(), 1u32);
+    }
+
+    #[test]
+    fn test_divisors_prime_powers() {
+        let p = Natural::from(97u32);
+        let factors = FactoredNatural::from([(p.clone(), 1)]);
+        assert_eq!(factors.divisors(), vec![Natural::ONE, p.clone()]);
+        assert_eq!(factors.divisor_count(), 2u32);
+
+        let factors = FactoredNatural::from([(p.clone(), 2)]);
+        assert_eq!(factors.divisors(), vec![Natural::ONE, p.clone(), &p * &p]);
+        assert_eq!(factors.divisor_count(), 3u32);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Natural::from(360u32).factor().to_string(), "2^3 * 3^2 * 5");
+        assert_eq!(Natural::from(97u32).factor().to_string(), "97");
+        assert_eq!(FactoredNatural::new().to_string(), "1");
+
+        assert_eq!(ZZElem::from(-360).factor().to_string(), "-1 * 2^3 * 3^2 * 5");
+        assert_eq!(ZZElem::from(360).factor().to_string(), "2^3 * 3^2 * 5");
+        assert_eq!(ZZElem::from(-1).factor().to_string(), "-1");
+        assert_eq!(ZZElem::from(1).factor().to_string(), "1");
+
+        // an even power of -1 is not a sign
+        let factors = FactoredZZElem::from([(ZZElem::from(-1), 2), (ZZElem::from(7), 1)]);
+        assert_eq!(factors.to_string(), "7");
+    }
+
+    #[test]
+    fn test_radical() {
+        assert_eq!(Natural::from(360u32).factor().radical(), Natural::from(30u32));
+        assert_eq!(Natural::from(97u32).factor().radical(), Natural::from(97u32));
+        assert_eq!(FactoredNatural::new().radical(), Natural::ONE);
+
+        let factors = FactoredNatural::from([(Natural::from(2u32), 5), (Natural::from(3u32), 0)]);
+        assert_eq!(factors.radical(), Natural::from(2u32));
+    }
+
+    #[test]
+    fn test_moebius() {
+        assert_eq!(Natural::from(30u32).factor().moebius(), -1);
+        assert_eq!(Natural::from(6u32).factor().moebius(), 1);
+        assert_eq!(Natural::from(12u32).factor().moebius(), 0);
+        assert_eq!(FactoredNatural::new().moebius(), 1);
+    }
+
+    #[test]
+    fn test_sigma() {
+        let factors = Natural::from(6u32).factor();
+        assert_eq!(factors.sigma(1), Natural::from(12u32));
+        assert_eq!(Natural::from(12u32).factor().sigma(0), Natural::from(6u32));
+
+        // agrees with summing powers of the divisors
+        let factors = Natural::from(360u32).factor();
+        for k in 0..4 {
+            let expected = factors.divisors().iter().fold(Natural::ZERO, |acc, d| acc + d.pow(u64::from(k)));
+            assert_eq!(factors.sigma(k), expected);
+        }
+        assert_eq!(FactoredNatural::new().sigma(2), Natural::ONE);
+
+        let factors = ZZElem::from(-12).factor();
+        assert_eq!(factors.sigma(1), Natural::from(28u32));
+        assert_eq!(factors.sigma(0), Natural::from(6u32));
+    }
+
+    #[test]
+    fn test_euler_phi() {
+        let factors = Natural::from(360u32).factor();
+        assert_eq!(factors.euler_phi(), Natural::from(96u32));
+        assert_eq!(FactoredNatural::new().euler_phi(), Natural::ONE);
+
+        let factors = FactoredZZElem::from([
+            (ZZElem::from(-1), 1),
+            (ZZElem::from(2), 3),
+            (ZZElem::from(3), 2),
+            (ZZElem::from(5), 1),
+        ]);
+        assert_eq!(factors.euler_phi(), Natural::from(96u32));
+    }
+}
@@ -15,8 +15,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::StalagmiteError;
 use crate::traits::{Element, Parent, Ring, RingElement};
 pub use malachite::Integer;
+use malachite::Natural;
+use malachite::base::num::arithmetic::traits::{DivExact, ExtendedGcd, Mod, Parity};
+use malachite::base::num::logic::traits::{BitAccess, SignificantBits};
+use std::mem::swap;
 
 pub struct IntegerRing;
 
@@ -41,3 +46,293 @@ impl Element for ZZElem {
 impl RingElement for ZZElem {
     type Parent = ZZRing;
 }
+
+/// Return the number of significant bits of the absolute value of `x`, which
+/// is zero for zero.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_base::integer::{bits, Integer};
+///
+/// assert_eq!(bits(&Integer::from(255)), 8);
+/// assert_eq!(bits(&Integer::from(-256)), 9);
+/// assert_eq!(bits(&Integer::from(0)), 0);
+/// ```
+#[inline]
+pub fn bits(x: &Integer) -> u64 {
+    x.significant_bits()
+}
+
+/// Return whether the absolute value of `x` fits in a single limb, so that
+/// word-sized arithmetic can be used on it.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_base::integer::{is_small, Integer};
+///
+/// assert!(is_small(&Integer::from(-12345)));
+/// assert!(!is_small(&(Integer::from(1) << 200u64)));
+/// ```
+#[inline]
+pub fn is_small(x: &Integer) -> bool {
+    crate::natural::is_small(x.unsigned_abs_ref())
+}
+
+/// Return the Jacobi symbol `(a/n)` as -1, 0 or 1. Panics if `n` is even.
+pub fn jacobi(a: &Integer, n: &Natural) -> i8 {
+    assert!(n.odd(), "Jacobi symbol requires an odd positive modulus");
+    let mut n = n.clone();
+    let mut a = Natural::try_from(a.mod_op(Integer::from(&n))).unwrap();
+    let mut result = 1;
+    while a != 0u32 {
+        // (2/n) = -1 iff n = 3, 5 mod 8
+        let twos = a.trailing_zeros().unwrap();
+        a >>= twos;
+        if twos.odd() && (n.get_bit(1) != n.get_bit(2)) {
+            result = -result;
+        }
+        // quadratic reciprocity, with a and n odd
+        swap(&mut a, &mut n);
+        if a.get_bit(1) && n.get_bit(1) {
+            result = -result;
+        }
+        a %= &n;
+    }
+    if n == 1u32 { result } else { 0 }
+}
+
+/// Return the Legendre symbol `(a/p)` as -1, 0 or 1. The modulus `p` is
+/// assumed to be an odd prime.
+#[inline]
+pub fn legendre(a: &Integer, p: &Natural) -> i8 {
+    jacobi(a, p)
+}
+
+/// Return the residue of `x` modulo `modulus` in the symmetric range
+/// `(-m/2, m/2]`, as used when lifting residues to signed integers. The least
+/// nonnegative residue is `x.mod_op(m)`.
+///
+/// # Panics
+///
+/// Panics if `modulus` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_base::integer::{rem_centered, Integer};
+/// use malachite::Natural;
+///
+/// let m = Natural::from(7u32);
+/// assert_eq!(rem_centered(&Integer::from(5), &m), -2);
+/// assert_eq!(rem_centered(&Integer::from(3), &m), 3);
+/// assert_eq!(rem_centered(&Integer::from(-4), &m), 3);
+/// ```
+pub fn rem_centered(x: &Integer, modulus: &Natural) -> Integer {
+    if *modulus == 0u32 {
+        panic!("{}", StalagmiteError::DivisionByZero);
+    }
+    let m = Integer::from(modulus);
+    let r = x.mod_op(&m);
+    if r > (&m >> 1u64) { r - m } else { r }
+}
+
+/// Return `(g, s, t)` with `g = gcd(a, b)` nonnegative and
+/// `s * a + t * b == g`. If both `a` and `b` are zero this is `(0, 0, 0)`.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_base::integer::{xgcd, Integer};
+///
+/// let (a, b) = (Integer::from(240), Integer::from(46));
+/// let (g, s, t) = xgcd(&a, &b);
+/// assert_eq!(g, 2);
+/// assert_eq!(s * a + t * b, g);
+/// ```
+pub fn xgcd(a: &Integer, b: &Integer) -> (Integer, Integer, Integer) {
+    let (g, s, t) = a.extended_gcd(b);
+    (Integer::from(g), s, t)
+}
+
+/// Solve the system of congruences `x = r mod m` for the given
+/// `(r, m)` pairs, returning the unique solution modulo the least common
+/// multiple of the moduli (their product when pairwise coprime), or `None` if
+/// the system is inconsistent or a modulus is zero. The empty system has
+/// solution zero.
+pub fn crt(residues: &[(Natural, Natural)]) -> Option<Natural> {
+    let mut x = Integer::from(0);
+    let mut m = Integer::from(1);
+    for (r, n) in residues {
+        if *n == 0u32 {
+            return None;
+        }
+        (x, m) = crt_pair(x, m, Integer::from(r), Integer::from(n))?;
+    }
+    Some(Natural::try_from(x).unwrap())
+}
+
+// Combine x = r1 mod m1 and x = r2 mod m2 into a single congruence modulo
+// lcm(m1, m2), with the result in [0, lcm).
+pub(crate) fn crt_pair(
+    r1: Integer,
+    m1: Integer,
+    r2: Integer,
+    m2: Integer,
+) -> Option<(Integer, Integer)> {
+    // s * m1 + t * m2 = g
+    let (g, s, _) = xgcd(&m1, &m2);
+    let diff = r2 - &r1;
+    if (&diff).mod_op(&g) != 0 {
+        return None;
+    }
+    let m2_g = (&m2).div_exact(&g);
+    let lcm = &m1 * &m2_g;
+    let k = (diff.div_exact(&g) * s).mod_op(&m2_g);
+    let x = (r1 + m1 * k).mod_op(&lcm);
+    Some((x, lcm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LIMB_BITS;
+
+    #[test]
+    fn test_legendre() {
+        let p = Natural::from(7u32);
+        assert_eq!(legendre(&Integer::from(2), &p), 1);
+        assert_eq!(legendre(&Integer::from(3), &p), -1);
+        assert_eq!(legendre(&Integer::from(14), &p), 0);
+        assert_eq!(legendre(&Integer::from(-1), &p), -1);
+    }
+
+    #[test]
+    fn test_jacobi() {
+        assert_eq!(jacobi(&Integer::from(1001), &Natural::from(9907u32)), -1);
+        assert_eq!(jacobi(&Integer::from(19), &Natural::from(45u32)), 1);
+        assert_eq!(jacobi(&Integer::from(8), &Natural::from(21u32)), -1);
+        assert_eq!(jacobi(&Integer::from(5), &Natural::from(21u32)), 1);
+        assert_eq!(jacobi(&Integer::from(6), &Natural::from(15u32)), 0);
+        assert_eq!(jacobi(&Integer::from(0), &Natural::from(1u32)), 1);
+        assert_eq!(jacobi(&Integer::from(-2), &Natural::from(15u32)), -1);
+
+        // agrees with malachite
+        use malachite::base::num::arithmetic::traits::JacobiSymbol;
+        for a in -50i32..50 {
+            for n in (1u32..60).step_by(2) {
+                let expected = Integer::from(a).jacobi_symbol(Integer::from(n));
+                assert_eq!(jacobi(&Integer::from(a), &Natural::from(n)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bits() {
+        let z = Integer::from;
+        assert_eq!(bits(&z(255)), 8);
+        assert_eq!(bits(&z(256)), 9);
+        assert_eq!(bits(&z(-255)), 8);
+        assert_eq!(bits(&z(1)), 1);
+        assert_eq!(bits(&z(0)), 0);
+        assert_eq!(bits(&(z(1) << LIMB_BITS as u64)), LIMB_BITS as u64 + 1);
+    }
+
+    #[test]
+    fn test_is_small() {
+        let z = Integer::from;
+        let limb_max = (z(1) << LIMB_BITS as u64) - z(1);
+        assert!(is_small(&z(0)));
+        assert!(is_small(&z(-1)));
+        assert!(is_small(&limb_max));
+        assert!(is_small(&-&limb_max));
+        assert!(!is_small(&(&limb_max + z(1))));
+        assert!(!is_small(&-(&limb_max + z(1))));
+    }
+
+    #[test]
+    fn test_rem_centered() {
+        let z = Integer::from;
+        let seven = Natural::from(7u32);
+        assert_eq!(rem_centered(&z(5), &seven), -2);
+        assert_eq!(rem_centered(&z(3), &seven), 3);
+        assert_eq!(rem_centered(&z(4), &seven), -3);
+        assert_eq!(rem_centered(&z(-3), &seven), -3);
+        assert_eq!(rem_centered(&z(-4), &seven), 3);
+        assert_eq!(rem_centered(&z(14), &seven), 0);
+
+        // even modulus: m/2 is included, -m/2 is not
+        let six = Natural::from(6u32);
+        assert_eq!(rem_centered(&z(3), &six), 3);
+        assert_eq!(rem_centered(&z(-3), &six), 3);
+        assert_eq!(rem_centered(&z(4), &six), -2);
+
+        assert_eq!(rem_centered(&z(-5), &Natural::from(1u32)), 0);
+        assert_eq!(rem_centered(&z(1), &Natural::from(2u32)), 1);
+        for x in -30i32..30 {
+            for m in 1u32..12 {
+                let r = rem_centered(&z(x), &Natural::from(m));
+                assert_eq!((z(x) - &r).mod_op(z(m as i32)), 0);
+                assert!(z(-(m as i32)) < z(2) * &r && z(2) * &r <= z(m as i32));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_rem_centered_zero_modulus() {
+        rem_centered(&Integer::from(3), &Natural::from(0u32));
+    }
+
+    #[test]
+    fn test_xgcd() {
+        let z = Integer::from;
+        let (g, s, t) = xgcd(&z(240), &z(46));
+        assert_eq!(g, 2);
+        assert_eq!(&s * z(240) + &t * z(46), 2);
+
+        for (a, b) in [(-240, 46), (240, -46), (-7, -21), (17, 5), (1, 1)] {
+            let (g, s, t) = xgcd(&z(a), &z(b));
+            assert!(g > 0);
+            assert_eq!(s * z(a) + t * z(b), g);
+        }
+
+        // zero arguments give the absolute value of the other
+        let (g, s, t) = xgcd(&z(0), &z(-9));
+        assert_eq!(g, 9);
+        assert_eq!(t * z(-9), 9);
+        assert_eq!(s * z(0), 0);
+        let (g, s, _) = xgcd(&z(12), &z(0));
+        assert_eq!(g, 12);
+        assert_eq!(s * z(12), 12);
+        assert_eq!(xgcd(&z(0), &z(0)), (z(0), z(0), z(0)));
+    }
+
+    #[test]
+    fn test_crt() {
+        let n = |x: u32| Natural::from(x);
+        assert_eq!(crt(&[(n(2), n(3)), (n(3), n(5))]), Some(n(8)));
+        assert_eq!(crt(&[(n(2), n(3)), (n(3), n(5)), (n(2), n(7))]), Some(n(23)));
+        assert_eq!(crt(&[(n(7), n(3))]), Some(n(1)));
+        assert_eq!(crt(&[]), Some(n(0)));
+
+        // non-coprime moduli
+        assert_eq!(crt(&[(n(2), n(4)), (n(4), n(6))]), Some(n(10)));
+        assert_eq!(crt(&[(n(1), n(4)), (n(2), n(6))]), None);
+        assert_eq!(crt(&[(n(1), n(0))]), None);
+
+        // large moduli
+        let p = (Natural::from(1u32) << 127u64) - Natural::from(1u32);
+        let q = (Natural::from(1u32) << 89u64) - Natural::from(1u32);
+        let x = Natural::from(123456789u64) * Natural::from(987654321u64) << 100u64;
+        let res = crt(&[(&x % &p, p.clone()), (&x % &q, q.clone())]).unwrap();
+        assert_eq!(res, x % (p * q));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_jacobi_even_modulus() {
+        jacobi(&Integer::from(3), &Natural::from(8u32));
+    }
+}
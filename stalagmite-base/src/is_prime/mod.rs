@@ -1,33 +1,66 @@
-use malachite::Natural;
+use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::{
+    FloorSqrt, JacobiSymbol, Mod, ModPow, Parity, Square,
+};
+use malachite::base::num::basic::traits::{One, Two};
+use malachite::base::num::logic::traits::BitIterable;
 
-fn _is_prime(n: Natural, proved: bool) -> bool {
-    // 1. if small use n_is_prime (NOT n_is_probable_prime)
-    // 2. else:
-    //    check if 0 mod 2
-    //    trial_primes = bit_size * LIMB_BITS + (?)
-    //    brute force check divisibility by primes up to trial_primes
+use crate::factor::prime_cache::{ensure_primes_computed, get_prime_cache};
 
-    // 3. check if square, or maybe if perfect power
-    // 4. if !proved return is_probable_prime_BPSW
+// Number of cached primes used for trial division before running any
+// probable prime test.
+const NUM_TRIAL_PRIMES: usize = 64;
 
-    // 5. deterministic Miller-Rabin test up to ~81 bits (is_strong_probable_prime)
-    // 6. is_strong_probably_prime(n, 2) rules out most composites (?)
+// Miller-Rabin with the first 13 primes as bases is deterministic below this
+// bound, which is larger than 2^81.
+const MR_DETERMINISTIC_BOUND: u128 = 3317044064679887385961981;
+const MR_BASES: [u64; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
 
-    // 7. multi-modular reduction?
-    // 8. is_prime_pocklington
-    // 9. p-1, p+1 tests
-    // 10. is_prime_morrison
-    // 11. aprcl_is_prime
+fn _is_prime(n: &Natural, proved: bool) -> bool {
+    // 1. trial division by small primes, which also settles small n
+    if *n < 2u32 {
+        return false;
+    }
+    ensure_primes_computed(NUM_TRIAL_PRIMES);
+    {
+        let cache = get_prime_cache().read().unwrap();
+        for &p in cache[..NUM_TRIAL_PRIMES].iter() {
+            if *n == p {
+                return true;
+            }
+            if (n % Natural::from(p)) == 0u32 {
+                return false;
+            }
+            if Natural::from(p).square() > *n {
+                return true;
+            }
+        }
+    }
 
-    false
+    // 2. a probable prime only needs BPSW
+    if !proved {
+        return is_probable_prime_bpsw(n);
+    }
+
+    // 3. deterministic Miller-Rabin test below 2^81
+    if *n < MR_DETERMINISTIC_BOUND {
+        return MR_BASES.iter().all(|&a| is_strong_probable_prime(n, &Natural::from(a)));
+    }
+
+    // TODO: proving primality of large n (pocklington, morrison, aprcl) is
+    // not implemented yet, so BPSW is used instead.
+    is_probable_prime_bpsw(n)
 }
 
-fn is_prime(n: Natural) -> bool {
-    _is_prime(n, true)
+/// Return true if `n` is prime. This is deterministic for `n` below 2^81;
+/// for larger `n` the BPSW test is used, for which no counterexample is known.
+pub fn is_prime(n: Natural) -> bool {
+    _is_prime(&n, true)
 }
 
-fn is_probable_prime(n: Natural) -> bool {
-    _is_prime(n, false)
+/// Return true if `n` is a probable prime, using trial division and BPSW.
+pub fn is_probable_prime(n: Natural) -> bool {
+    _is_prime(&n, false)
 }
 
 // Deterministic tests
@@ -46,15 +79,201 @@ pub fn is_prime_aprcl() -> bool {
 
 // Probabalistic
 
-use crate::factor::prime_cache::PRIME_CACHE;
-pub fn is_prime_using_cache() -> bool {
+/// Return true if the odd integer `n > 2` is a strong probable prime to base
+/// `a`, i.e. passes a single round of Miller-Rabin.
+pub fn is_strong_probable_prime(n: &Natural, a: &Natural) -> bool {
+    let n_minus_one = n - Natural::ONE;
+    let a = a % n;
+    if a == 0u32 || a == 1u32 || a == n_minus_one {
+        return true;
+    }
+
+    // n - 1 = d * 2^s with d odd
+    let s = n_minus_one.trailing_zeros().unwrap();
+    let d = &n_minus_one >> s;
+
+    let mut x = a.mod_pow(&d, n);
+    if x == 1u32 || x == n_minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        x = x.square() % n;
+        if x == n_minus_one {
+            return true;
+        } else if x == 1u32 {
+            return false;
+        }
+    }
     false
 }
 
-pub fn is_strong_probable_prime() -? bool {
-    false
+// Divide by two modulo the odd modulus n.
+fn half_mod(x: Natural, n: &Natural) -> Natural {
+    if x.odd() {
+        (x + n) >> 1
+    } else {
+        x >> 1
+    }
 }
 
-pub fn is_probable_prime_bpsw() -? bool {
+/// Return true if the odd integer `n > 2` is a strong Lucas probable prime
+/// with parameters chosen by Selfridge's method A. Perfect squares return
+/// false.
+pub fn is_strong_lucas_probable_prime(n: &Natural) -> bool {
+    if n.floor_sqrt().square() == *n {
+        return false;
+    }
+
+    // Find the first D in 5, -7, 9, -11, ... with Jacobi(D/n) = -1.
+    let n_int = Integer::from(n);
+    let mut d = Integer::from(5);
+    loop {
+        let d_mod = Natural::try_from(d.clone().mod_op(&n_int)).unwrap();
+        match (&d_mod).jacobi_symbol(n) {
+            -1 => break,
+            0 if d_mod != 0u32 => return false,
+            _ => {},
+        }
+        d = if d > 0 { -(d + Integer::TWO) } else { -d + Integer::TWO };
+    }
+
+    // P = 1, Q = (1 - D)/4
+    let q = Natural::try_from(((Integer::ONE - &d) >> 2u32).mod_op(&n_int)).unwrap();
+    let d = Natural::try_from(d.mod_op(&n_int)).unwrap();
+
+    // n + 1 = k * 2^s with k odd
+    let n_plus_one = n + Natural::ONE;
+    let s = n_plus_one.trailing_zeros().unwrap();
+    let k = &n_plus_one >> s;
+
+    // Compute U_k, V_k and Q^k by binary expansion of k.
+    let mut u = Natural::ONE;
+    let mut v = Natural::ONE;
+    let mut qk = q.clone();
+    for bit in k.bits().rev().skip(1) {
+        u = (&u * &v) % n;
+        v = (v.square() + (n - &qk) * Natural::TWO) % n;
+        qk = qk.square() % n;
+        if bit {
+            let new_u = half_mod((&u + &v) % n, n);
+            let new_v = half_mod((&d * &u + &v) % n, n);
+            u = new_u;
+            v = new_v;
+            qk = (qk * &q) % n;
+        }
+    }
+
+    if u == 0u32 || v == 0u32 {
+        return true;
+    }
+    for _ in 1..s {
+        v = (v.square() + (n - &qk) * Natural::TWO) % n;
+        if v == 0u32 {
+            return true;
+        }
+        qk = qk.square() % n;
+    }
     false
 }
+
+/// Baillie-PSW probable prime test: a strong probable prime test to base 2
+/// followed by a strong Lucas probable prime test. No composite passing both
+/// is known.
+pub fn is_probable_prime_bpsw(n: &Natural) -> bool {
+    if *n < 2u32 {
+        return false;
+    } else if *n == 2u32 {
+        return true;
+    } else if n.even() {
+        return false;
+    }
+    is_strong_probable_prime(n, &Natural::TWO) && is_strong_lucas_probable_prime(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mersenne(p: u64) -> Natural {
+        (Natural::ONE << p) - Natural::ONE
+    }
+
+    #[test]
+    fn test_is_prime_small() {
+        let primes = [2u32, 3, 5, 7, 11, 13, 97, 101, 311, 7919, 65537, 1000003];
+        for p in primes {
+            assert!(is_prime(Natural::from(p)), "{}", p);
+            assert!(is_probable_prime(Natural::from(p)), "{}", p);
+        }
+        let composites = [0u32, 1, 4, 6, 9, 15, 25, 91, 1001, 65535, 1000001];
+        for c in composites {
+            assert!(!is_prime(Natural::from(c)), "{}", c);
+            assert!(!is_probable_prime(Natural::from(c)), "{}", c);
+        }
+    }
+
+    #[test]
+    fn test_is_prime_carmichael() {
+        let carmichael = [561u64, 1105, 1729, 2465, 2821, 6601, 8911, 41041, 825265, 321197185];
+        for c in carmichael {
+            assert!(!is_prime(Natural::from(c)), "{}", c);
+        }
+    }
+
+    #[test]
+    fn test_is_prime_strong_pseudoprimes() {
+        // strong pseudoprimes to base 2
+        for c in [2047u64, 3277, 4033, 4681, 8321] {
+            assert!(is_strong_probable_prime(&Natural::from(c), &Natural::TWO));
+            assert!(!is_prime(Natural::from(c)), "{}", c);
+        }
+        // strong pseudoprime to bases 2, 3, 5 and 7
+        assert!(!is_prime(Natural::from(3215031751u64)));
+        // strong pseudoprime to all prime bases up to 23
+        assert!(!is_prime(Natural::from(3825123056546413051u64)));
+        // strong pseudoprime to all prime bases up to 37, below the
+        // deterministic bound
+        let c = Natural::from(318665857834031151167461u128);
+        assert_eq!(c, Natural::from(399165290221u64) * Natural::from(798330580441u64));
+        assert!([2u32, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]
+            .iter()
+            .all(|&a| is_strong_probable_prime(&c, &Natural::from(a))));
+        assert!(!is_prime(c.clone()));
+        assert!(!is_probable_prime(c));
+    }
+
+    #[test]
+    fn test_is_prime_mersenne() {
+        for p in [31u64, 61, 89, 107, 127, 521, 607] {
+            assert!(is_prime(mersenne(p)), "2^{} - 1", p);
+            assert!(is_probable_prime(mersenne(p)), "2^{} - 1", p);
+        }
+        for p in [11u64, 67, 101, 128, 257] {
+            assert!(!is_prime(mersenne(p)), "2^{} - 1", p);
+        }
+    }
+
+    #[test]
+    fn test_is_prime_large_composites() {
+        // product of two primes above the deterministic bound
+        let a = mersenne(89);
+        let b = mersenne(107);
+        assert!(!is_prime(&a * &b));
+        assert!(!is_prime(a.square()));
+        assert!(!is_prime((Natural::ONE << 128u64) + Natural::ONE));
+    }
+
+    #[test]
+    fn test_strong_lucas() {
+        // strong Lucas pseudoprimes are composite but pass the Lucas test
+        for c in [5459u64, 5777, 10877, 16109, 18971] {
+            assert!(is_strong_lucas_probable_prime(&Natural::from(c)), "{}", c);
+            assert!(!is_probable_prime_bpsw(&Natural::from(c)), "{}", c);
+        }
+        for p in [3u64, 5, 7, 11, 13, 1000003] {
+            assert!(is_strong_lucas_probable_prime(&Natural::from(p)), "{}", p);
+            assert!(is_probable_prime_bpsw(&Natural::from(p)), "{}", p);
+        }
+        assert!(!is_strong_lucas_probable_prime(&Natural::from(49u32)));
+    }
+}
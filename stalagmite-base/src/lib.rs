@@ -34,6 +34,7 @@ pub const LIMB_BITS: usize = malachite::platform::Limb::BITS as usize;
 pub enum StalagmiteError {
     DivisionByZero,
     InvalidModulus(Natural),
+    ParseError(String),
 }
 
 impl fmt::Display for StalagmiteError {
@@ -41,6 +42,7 @@ impl fmt::Display for StalagmiteError {
         match self {
             StalagmiteError::DivisionByZero => write!(f, "Division by zero"),
             StalagmiteError::InvalidModulus(modulus) => write!(f, "Invalid modulus: {}", modulus),
+            StalagmiteError::ParseError(msg) => write!(f, "Parse error: {}", msg),
         }
     }
 }
@@ -15,8 +15,14 @@
 // You should have received a copy of the GNU General Public License
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::factor::Factor;
+use crate::factor::prime_cache::{ensure_primes_below, get_nth_prime_using_cache, get_prime_cache};
+use crate::is_prime::is_prime;
 use crate::traits::{Element, Parent};
 pub use malachite::Natural;
+use malachite::base::num::arithmetic::traits::CheckedRoot;
+use malachite::base::num::basic::traits::{One, Zero};
+use malachite::base::num::logic::traits::SignificantBits;
 
 pub struct Naturals;
 
@@ -30,3 +36,265 @@ impl Element for Natural {
         Naturals
     }
 }
+
+/// Return whether `n` fits in a single limb, so that word-sized arithmetic
+/// can be used on it.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_base::natural::{is_small, Natural};
+///
+/// assert!(is_small(&Natural::from(12345u32)));
+/// assert!(!is_small(&(Natural::from(1u32) << 200u64)));
+/// ```
+#[inline]
+pub fn is_small(n: &Natural) -> bool {
+    n.limb_count() <= 1
+}
+
+/// Euler's totient function, the number of integers in `1..=n` coprime to
+/// `n`. This factors `n` first; use `FactoredNatural::euler_phi` if the
+/// factorization is already known. By convention `euler_phi(0) = 0`.
+pub fn euler_phi(n: &Natural) -> Natural {
+    if *n == 0u32 {
+        return Natural::ZERO;
+    }
+    n.clone().factor().euler_phi()
+}
+
+/// Return the radical of `n`, the product of its distinct prime factors.
+/// This factors `n` first; use `FactoredNatural::radical` if the
+/// factorization is already known. By convention `radical(0) = 0`.
+pub fn radical(n: &Natural) -> Natural {
+    if *n == 0u32 {
+        return Natural::ZERO;
+    }
+    n.clone().factor().radical()
+}
+
+/// Return the Möbius function of `n`: zero if `n` is divisible by the square
+/// of a prime, otherwise `(-1)^k` where `k` is the number of distinct prime
+/// factors. This factors `n` first; use `FactoredNatural::moebius` if the
+/// factorization is already known. By convention `moebius(0) = 0`.
+pub fn moebius(n: &Natural) -> i8 {
+    if *n == 0u32 {
+        return 0;
+    }
+    n.clone().factor().moebius()
+}
+
+/// Return the smallest base `b` and the exponent `k >= 2` with `n = b^k`, or
+/// `None` if `n` is not a perfect power. Zero and one are powers of themselves
+/// for every exponent, so no smallest base exists and `None` is returned.
+pub fn is_perfect_power(n: &Natural) -> Option<(Natural, u32)> {
+    if *n <= 1u32 {
+        return None;
+    }
+
+    // Only prime exponents need to be tried: whenever a root is found the
+    // same exponent is tried again on the root.
+    let mut base = n.clone();
+    let mut exp = 1u32;
+    let mut k = 2u64;
+    while k <= base.significant_bits() {
+        if (2..k).take_while(|d| d * d <= k).any(|d| k % d == 0) {
+            k += 1;
+            continue;
+        }
+        match (&base).checked_root(k) {
+            Some(root) => {
+                base = root;
+                exp *= k as u32;
+            }
+            None => k += 1,
+        }
+    }
+    if exp == 1 { None } else { Some((base, exp)) }
+}
+
+// Primes below this bound are looked up in the prime cache by `next_prime`
+// and `prev_prime`.
+const PRIME_CACHE_LOOKUP_LIMIT: u64 = 1 << 16;
+
+// Primes with index below this bound are read from the prime cache by
+// `nth_prime`, larger ones are found by stepping with `next_prime`.
+const NTH_PRIME_CACHE_LIMIT: usize = 1 << 22;
+
+// Return n mod 210 = 2 * 3 * 5 * 7.
+fn residue_mod_210(n: &Natural) -> u32 {
+    u32::try_from(&(n % Natural::from(210u32))).unwrap()
+}
+
+// Whether a number with residue r mod 210 is coprime to 2, 3, 5 and 7.
+fn coprime_to_210(r: u32) -> bool {
+    r % 2 != 0 && r % 3 != 0 && r % 5 != 0 && r % 7 != 0
+}
+
+/// Return the smallest prime strictly greater than `n`.
+pub fn next_prime(n: &Natural) -> Natural {
+    if *n < PRIME_CACHE_LOOKUP_LIMIT {
+        // there is always a prime in (n, 2n]
+        ensure_primes_below(2 * PRIME_CACHE_LOOKUP_LIMIT);
+        let cache = get_prime_cache().read().unwrap();
+        let n = u64::try_from(n).unwrap();
+        return Natural::from(cache[cache.partition_point(|&p| p <= n)]);
+    }
+
+    // step through odd candidates, skipping multiples of 3, 5 and 7
+    let mut candidate = n + Natural::ONE;
+    let mut r = residue_mod_210(&candidate);
+    if r % 2 == 0 {
+        candidate += Natural::ONE;
+        r = (r + 1) % 210;
+    }
+    loop {
+        if coprime_to_210(r) && is_prime(candidate.clone()) {
+            return candidate;
+        }
+        candidate += Natural::from(2u32);
+        r = (r + 2) % 210;
+    }
+}
+
+/// Return the largest prime strictly less than `n`, or `None` if `n <= 2`.
+pub fn prev_prime(n: &Natural) -> Option<Natural> {
+    if *n <= 2u32 {
+        return None;
+    } else if *n <= PRIME_CACHE_LOOKUP_LIMIT {
+        ensure_primes_below(PRIME_CACHE_LOOKUP_LIMIT);
+        let cache = get_prime_cache().read().unwrap();
+        let n = u64::try_from(n).unwrap();
+        return Some(Natural::from(cache[cache.partition_point(|&p| p < n) - 1]));
+    }
+
+    // step through odd candidates, skipping multiples of 3, 5 and 7
+    let mut candidate = n - Natural::ONE;
+    let mut r = residue_mod_210(&candidate);
+    if r % 2 == 0 {
+        candidate -= Natural::ONE;
+        r = (r + 209) % 210;
+    }
+    loop {
+        if coprime_to_210(r) && is_prime(candidate.clone()) {
+            return Some(candidate);
+        }
+        candidate -= Natural::from(2u32);
+        r = (r + 208) % 210;
+    }
+}
+
+/// Return the prime with the given (zero-based) index, so `nth_prime(0) = 2`.
+/// Unlike `get_nth_prime_using_cache` the result is not limited to 64 bits,
+/// though indices beyond the cached range are slow to reach.
+pub fn nth_prime(index: usize) -> Natural {
+    if index < NTH_PRIME_CACHE_LIMIT {
+        return Natural::from(get_nth_prime_using_cache(index));
+    }
+    let mut p = Natural::from(get_nth_prime_using_cache(NTH_PRIME_CACHE_LIMIT - 1));
+    for _ in NTH_PRIME_CACHE_LIMIT..=index {
+        p = next_prime(&p);
+    }
+    p
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_euler_phi() {
+        assert_eq!(euler_phi(&Natural::from(360u32)), 96u32);
+        assert_eq!(euler_phi(&Natural::from(97u32)), 96u32);
+        assert_eq!(euler_phi(&Natural::from(1000003u32)), 1000002u32);
+        assert_eq!(euler_phi(&Natural::ONE), 1u32);
+        assert_eq!(euler_phi(&Natural::ZERO), 0u32);
+
+        let p = (Natural::ONE << 61u64) - Natural::ONE;
+        assert_eq!(euler_phi(&p), &p - Natural::ONE);
+    }
+
+    #[test]
+    fn test_radical() {
+        assert_eq!(radical(&Natural::from(360u32)), 30u32);
+        assert_eq!(radical(&Natural::from(1024u32)), 2u32);
+        assert_eq!(radical(&Natural::from(30u32)), 30u32);
+        assert_eq!(radical(&Natural::ONE), 1u32);
+        assert_eq!(radical(&Natural::ZERO), 0u32);
+    }
+
+    #[test]
+    fn test_moebius() {
+        assert_eq!(moebius(&Natural::from(30u32)), -1);
+        assert_eq!(moebius(&Natural::from(12u32)), 0);
+        assert_eq!(moebius(&Natural::ONE), 1);
+        assert_eq!(moebius(&Natural::from(97u32)), -1);
+        assert_eq!(moebius(&Natural::from(1000003u32 * 3)), 1);
+        assert_eq!(moebius(&Natural::ZERO), 0);
+
+        // the sum over the divisors of n > 1 vanishes
+        let divisors = Natural::from(360u32).factor().divisors();
+        assert_eq!(divisors.iter().map(|d| i32::from(moebius(d))).sum::<i32>(), 0);
+    }
+
+    #[test]
+    fn test_is_perfect_power() {
+        assert_eq!(is_perfect_power(&Natural::from(1024u32)), Some((Natural::from(2u32), 10)));
+        assert_eq!(is_perfect_power(&Natural::from(12u32)), None);
+        assert_eq!(is_perfect_power(&Natural::from(64u32)), Some((Natural::from(2u32), 6)));
+        assert_eq!(is_perfect_power(&Natural::from(36u32)), Some((Natural::from(6u32), 2)));
+        assert_eq!(is_perfect_power(&Natural::from(3125u32)), Some((Natural::from(5u32), 5)));
+        assert_eq!(is_perfect_power(&Natural::from(97u32)), None);
+        assert_eq!(is_perfect_power(&Natural::from(4u32)), Some((Natural::from(2u32), 2)));
+        assert_eq!(is_perfect_power(&Natural::ONE), None);
+        assert_eq!(is_perfect_power(&Natural::ZERO), None);
+
+        let p = (Natural::ONE << 61u64) - Natural::ONE;
+        let n = (&p * &p) * (&p * &p) * &p;
+        assert_eq!(is_perfect_power(&n), Some((p.clone(), 5)));
+        assert_eq!(is_perfect_power(&(n + Natural::ONE)), None);
+    }
+
+    #[test]
+    fn test_next_prime() {
+        assert_eq!(next_prime(&Natural::from(14u32)), 17u32);
+        assert_eq!(next_prime(&Natural::from(17u32)), 19u32);
+        assert_eq!(next_prime(&Natural::ZERO), 2u32);
+        assert_eq!(next_prime(&Natural::ONE), 2u32);
+        assert_eq!(next_prime(&Natural::from(2u32)), 3u32);
+        assert_eq!(next_prime(&Natural::from(65520u32)), 65521u32);
+        assert_eq!(next_prime(&Natural::from(65521u32)), 65537u32);
+        assert_eq!(next_prime(&Natural::from(1000000u32)), 1000003u32);
+
+        let p = (Natural::ONE << 61u64) - Natural::ONE;
+        assert_eq!(next_prime(&(&p - Natural::from(2u32))), p);
+        assert_eq!(next_prime(&(Natural::ONE << 64u64)), (Natural::ONE << 64u64) + Natural::from(13u32));
+    }
+
+    #[test]
+    fn test_prev_prime() {
+        assert_eq!(prev_prime(&Natural::from(17u32)), Some(Natural::from(13u32)));
+        assert_eq!(prev_prime(&Natural::from(14u32)), Some(Natural::from(13u32)));
+        assert_eq!(prev_prime(&Natural::from(3u32)), Some(Natural::from(2u32)));
+        assert_eq!(prev_prime(&Natural::from(2u32)), None);
+        assert_eq!(prev_prime(&Natural::ONE), None);
+        assert_eq!(prev_prime(&Natural::ZERO), None);
+        assert_eq!(prev_prime(&Natural::from(65537u32)), Some(Natural::from(65521u32)));
+        assert_eq!(prev_prime(&Natural::from(1000003u32)), Some(Natural::from(999983u32)));
+
+        let p = (Natural::ONE << 61u64) - Natural::ONE;
+        assert_eq!(prev_prime(&(&p + Natural::ONE)), Some(p));
+        assert_eq!(prev_prime(&(Natural::ONE << 64u64)), Some((Natural::ONE << 64u64) - Natural::from(59u32)));
+    }
+
+    #[test]
+    fn test_nth_prime() {
+        assert_eq!(nth_prime(0), 2u32);
+        assert_eq!(nth_prime(1), 3u32);
+        assert_eq!(nth_prime(99), 541u32);
+        assert_eq!(nth_prime(999_999), 15485863u32);
+        for i in 0..1000 {
+            assert_eq!(nth_prime(i), get_nth_prime_using_cache(i));
+        }
+    }
+}
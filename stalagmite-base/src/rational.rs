@@ -15,7 +15,8 @@
 // You should have received a copy of the GNU General Public License
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::traits::{Element, Parent};
+use crate::traits::{Element, Parent, Ring, RingElement};
+use malachite::base::num::arithmetic::traits::Pow;
 pub use malachite::rational::Rational;
 
 pub struct RationalField;
@@ -33,3 +34,54 @@ impl Element for QQElem {
         QQField {}
     }
 }
+
+impl Ring for QQField {
+    type Element = QQElem;
+}
+
+impl RingElement for QQElem {
+    type Parent = QQField;
+}
+
+/// Return `x^exp`, where `x^0` is one for every `x` and a negative exponent
+/// raises the reciprocal. Panics if `x` is zero and `exp` is negative.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_base::rational::{pow, Rational};
+///
+/// assert_eq!(pow(&Rational::from_signeds(2, 3), -2), Rational::from_signeds(9, 4));
+/// assert_eq!(pow(&Rational::from_signeds(-1, 2), 3), Rational::from_signeds(-1, 8));
+/// assert_eq!(pow(&Rational::from(0), 0), 1);
+/// ```
+pub fn pow(x: &Rational, exp: i64) -> Rational {
+    if exp < 0 && *x == 0 {
+        panic!("Division by zero");
+    }
+    x.pow(exp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pow() {
+        let q = Rational::from_signeds;
+        assert_eq!(pow(&q(2, 3), -2), q(9, 4));
+        assert_eq!(pow(&q(2, 3), 2), q(4, 9));
+        assert_eq!(pow(&q(-2, 3), -3), q(-27, 8));
+        assert_eq!(pow(&q(-5, 7), 1), q(-5, 7));
+        assert_eq!(pow(&q(-5, 7), -1), q(-7, 5));
+        assert_eq!(pow(&q(5, 7), 0), 1);
+        assert_eq!(pow(&q(0, 1), 0), 1);
+        assert_eq!(pow(&q(0, 1), 4), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_pow_zero_negative_exponent() {
+        pow(&Rational::from(0), -1);
+    }
+}
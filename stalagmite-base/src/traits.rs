@@ -15,6 +15,10 @@
 // You should have received a copy of the GNU General Public License
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
 
+use malachite::{Integer, Natural};
+use malachite::base::num::basic::traits as malachite_traits;
+use malachite::rational::Rational;
+
 pub trait Parent {
     type Element;
 }
@@ -37,6 +41,52 @@ pub trait FieldElement: RingElement {
     type Parent: Field;
 }
 
+/// Types with an additive identity. Unlike malachite's `Zero`, the identity
+/// is constructed at runtime, so heap-allocated types such as polynomials can
+/// implement it.
+pub trait AdditiveIdentity: Sized {
+    fn zero() -> Self;
+    fn is_zero(&self) -> bool;
+}
+
+/// Types with a multiplicative identity; see [`AdditiveIdentity`].
+pub trait MultiplicativeIdentity: Sized {
+    fn one() -> Self;
+    fn is_one(&self) -> bool;
+}
+
+macro_rules! impl_identities {
+    ($($t:ty),*) => {
+        $(
+            impl AdditiveIdentity for $t {
+                #[inline]
+                fn zero() -> Self {
+                    <$t as malachite_traits::Zero>::ZERO
+                }
+
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    *self == <$t as malachite_traits::Zero>::ZERO
+                }
+            }
+
+            impl MultiplicativeIdentity for $t {
+                #[inline]
+                fn one() -> Self {
+                    <$t as malachite_traits::One>::ONE
+                }
+
+                #[inline]
+                fn is_one(&self) -> bool {
+                    *self == <$t as malachite_traits::One>::ONE
+                }
+            }
+        )*
+    };
+}
+
+impl_identities!(Integer, Natural, Rational);
+
 pub trait PolynomialRing<R: Ring> {}
 pub trait Polynomial<R: Ring> {}
 
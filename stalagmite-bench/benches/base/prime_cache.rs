@@ -0,0 +1,41 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+extern crate criterion;
+extern crate stalagmite_base;
+
+use criterion::*;
+use stalagmite_base::factor::prime_cache::extend_cache_to;
+
+// Grow a fresh cache from the first ten primes, as the global cache does.
+fn bench_extend_cache(c: &mut Criterion) {
+    let mut group = c.benchmark_group("prime_cache_extend");
+    group.sample_size(10);
+    for target in [10_000usize, 100_000] {
+        group.bench_function(BenchmarkId::from_parameter(target), |b| {
+            b.iter(|| {
+                let mut cache = vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
+                extend_cache_to(&mut cache, target);
+                black_box(cache)
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_extend_cache);
+criterion_main!(benches);
@@ -0,0 +1,69 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+extern crate criterion;
+extern crate stalagmite_poly;
+extern crate stalagmite_bench;
+
+use std::str::FromStr;
+use criterion::*;
+use stalagmite_poly::qq_poly::QQPoly;
+use stalagmite_poly::zz_poly::ZZPoly;
+use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::{Abs, Gcd};
+use stalagmite_bench::generate_coeffs;
+
+// The previous normalization, which took the content through a string round
+// trip of each coefficient.
+fn normalize_via_strings(numerator: &ZZPoly, denominator: &Natural) -> (ZZPoly, Natural) {
+    let mut gcd = denominator.clone();
+    for coeff in numerator.iter() {
+        if *coeff != 0 {
+            let nat_coeff = Natural::from_str(&coeff.abs().to_string()).unwrap();
+            gcd = Natural::gcd(gcd.clone(), nat_coeff);
+        }
+    }
+    let divisor = Integer::from(&gcd);
+    let coeffs = numerator.iter().map(|c| c / &divisor).collect();
+    (ZZPoly::from_raw(coeffs), denominator / &gcd)
+}
+
+// Normalize numerators sharing a large common factor with the denominator.
+fn bench_normalize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("QQPoly normalize (1000-bit coefficients)");
+
+    let bits = 1000;
+    for &len in [16usize, 64, 256].iter() {
+        let common = Integer::from(generate_coeffs(1, 500, 0x0F0F0F0F0F0F0F0F)[0].unsigned_abs_ref().clone());
+        let numerator = ZZPoly::from_raw(
+            generate_coeffs(len, bits, 0x1234567890ABCDEF).into_iter().map(|c| c * &common).collect()
+        );
+        let denominator = Natural::from(12345u32) * common.unsigned_abs_ref();
+
+        group.bench_function(BenchmarkId::new("gcd", len), |b| {
+            b.iter(|| black_box(QQPoly::from_raw(numerator.clone(), denominator.clone())))
+        });
+
+        group.bench_function(BenchmarkId::new("strings", len), |b| {
+            b.iter(|| black_box(normalize_via_strings(&numerator, &denominator)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_normalize);
+criterion_main!(benches);
@@ -0,0 +1,70 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+extern crate criterion;
+extern crate stalagmite_poly;
+
+use criterion::*;
+use stalagmite_poly::zz_poly::arithmetic::mullow::{auto_mullow, mullow_into};
+use malachite::Integer;
+use rand::{Rng, SeedableRng};
+use rand::rngs::SmallRng;
+
+fn generate_mixed_sign_coeffs(size: usize, max_coeff: i64) -> Vec<Integer> {
+    let mut rng = SmallRng::seed_from_u64(0x1234567890ABCDEF); // Fixed seed for reproducible benchmarks
+    (0..size).map(|_| {
+        let val = rng.random_range(1..=max_coeff);
+        Integer::from(if rng.random_bool(0.5) { val } else { -val })
+    }).collect()
+}
+
+// Many truncated products in a row, as in a Newton iteration, allocating a
+// new vector each time against reusing one buffer. The sizes above 16 take
+// the Karatsuba path.
+fn bench_mullow_repeated(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ZZPoly mullow - 100 iterations");
+    let plot_config = PlotConfiguration::default().summary_scale(AxisScale::Logarithmic);
+    group.plot_config(plot_config);
+
+    let iterations = 100;
+    for &size in [4usize, 8, 15, 32, 64].iter() {
+        let poly1 = generate_mixed_sign_coeffs(size, 1000);
+        let poly2 = generate_mixed_sign_coeffs(size + 1, 1000);
+
+        group.bench_function(BenchmarkId::new("auto_mullow", size), |b| {
+            b.iter(|| {
+                for _ in 0..iterations {
+                    black_box(auto_mullow(&poly1, size, &poly2, size + 1, size));
+                }
+            })
+        });
+
+        group.bench_function(BenchmarkId::new("mullow_into", size), |b| {
+            let mut out = Vec::new();
+            b.iter(|| {
+                for _ in 0..iterations {
+                    mullow_into(&poly1, size, &poly2, size + 1, size, &mut out);
+                    black_box(&out);
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_mullow_repeated);
+criterion_main!(benches);
@@ -0,0 +1,56 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+extern crate criterion;
+extern crate stalagmite_poly;
+extern crate stalagmite_bench;
+
+use criterion::*;
+use stalagmite_poly::zz_poly::ZZPoly;
+use stalagmite_poly::zz_poly::arithmetic::mul_karatsuba::mul_karatsuba;
+use stalagmite_poly::zz_poly::arithmetic::mul_ks::mul_ks;
+use stalagmite_poly::zz_poly::arithmetic::mul_multimodular::mul_multimodular;
+use stalagmite_bench::generate_coeffs;
+
+// Multi-modular multiplication against Kronecker substitution and Karatsuba
+// for thousand-bit coefficients.
+fn bench_multimodular_vs_ks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ZZPoly Mul - multimodular vs KS (1000-bit coefficients)");
+    group.sample_size(10);
+
+    let bits = 1000;
+    for &len in [16usize, 64, 256, 1024].iter() {
+        let poly_a = ZZPoly::from(generate_coeffs(len, bits, 0x1234567890ABCDEF));
+        let poly_b = ZZPoly::from(generate_coeffs(len, bits, 0xFEDCBA0987654321));
+
+        group.bench_function(BenchmarkId::new("multimodular", len), |b| {
+            b.iter(|| black_box(mul_multimodular(&poly_a, &poly_b)))
+        });
+
+        group.bench_function(BenchmarkId::new("ks", len), |b| {
+            b.iter(|| black_box(mul_ks(&poly_a, &poly_b)))
+        });
+
+        group.bench_function(BenchmarkId::new("karatsuba", len), |b| {
+            b.iter(|| black_box(mul_karatsuba(&poly_a, &poly_b)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_multimodular_vs_ks);
+criterion_main!(benches);
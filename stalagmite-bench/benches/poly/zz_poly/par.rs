@@ -0,0 +1,87 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+extern crate criterion;
+extern crate stalagmite_poly;
+
+use criterion::*;
+use stalagmite_poly::zz_poly::ZZPoly;
+use malachite::Integer;
+use malachite::base::num::logic::traits::SignificantBits;
+use rand::{Rng, SeedableRng};
+use rand::rngs::SmallRng;
+
+// Random multi-limb coefficients of roughly `bits` bits.
+fn generate_big_coeffs(size: usize, bits: u64) -> Vec<Integer> {
+    let mut rng = SmallRng::seed_from_u64(0x1234567890ABCDEF); // Fixed seed for reproducible benchmarks
+    (0..size).map(|_| {
+        let mut x = Integer::from(rng.random_range(1..=i64::MAX));
+        while x.significant_bits() < bits {
+            x = (x << 63u64) + Integer::from(rng.random_range(0..=i64::MAX));
+        }
+        x
+    }).collect()
+}
+
+// Serial vs parallel runs of the same operation. The serial path is measured
+// by running inside a single-threaded rayon pool.
+fn bench_scalar_mul_serial_vs_parallel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ZZPoly scalar Mul - serial vs parallel");
+    group.sample_size(10);
+
+    let serial = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+    let scalar = Integer::from(0x1234567890ABCDEFu64) << 100u64;
+
+    for &size in [10_000usize, 100_000].iter() {
+        let poly = ZZPoly::from(generate_big_coeffs(size, 512));
+
+        group.bench_function(BenchmarkId::new("serial", size), |b| {
+            b.iter(|| serial.install(|| black_box(&poly * &scalar)))
+        });
+
+        group.bench_function(BenchmarkId::new("parallel", size), |b| {
+            b.iter(|| black_box(&poly * &scalar))
+        });
+    }
+    group.finish();
+}
+
+fn bench_add_serial_vs_parallel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ZZPoly Add - serial vs parallel");
+    group.sample_size(10);
+
+    let serial = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+    let size = 100_000;
+    let poly_a = ZZPoly::from(generate_big_coeffs(size, 512));
+    let poly_b = ZZPoly::from(generate_big_coeffs(size, 256));
+
+    group.bench_function(BenchmarkId::new("serial", size), |b| {
+        b.iter(|| serial.install(|| black_box(&poly_a + &poly_b)))
+    });
+
+    group.bench_function(BenchmarkId::new("parallel", size), |b| {
+        b.iter(|| black_box(&poly_a + &poly_b))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    par_benches,
+    bench_scalar_mul_serial_vs_parallel,
+    bench_add_serial_vs_parallel
+);
+criterion_main!(par_benches);
@@ -0,0 +1,87 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+extern crate criterion;
+extern crate stalagmite_poly;
+
+use criterion::*;
+use stalagmite_poly::zz_poly::ZZPoly;
+use malachite::Integer;
+use malachite::base::num::arithmetic::traits::Pow;
+use rand::{Rng, SeedableRng};
+use rand::rngs::SmallRng;
+
+fn generate_mixed_sign_coeffs(size: usize, max_coeff: i64) -> Vec<i64> {
+    let mut rng = SmallRng::seed_from_u64(0x1234567890ABCDEF); // Fixed seed for reproducible benchmarks
+    (0..size).map(|_| {
+        let val = rng.random_range(1..=max_coeff);
+        if rng.random_bool(0.5) { val } else { -val }
+    }).collect()
+}
+
+// Scaling by a power of two with a shift against multiplying by the power.
+// Both allocate for every coefficient, so the shift only pays off once the
+// coefficients and the shift span many limbs.
+fn bench_scale_pow2(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ZZPoly scale - power of two (2000-bit coefficients)");
+    let plot_config = PlotConfiguration::default().summary_scale(AxisScale::Logarithmic);
+    group.plot_config(plot_config);
+
+    let shift = 1000;
+    let factor = Integer::from(2).pow(shift);
+    for &size in [10usize, 100, 1000, 5000].iter() {
+        let coeffs: Vec<Integer> = generate_mixed_sign_coeffs(size, i64::MAX)
+            .into_iter()
+            .map(|c| Integer::from(c) << 2000u64)
+            .collect();
+        let poly = ZZPoly::from(coeffs);
+
+        group.bench_function(BenchmarkId::new("scale_pow2_assign", size), |b| {
+            b.iter_with_setup(
+                || poly.clone(),
+                |mut p| {
+                    p.scale_pow2_assign(shift);
+                    black_box(p)
+                }
+            )
+        });
+
+        group.bench_function(BenchmarkId::new("scale_assign", size), |b| {
+            b.iter_with_setup(
+                || poly.clone(),
+                |mut p| {
+                    p.scale_assign(&factor);
+                    black_box(p)
+                }
+            )
+        });
+
+        group.bench_function(BenchmarkId::new("mul_assign", size), |b| {
+            b.iter_with_setup(
+                || poly.clone(),
+                |mut p| {
+                    p *= &factor;
+                    black_box(p)
+                }
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_scale_pow2);
+criterion_main!(benches);
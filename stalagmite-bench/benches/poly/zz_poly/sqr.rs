@@ -0,0 +1,80 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+extern crate criterion;
+extern crate stalagmite_poly;
+extern crate stalagmite_bench;
+
+use criterion::*;
+use stalagmite_poly::zz_poly::ZZPoly;
+use stalagmite_poly::zz_poly::arithmetic::sqr::sqr;
+use stalagmite_bench::generate_coeffs;
+
+// Squaring a degree 500 polynomial against a generic multiplication of two
+// distinct polynomials of the same shape.
+fn bench_sqr_vs_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ZZPoly Sqr - sqr vs generic mul (degree 500)");
+    group.sample_size(10);
+
+    let len = 501;
+    for &bits in [10u64, 64, 1024, 4096].iter() {
+        let poly_a = ZZPoly::from(generate_coeffs(len, bits, 0x1234567890ABCDEF));
+        let poly_b = ZZPoly::from(generate_coeffs(len, bits, 0xFEDCBA0987654321));
+
+        group.bench_function(BenchmarkId::new("sqr", bits), |b| {
+            b.iter(|| black_box(sqr(&poly_a)))
+        });
+
+        group.bench_function(BenchmarkId::new("ref_ref_same", bits), |b| {
+            b.iter(|| black_box(&poly_a * &poly_a))
+        });
+
+        group.bench_function(BenchmarkId::new("mul", bits), |b| {
+            b.iter(|| black_box(&poly_a * &poly_b))
+        });
+    }
+    group.finish();
+}
+
+// Short polynomials with large coefficients, where Karatsuba squaring is now
+// used below the length threshold.
+fn bench_sqr_large_coeffs_short(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ZZPoly Sqr - short, large coefficients");
+    group.sample_size(10);
+
+    let bits = 20_000;
+    for &len in [9usize, 12, 15].iter() {
+        let poly_a = ZZPoly::from(generate_coeffs(len, bits, 0x1234567890ABCDEF));
+        let poly_b = ZZPoly::from(generate_coeffs(len, bits, 0xFEDCBA0987654321));
+
+        group.bench_function(BenchmarkId::new("sqr", len), |b| {
+            b.iter(|| black_box(sqr(&poly_a)))
+        });
+
+        group.bench_function(BenchmarkId::new("mul", len), |b| {
+            b.iter(|| black_box(&poly_a * &poly_b))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    sqr_benches,
+    bench_sqr_vs_mul,
+    bench_sqr_large_coeffs_short
+);
+criterion_main!(sqr_benches);
@@ -0,0 +1,63 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+extern crate criterion;
+extern crate stalagmite_zn;
+extern crate stalagmite_bench;
+extern crate malachite;
+
+use criterion::*;
+use malachite::base::num::logic::traits::BitAccess;
+use malachite::natural::random::get_random_natural_with_up_to_bits;
+use malachite::base::num::random::random_primitive_ints;
+use stalagmite_bench::BENCH_SEED;
+use stalagmite_zn::ZnRing;
+
+// Square and multiply against sliding window exponentiation for a 2048-bit
+// odd modulus and a 2048-bit exponent.
+fn bench_pow_2048(c: &mut Criterion) {
+    let mut group = c.benchmark_group("zn_pow_2048");
+    group.sample_size(10);
+    let mut rng = random_primitive_ints(BENCH_SEED);
+
+    let mut modulus = get_random_natural_with_up_to_bits(&mut rng, 2048);
+    modulus.set_bit(2047);
+    modulus.set_bit(0);
+    let mut exp = get_random_natural_with_up_to_bits(&mut rng, 2048);
+    exp.set_bit(2047);
+
+    for (name, ring) in [
+        ("standard", ZnRing::init(modulus.clone())),
+        ("montgomery", ZnRing::init_montgomery(modulus.clone())),
+    ] {
+        let a = ring.random_element(&mut rng);
+
+        group.bench_function(BenchmarkId::new("pow", name), |b| {
+            b.iter(|| black_box(a.pow(&exp)))
+        });
+
+        for window in [4usize, 5, 6] {
+            group.bench_function(BenchmarkId::new(format!("pow_windowed_{}", window), name), |b| {
+                b.iter(|| black_box(a.pow_windowed(&exp, window)))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_pow_2048);
+criterion_main!(benches);
@@ -1,3 +1,5 @@
+use malachite::Integer;
+use malachite::base::num::logic::traits::SignificantBits;
 use malachite::base::random::Seed;
 use rand::{Rng, SeedableRng};
 use rand::rngs::SmallRng;
@@ -8,6 +10,19 @@ pub const BENCH_SEED: Seed = Seed::from_bytes([
     0xff, 0x71, 0xe0, 0xf8, 0x0b, 0x2a, 0xcf, 0x27, 0x85, 0xb3, 0x32, 0xc6, 0x20, 0x80, 0x5e, 0x36,
 ]);
 
+/// Random coefficients of roughly `bits` bits with random signs. The same
+/// seed always gives the same coefficients.
+pub fn generate_coeffs(size: usize, bits: u64, seed: u64) -> Vec<Integer> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    (0..size).map(|_| {
+        let mut x = Integer::from(rng.random_range(1..=i64::MAX));
+        while x.significant_bits() < bits {
+            x = (x << 63u64) + Integer::from(rng.random_range(0..=i64::MAX));
+        }
+        if rng.random_bool(0.5) { -x } else { x }
+    }).collect()
+}
+
 //pub fn generate_random_coeffs(size: usize, min_coeff: i32, max_coeff: i32) -> Vec<i32> {
 //    let mut rng = SmallRng::seed_from_u64(BENCH_SEED);
 //    (0..size).map(|_| rng.random_range(min_coeff..=max_coeff)).collect()
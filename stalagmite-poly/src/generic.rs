@@ -16,14 +16,203 @@
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
 
 use std::rc::Rc;
+use malachite::{Integer, Natural};
+use malachite::rational::Rational;
 use stalagmite_base::traits::{
     Parent,
     Element,
     Ring,
     RingElement,
     PolynomialRing,
-    Polynomial
+    AdditiveIdentity,
+    MultiplicativeIdentity,
 };
+use stalagmite_zn::ZnElem;
+use crate::qq_poly::QQPoly;
+use crate::zn_poly::ZnPoly;
+use crate::zz_poly::ZZPoly;
+
+/// The interface shared by the concrete polynomial types, for writing
+/// algorithms that work over any of them.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_poly::generic::Polynomial;
+/// use stalagmite_poly::zz_poly::ZZPoly;
+/// use malachite::Integer;
+///
+/// fn is_constant<P: Polynomial>(p: &P) -> bool {
+///     p.degree() == 0
+/// }
+///
+/// let p = ZZPoly::from(vec![1, -2, 3]);
+/// assert!(!is_constant(&p));
+/// assert_eq!(Polynomial::evaluate(&p, &Integer::from(2)), Integer::from(9));
+/// ```
+pub trait Polynomial {
+    type Coefficient;
+
+    /// Return the number of coefficients, which is zero for the zero
+    /// polynomial.
+    fn length(&self) -> usize;
+
+    /// Return the degree, taking the degree of the zero polynomial to be zero.
+    fn degree(&self) -> usize;
+
+    fn is_zero(&self) -> bool;
+
+    /// Return the coefficient of `x^i`, which is zero if `i` is past the end.
+    fn coeff(&self, i: usize) -> Self::Coefficient;
+
+    /// Return the leading coefficient, or `None` for the zero polynomial.
+    fn leading_coefficient(&self) -> Option<Self::Coefficient>;
+
+    /// Evaluate the polynomial at `x`.
+    fn evaluate(&self, x: &Self::Coefficient) -> Self::Coefficient;
+}
+
+impl Polynomial for ZZPoly {
+    type Coefficient = Integer;
+
+    fn length(&self) -> usize {
+        ZZPoly::length(self)
+    }
+
+    fn degree(&self) -> usize {
+        ZZPoly::degree(self)
+    }
+
+    fn is_zero(&self) -> bool {
+        ZZPoly::is_zero(self)
+    }
+
+    fn coeff(&self, i: usize) -> Integer {
+        ZZPoly::coeff(self, i)
+    }
+
+    fn leading_coefficient(&self) -> Option<Integer> {
+        ZZPoly::leading_coefficient(self).cloned()
+    }
+
+    fn evaluate(&self, x: &Integer) -> Integer {
+        ZZPoly::evaluate(self, x)
+    }
+}
+
+impl Polynomial for QQPoly {
+    type Coefficient = Rational;
+
+    fn length(&self) -> usize {
+        QQPoly::length(self)
+    }
+
+    fn degree(&self) -> usize {
+        QQPoly::degree(self)
+    }
+
+    fn is_zero(&self) -> bool {
+        QQPoly::is_zero(self)
+    }
+
+    fn coeff(&self, i: usize) -> Rational {
+        let (num, den) = QQPoly::coeff(self, i);
+        Rational::from_integers(num, Integer::from(den))
+    }
+
+    fn leading_coefficient(&self) -> Option<Rational> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(Polynomial::coeff(self, self.degree()))
+        }
+    }
+
+    fn evaluate(&self, x: &Rational) -> Rational {
+        QQPoly::evaluate(self, x)
+    }
+}
+
+impl Polynomial for ZnPoly {
+    type Coefficient = ZnElem;
+
+    fn length(&self) -> usize {
+        ZnPoly::length(self)
+    }
+
+    fn degree(&self) -> usize {
+        ZnPoly::degree(self)
+    }
+
+    fn is_zero(&self) -> bool {
+        ZnPoly::is_zero(self)
+    }
+
+    fn coeff(&self, i: usize) -> ZnElem {
+        self.get(i).cloned().unwrap_or_else(|| self.ring().new(Natural::from(0u32)))
+    }
+
+    fn leading_coefficient(&self) -> Option<ZnElem> {
+        ZnPoly::leading_coefficient(self).cloned()
+    }
+
+    /// Evaluate the polynomial at `x`. Panics if `x` is in a different ring.
+    fn evaluate(&self, x: &ZnElem) -> ZnElem {
+        let mut result = self.ring().new(Natural::from(0u32));
+        for c in self.iter().rev() {
+            result = &result * x + c;
+        }
+        result
+    }
+}
+
+impl AdditiveIdentity for ZZPoly {
+    #[inline]
+    fn zero() -> Self {
+        ZZPoly::zero()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        ZZPoly::is_zero(self)
+    }
+}
+
+impl MultiplicativeIdentity for ZZPoly {
+    #[inline]
+    fn one() -> Self {
+        ZZPoly::one()
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        ZZPoly::is_one(self)
+    }
+}
+
+impl AdditiveIdentity for QQPoly {
+    #[inline]
+    fn zero() -> Self {
+        QQPoly::zero()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        QQPoly::is_zero(self)
+    }
+}
+
+impl MultiplicativeIdentity for QQPoly {
+    #[inline]
+    fn one() -> Self {
+        QQPoly::one()
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        QQPoly::is_one(self)
+    }
+}
 
 pub struct PolyCtx<R: Ring> {
     base_ring: R,
@@ -68,7 +257,7 @@ impl<R: Ring> RingElement for GenericPoly<R> {
     type Parent = GenericPolyRing<R>;
 }
 
-impl<R: Ring> Polynomial<R> for GenericPoly<R> {}
+impl<R: Ring> stalagmite_base::traits::Polynomial<R> for GenericPoly<R> {}
 
 
 
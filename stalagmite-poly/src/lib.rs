@@ -18,6 +18,7 @@
 pub mod poly;
 pub mod zz_poly;
 pub mod qq_poly;
+pub mod zn_poly;
 pub mod generic;
 
 #[cfg(test)]
@@ -19,6 +19,7 @@ use std::ops::{Add, AddAssign};
 use malachite::{Integer, Natural};
 use malachite::base::num::arithmetic::traits::Lcm;
 use crate::qq_poly::QQPoly;
+use crate::zz_poly::ZZPoly;
 
 // Addition: QQPoly + QQPoly
 impl Add<QQPoly> for QQPoly {
@@ -97,4 +98,90 @@ impl AddAssign<&QQPoly> for QQPoly {
     fn add_assign(&mut self, rhs: &QQPoly) {
         *self = self.clone() + rhs;
     }
-}
\ No newline at end of file
+}
+
+// Addition: QQPoly + ZZPoly
+impl Add<ZZPoly> for QQPoly {
+    type Output = QQPoly;
+
+    fn add(self, rhs: ZZPoly) -> QQPoly {
+        self + QQPoly::from(rhs)
+    }
+}
+
+// Addition: QQPoly + &ZZPoly
+impl Add<&ZZPoly> for QQPoly {
+    type Output = QQPoly;
+
+    fn add(self, rhs: &ZZPoly) -> QQPoly {
+        self + QQPoly::from(rhs.clone())
+    }
+}
+
+// Addition: &QQPoly + ZZPoly
+impl Add<ZZPoly> for &QQPoly {
+    type Output = QQPoly;
+
+    fn add(self, rhs: ZZPoly) -> QQPoly {
+        self + QQPoly::from(rhs)
+    }
+}
+
+// Addition: &QQPoly + &ZZPoly
+impl Add<&ZZPoly> for &QQPoly {
+    type Output = QQPoly;
+
+    fn add(self, rhs: &ZZPoly) -> QQPoly {
+        self + QQPoly::from(rhs.clone())
+    }
+}
+
+// Addition: ZZPoly + QQPoly
+impl Add<QQPoly> for ZZPoly {
+    type Output = QQPoly;
+
+    fn add(self, rhs: QQPoly) -> QQPoly {
+        QQPoly::from(self) + rhs
+    }
+}
+
+// Addition: ZZPoly + &QQPoly
+impl Add<&QQPoly> for ZZPoly {
+    type Output = QQPoly;
+
+    fn add(self, rhs: &QQPoly) -> QQPoly {
+        QQPoly::from(self) + rhs
+    }
+}
+
+// Addition: &ZZPoly + QQPoly
+impl Add<QQPoly> for &ZZPoly {
+    type Output = QQPoly;
+
+    fn add(self, rhs: QQPoly) -> QQPoly {
+        QQPoly::from(self.clone()) + rhs
+    }
+}
+
+// Addition: &ZZPoly + &QQPoly
+impl Add<&QQPoly> for &ZZPoly {
+    type Output = QQPoly;
+
+    fn add(self, rhs: &QQPoly) -> QQPoly {
+        QQPoly::from(self.clone()) + rhs
+    }
+}
+
+// AddAssign: QQPoly += ZZPoly
+impl AddAssign<ZZPoly> for QQPoly {
+    fn add_assign(&mut self, rhs: ZZPoly) {
+        *self += QQPoly::from(rhs);
+    }
+}
+
+// AddAssign: QQPoly += &ZZPoly
+impl AddAssign<&ZZPoly> for QQPoly {
+    fn add_assign(&mut self, rhs: &ZZPoly) {
+        *self += QQPoly::from(rhs.clone());
+    }
+}
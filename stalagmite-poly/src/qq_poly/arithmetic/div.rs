@@ -0,0 +1,128 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use std::ops::{Div, DivAssign};
+use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::{Pow, UnsignedAbs};
+use stalagmite_base::StalagmiteError;
+use crate::qq_poly::QQPoly;
+
+impl QQPoly {
+    /// Return the quotient and remainder of `self` divided by `other`, i.e.
+    /// `(q, r)` with `self = q * other + r` and `deg(r) < deg(other)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::qq_poly::QQPoly;
+    /// use malachite::{Integer, Natural};
+    ///
+    /// // x^2 + 1 = (x/2 - 1/4)(2x + 1) + 5/4
+    /// let a = QQPoly::from(vec![1, 0, 1]);
+    /// let b = QQPoly::from(vec![1, 2]);
+    /// let (q, r) = a.div_rem(&b);
+    /// assert_eq!(q, QQPoly::from((vec![-1, 2], Natural::from(4u32))));
+    /// assert_eq!(r, QQPoly::from((vec![5], Natural::from(4u32))));
+    /// ```
+    pub fn div_rem(&self, other: &QQPoly) -> (QQPoly, QQPoly) {
+        if other.is_zero() {
+            panic!("{}", StalagmiteError::DivisionByZero);
+        }
+        if self.length() < other.length() {
+            return (QQPoly::zero(), self.clone());
+        }
+
+        // With a = N1/d1, b = N2/d2 and lc(N2)^k N1 = Q N2 + R we have
+        // a = (Q d2 / (lc^k d1)) b + R / (lc^k d1).
+        let k = (self.length() - other.length() + 1) as u64;
+        let lead = &other.numerator[other.length() - 1];
+        let (mut q, mut r) = self.numerator.pseudo_divrem(&other.numerator);
+        if *lead < 0 && k % 2 == 1 {
+            q.negate_assign();
+            r.negate_assign();
+        }
+        let scale: Natural = lead.unsigned_abs().pow(k) * &self.denominator;
+
+        let quotient = QQPoly::from_raw(q * Integer::from(&other.denominator), scale.clone());
+        let remainder = QQPoly::from_raw(r, scale);
+        (quotient, remainder)
+    }
+
+    // Exact division, panicking if there is a nonzero remainder.
+    fn div_exact(&self, other: &QQPoly) -> QQPoly {
+        let (q, r) = self.div_rem(other);
+        if !r.is_zero() {
+            panic!("Polynomial division is not exact");
+        }
+        q
+    }
+}
+
+// Division: QQPoly / QQPoly. Division is only defined when the remainder is
+// zero; use div_rem otherwise.
+impl Div<QQPoly> for QQPoly {
+    type Output = QQPoly;
+
+    fn div(self, rhs: QQPoly) -> QQPoly {
+        self.div_exact(&rhs)
+    }
+}
+
+// Division: QQPoly / &QQPoly
+impl Div<&QQPoly> for QQPoly {
+    type Output = QQPoly;
+
+    fn div(self, rhs: &QQPoly) -> QQPoly {
+        self.div_exact(rhs)
+    }
+}
+
+// Division: &QQPoly / QQPoly
+impl Div<QQPoly> for &QQPoly {
+    type Output = QQPoly;
+
+    fn div(self, rhs: QQPoly) -> QQPoly {
+        self.div_exact(&rhs)
+    }
+}
+
+// Division: &QQPoly / &QQPoly
+impl Div<&QQPoly> for &QQPoly {
+    type Output = QQPoly;
+
+    fn div(self, rhs: &QQPoly) -> QQPoly {
+        self.div_exact(rhs)
+    }
+}
+
+// DivAssign: QQPoly /= QQPoly
+impl DivAssign<QQPoly> for QQPoly {
+    fn div_assign(&mut self, rhs: QQPoly) {
+        *self = self.div_exact(&rhs);
+    }
+}
+
+// DivAssign: QQPoly /= &QQPoly
+impl DivAssign<&QQPoly> for QQPoly {
+    fn div_assign(&mut self, rhs: &QQPoly) {
+        *self = self.div_exact(rhs);
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::rational::Rational;
+use crate::qq_poly::QQPoly;
+use crate::zz_poly::ZZPoly;
+use crate::zz_poly::arithmetic::mullow::mullow;
+
+// Product of a and b truncated to its first n coefficients.
+fn qq_mullow(a: &QQPoly, b: &QQPoly, n: usize) -> QQPoly {
+    QQPoly::from_raw(mullow(&a.numerator, &b.numerator, n), &a.denominator * &b.denominator)
+}
+
+// The polynomial truncated to its first n coefficients.
+fn truncate(f: &QQPoly, n: usize) -> QQPoly {
+    let coeffs = f.numerator.as_coeffs();
+    let numerator = ZZPoly::from(coeffs[..n.min(coeffs.len())].to_vec());
+    QQPoly::from_raw(numerator, f.denominator.clone())
+}
+
+// The formal derivative of f.
+fn derivative(f: &QQPoly) -> QQPoly {
+    QQPoly::from_raw(f.numerator.derivative(), f.denominator.clone())
+}
+
+// The antiderivative of f with zero constant term.
+fn integral(f: &QQPoly) -> QQPoly {
+    let mut coeffs = vec![Rational::from(0); f.length() + 1];
+    for (i, c) in f.terms() {
+        coeffs[i + 1] = c / Rational::from(i + 1);
+    }
+    QQPoly::from(coeffs)
+}
+
+// log(f) mod x^n for f with constant term 1, as the integral of f'/f.
+fn log_series_unchecked(f: &QQPoly, n: usize) -> QQPoly {
+    if n <= 1 {
+        return QQPoly::zero();
+    }
+    let inv = f.inv_series(n - 1).unwrap();
+    integral(&qq_mullow(&derivative(f), &inv, n - 1))
+}
+
+impl QQPoly {
+    /// Return the exponential of the polynomial as a power series truncated
+    /// to its first `n` coefficients, or `None` if the constant term is not
+    /// zero.
+    ///
+    /// Uses Newton iteration `g <- g(1 + f - log g)`, doubling the precision
+    /// at each step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::qq_poly::QQPoly;
+    /// use malachite::rational::Rational;
+    ///
+    /// // exp(x) = 1 + x + x^2/2 + x^3/6 + ...
+    /// let p = QQPoly::from(vec![0, 1]);
+    /// let expected = QQPoly::from(vec![
+    ///     Rational::from(1),
+    ///     Rational::from(1),
+    ///     Rational::from_unsigneds(1u32, 2),
+    ///     Rational::from_unsigneds(1u32, 6),
+    /// ]);
+    /// assert_eq!(p.exp_series(4).unwrap(), expected);
+    /// assert!(QQPoly::from(vec![1, 1]).exp_series(4).is_none());
+    /// ```
+    pub fn exp_series(&self, n: usize) -> Option<QQPoly> {
+        if self.constant_term() != 0u32 {
+            return None;
+        }
+        if n == 0 {
+            return Some(QQPoly::zero());
+        }
+
+        let one = QQPoly::one();
+        let mut g = QQPoly::one();
+        let mut prec = 1;
+        while prec < n {
+            prec = usize::min(2 * prec, n);
+            let t = &one + truncate(self, prec) - log_series_unchecked(&g, prec);
+            g = qq_mullow(&g, &t, prec);
+        }
+        Some(g)
+    }
+
+    /// Return the logarithm of the polynomial as a power series truncated to
+    /// its first `n` coefficients, or `None` if the constant term is not one.
+    ///
+    /// Computed as the integral of `f'/f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::qq_poly::QQPoly;
+    /// use malachite::rational::Rational;
+    ///
+    /// // log(1 + x) = x - x^2/2 + x^3/3 - ...
+    /// let p = QQPoly::from(vec![1, 1]);
+    /// let expected = QQPoly::from(vec![
+    ///     Rational::from(0),
+    ///     Rational::from(1),
+    ///     Rational::from_signeds(-1, 2),
+    ///     Rational::from_signeds(1, 3),
+    /// ]);
+    /// assert_eq!(p.log_series(4).unwrap(), expected);
+    /// assert!(QQPoly::from(vec![2, 1]).log_series(4).is_none());
+    /// ```
+    pub fn log_series(&self, n: usize) -> Option<QQPoly> {
+        if self.constant_term() != 1u32 {
+            return None;
+        }
+        Some(log_series_unchecked(self, n))
+    }
+}
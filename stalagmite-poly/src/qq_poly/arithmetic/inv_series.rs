@@ -0,0 +1,77 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::UnsignedAbs;
+use crate::qq_poly::QQPoly;
+use crate::zz_poly::ZZPoly;
+use crate::zz_poly::arithmetic::mullow::mullow;
+
+impl QQPoly {
+    /// Return the inverse of the polynomial as a power series truncated to
+    /// its first `n` coefficients, or `None` if the constant term is zero.
+    ///
+    /// Uses Newton iteration `g <- g(2 - fg)`, doubling the precision at each
+    /// step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::qq_poly::QQPoly;
+    ///
+    /// // 1/(1 - x) = 1 + x + x^2 + ...
+    /// let p = QQPoly::from(vec![1, -1]);
+    /// assert_eq!(p.inv_series(4).unwrap(), QQPoly::from(vec![1, 1, 1, 1]));
+    /// assert!(QQPoly::from(vec![0, 1]).inv_series(4).is_none());
+    /// ```
+    pub fn inv_series(&self, n: usize) -> Option<QQPoly> {
+        let c0 = match self.numerator.first() {
+            Some(c) if *c != 0 => c,
+            _ => return None,
+        };
+        if n == 0 {
+            return Some(QQPoly::zero());
+        }
+
+        // g = G/e with f = N/d, starting from g = 1/f(0) = d/N[0].
+        let d = &self.denominator;
+        let mut g = if *c0 < 0 {
+            ZZPoly::from_raw(vec![-Integer::from(d)])
+        } else {
+            ZZPoly::from_raw(vec![Integer::from(d)])
+        };
+        let mut e: Natural = c0.unsigned_abs();
+
+        let mut prec = 1;
+        while prec < n {
+            prec = usize::min(2 * prec, n);
+
+            // fg = mullow(N, G)/(de), so 2 - fg = (2de - mullow(N, G))/(de)
+            let de = d * &e;
+            let two_de = ZZPoly::from_raw(vec![Integer::from(&de) * Integer::from(2)]);
+            let t = two_de - mullow(&self.numerator, &g, prec);
+            g = mullow(&g, &t, prec);
+            e *= de;
+
+            // Keep the numerator and denominator coprime.
+            let reduced = QQPoly::from_raw(g, e);
+            g = reduced.numerator;
+            e = reduced.denominator;
+        }
+        Some(QQPoly::from_raw(g, e))
+    }
+}
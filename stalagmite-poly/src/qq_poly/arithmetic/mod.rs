@@ -18,7 +18,10 @@
 pub mod add;
 pub mod sub;
 pub mod mul;
+pub mod div;
+pub mod inv_series;
+pub mod exp_log_series;
 
 pub use add::*;
 pub use sub::*;
-pub use mul::*;
\ No newline at end of file
+pub use mul::*;
@@ -19,7 +19,10 @@ use std::ops::{Mul, MulAssign};
 use std::mem::swap;
 use malachite::rational::Rational;
 use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::Pow;
 use crate::qq_poly::QQPoly;
+use crate::zz_poly::ZZPoly;
+use crate::zz_poly::arithmetic::sqr::sqr;
 
 // Multiplication: QQPoly * QQPoly
 impl Mul<QQPoly> for QQPoly {
@@ -144,8 +147,8 @@ impl Mul<Rational> for QQPoly {
             return QQPoly::zero();
         }
         
-        // Multiply numerator by the integer
-        self.numerator *= rhs.numerator_ref();
+        // Multiply by the signed numerator and the denominator
+        self.numerator *= Integer::from_sign_and_abs_ref(rhs > 0, rhs.numerator_ref());
         self.denominator *= rhs.denominator_ref();
         self.normalize();
         self
@@ -161,7 +164,7 @@ impl Mul<&Rational> for QQPoly {
             return QQPoly::zero();
         }
         
-        self.numerator *= rhs.numerator_ref();
+        self.numerator *= Integer::from_sign_and_abs_ref(*rhs > 0, rhs.numerator_ref());
         self.denominator *= rhs.denominator_ref();
         self.normalize();
         self
@@ -185,3 +188,124 @@ impl Mul<QQPoly> for &Rational {
         rhs * self
     }
 }
+
+// Multiplication: QQPoly * ZZPoly
+impl Mul<ZZPoly> for QQPoly {
+    type Output = QQPoly;
+
+    fn mul(self, rhs: ZZPoly) -> QQPoly {
+        self * QQPoly::from(rhs)
+    }
+}
+
+// Multiplication: QQPoly * &ZZPoly
+impl Mul<&ZZPoly> for QQPoly {
+    type Output = QQPoly;
+
+    fn mul(self, rhs: &ZZPoly) -> QQPoly {
+        self * QQPoly::from(rhs.clone())
+    }
+}
+
+// Multiplication: &QQPoly * ZZPoly
+impl Mul<ZZPoly> for &QQPoly {
+    type Output = QQPoly;
+
+    fn mul(self, rhs: ZZPoly) -> QQPoly {
+        self * QQPoly::from(rhs)
+    }
+}
+
+// Multiplication: &QQPoly * &ZZPoly
+impl Mul<&ZZPoly> for &QQPoly {
+    type Output = QQPoly;
+
+    fn mul(self, rhs: &ZZPoly) -> QQPoly {
+        self * QQPoly::from(rhs.clone())
+    }
+}
+
+// Multiplication: ZZPoly * QQPoly
+impl Mul<QQPoly> for ZZPoly {
+    type Output = QQPoly;
+
+    fn mul(self, rhs: QQPoly) -> QQPoly {
+        QQPoly::from(self) * rhs
+    }
+}
+
+// Multiplication: ZZPoly * &QQPoly
+impl Mul<&QQPoly> for ZZPoly {
+    type Output = QQPoly;
+
+    fn mul(self, rhs: &QQPoly) -> QQPoly {
+        QQPoly::from(self) * rhs
+    }
+}
+
+// Multiplication: &ZZPoly * QQPoly
+impl Mul<QQPoly> for &ZZPoly {
+    type Output = QQPoly;
+
+    fn mul(self, rhs: QQPoly) -> QQPoly {
+        QQPoly::from(self.clone()) * rhs
+    }
+}
+
+// Multiplication: &ZZPoly * &QQPoly
+impl Mul<&QQPoly> for &ZZPoly {
+    type Output = QQPoly;
+
+    fn mul(self, rhs: &QQPoly) -> QQPoly {
+        QQPoly::from(self.clone()) * rhs
+    }
+}
+
+// MulAssign: QQPoly *= ZZPoly
+impl MulAssign<ZZPoly> for QQPoly {
+    fn mul_assign(&mut self, rhs: ZZPoly) {
+        *self *= QQPoly::from(rhs);
+    }
+}
+
+// MulAssign: QQPoly *= &ZZPoly
+impl MulAssign<&ZZPoly> for QQPoly {
+    fn mul_assign(&mut self, rhs: &ZZPoly) {
+        *self *= QQPoly::from(rhs.clone());
+    }
+}
+
+impl QQPoly {
+    /// Raise the polynomial to the power `exp` by binary exponentiation,
+    /// squaring the numerator and raising the denominator to `exp`. The zeroth
+    /// power is one, including for the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::qq_poly::QQPoly;
+    /// use malachite::Natural;
+    ///
+    /// // ((1 + x)/2)^2 = (1 + 2x + x^2)/4
+    /// let p = QQPoly::from((vec![1, 1], Natural::from(2u32)));
+    /// assert_eq!(p.pow(2), QQPoly::from((vec![1, 2, 1], Natural::from(4u32))));
+    /// assert_eq!(p.pow(0), QQPoly::one());
+    /// ```
+    pub fn pow(&self, exp: u64) -> QQPoly {
+        if exp == 0 {
+            return QQPoly::one();
+        } else if self.is_zero() {
+            return QQPoly::zero();
+        }
+
+        // Left-to-right binary exponentiation on the numerator
+        let mut numerator = self.numerator.clone();
+        for i in (0..exp.ilog2()).rev() {
+            numerator = sqr(&numerator);
+            if (exp >> i) & 1 == 1 {
+                numerator *= &self.numerator;
+            }
+        }
+        QQPoly::from_raw(numerator, (&self.denominator).pow(exp))
+    }
+}
@@ -19,6 +19,7 @@ use std::ops::{Sub, SubAssign, Neg};
 use malachite::{Integer, Natural};
 use malachite::base::num::arithmetic::traits::Lcm;
 use crate::qq_poly::QQPoly;
+use crate::zz_poly::ZZPoly;
 
 // Negation: -QQPoly
 impl Neg for QQPoly {
@@ -119,4 +120,90 @@ impl SubAssign<&QQPoly> for QQPoly {
     fn sub_assign(&mut self, rhs: &QQPoly) {
         *self = self.clone() - rhs;
     }
-}
\ No newline at end of file
+}
+
+// Subtraction: QQPoly - ZZPoly
+impl Sub<ZZPoly> for QQPoly {
+    type Output = QQPoly;
+
+    fn sub(self, rhs: ZZPoly) -> QQPoly {
+        self - QQPoly::from(rhs)
+    }
+}
+
+// Subtraction: QQPoly - &ZZPoly
+impl Sub<&ZZPoly> for QQPoly {
+    type Output = QQPoly;
+
+    fn sub(self, rhs: &ZZPoly) -> QQPoly {
+        self - QQPoly::from(rhs.clone())
+    }
+}
+
+// Subtraction: &QQPoly - ZZPoly
+impl Sub<ZZPoly> for &QQPoly {
+    type Output = QQPoly;
+
+    fn sub(self, rhs: ZZPoly) -> QQPoly {
+        self - QQPoly::from(rhs)
+    }
+}
+
+// Subtraction: &QQPoly - &ZZPoly
+impl Sub<&ZZPoly> for &QQPoly {
+    type Output = QQPoly;
+
+    fn sub(self, rhs: &ZZPoly) -> QQPoly {
+        self - QQPoly::from(rhs.clone())
+    }
+}
+
+// Subtraction: ZZPoly - QQPoly
+impl Sub<QQPoly> for ZZPoly {
+    type Output = QQPoly;
+
+    fn sub(self, rhs: QQPoly) -> QQPoly {
+        QQPoly::from(self) - rhs
+    }
+}
+
+// Subtraction: ZZPoly - &QQPoly
+impl Sub<&QQPoly> for ZZPoly {
+    type Output = QQPoly;
+
+    fn sub(self, rhs: &QQPoly) -> QQPoly {
+        QQPoly::from(self) - rhs
+    }
+}
+
+// Subtraction: &ZZPoly - QQPoly
+impl Sub<QQPoly> for &ZZPoly {
+    type Output = QQPoly;
+
+    fn sub(self, rhs: QQPoly) -> QQPoly {
+        QQPoly::from(self.clone()) - rhs
+    }
+}
+
+// Subtraction: &ZZPoly - &QQPoly
+impl Sub<&QQPoly> for &ZZPoly {
+    type Output = QQPoly;
+
+    fn sub(self, rhs: &QQPoly) -> QQPoly {
+        QQPoly::from(self.clone()) - rhs
+    }
+}
+
+// SubAssign: QQPoly -= ZZPoly
+impl SubAssign<ZZPoly> for QQPoly {
+    fn sub_assign(&mut self, rhs: ZZPoly) {
+        *self -= QQPoly::from(rhs);
+    }
+}
+
+// SubAssign: QQPoly -= &ZZPoly
+impl SubAssign<&ZZPoly> for QQPoly {
+    fn sub_assign(&mut self, rhs: &ZZPoly) {
+        *self -= QQPoly::from(rhs.clone());
+    }
+}
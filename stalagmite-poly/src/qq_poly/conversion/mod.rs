@@ -15,18 +15,23 @@
 // You should have received a copy of the GNU General Public License
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
 
+use std::str::FromStr;
 use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::Lcm;
+use malachite::base::num::basic::traits::One;
+use malachite::rational::Rational;
+use stalagmite_base::StalagmiteError;
 use crate::qq_poly::QQPoly;
 use crate::zz_poly::ZZPoly;
+use crate::zz_poly::conversion::parse_terms;
 
-// From vectors of integers
+// From vectors of integers or rationals, over the LCM of the denominators
 impl<T> From<Vec<T>> for QQPoly 
 where
-    T: Into<Integer>
+    T: Into<Rational>
 {
     fn from(coeffs: Vec<T>) -> Self {
-        let zz_poly = ZZPoly::from(coeffs);
-        QQPoly::from_raw(zz_poly, Natural::from(1u32))
+        from_rational_coeffs(coeffs.into_iter().map(Into::into).collect())
     }
 }
 
@@ -133,4 +138,99 @@ impl From<malachite::rational::Rational> for QQPoly {
         let zz_poly = ZZPoly::from(vec![num]);
         QQPoly::from_raw(zz_poly, denom)
     }
-}
\ No newline at end of file
+}
+
+// Build a polynomial from rational coefficients by clearing denominators.
+pub(crate) fn from_rational_coeffs(coeffs: Vec<Rational>) -> QQPoly {
+    let den = coeffs.iter().fold(Natural::ONE, |acc, c| acc.lcm(c.denominator_ref()));
+    let num: Vec<Integer> = coeffs.into_iter().map(|c| {
+        let sign = c >= 0u32;
+        let (n, d) = c.into_numerator_and_denominator();
+        Integer::from_sign_and_abs(sign, n * (&den / d))
+    }).collect();
+    QQPoly::from_raw(ZZPoly::from_raw(num), den)
+}
+
+impl FromStr for QQPoly {
+    type Err = StalagmiteError;
+
+    /// Parse a polynomial from a string. Both the `Display` format
+    /// `(numerator)/denominator` (i.e. `(2*x^2 + 1)/3`) and rational
+    /// coefficients on individual terms (i.e. `1/2*x + 3`) are accepted.
+    /// Only a parenthesised numerator takes an overall denominator, so
+    /// `x + 1/3` is `x + 1/3`, not `(x + 1)/3`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+
+        let (num, den) = if let Some(rest) = s.strip_prefix('(') {
+            match rest.rsplit_once(")/") {
+                Some((num, den)) => (num, Some(den)),
+                None => match rest.strip_suffix(')') {
+                    Some(num) => (num, None),
+                    None => return Err(StalagmiteError::ParseError(
+                        format!("unbalanced parentheses in '{}'", s))),
+                },
+            }
+        } else {
+            (s.as_str(), None)
+        };
+
+        let den = match den {
+            Some(den) => {
+                let den = Natural::from_str(den).map_err(|_| {
+                    StalagmiteError::ParseError(format!("invalid denominator '{}'", den))
+                })?;
+                if den == 0u32 {
+                    return Err(StalagmiteError::DivisionByZero);
+                }
+                Rational::from(den)
+            },
+            None => Rational::ONE,
+        };
+
+        let mut coeffs: Vec<Rational> = Vec::new();
+        for (coeff, exp) in parse_terms(num)? {
+            let coeff = Rational::from_str(&coeff).map_err(|_| {
+                StalagmiteError::ParseError(format!("invalid rational coefficient '{}'", coeff))
+            })?;
+            if coeffs.len() <= exp {
+                coeffs.resize(exp + 1, Rational::from(0u32));
+            }
+            coeffs[exp] += coeff;
+        }
+        for coeff in coeffs.iter_mut() {
+            *coeff /= &den;
+        }
+        Ok(from_rational_coeffs(coeffs))
+    }
+}
+
+// Serialize as a numerator polynomial and denominator. Deserialized data is
+// re-normalized, so the numerator and denominator need not be in lowest terms.
+#[cfg(feature = "serde")]
+impl serde::Serialize for QQPoly {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("QQPoly", 2)?;
+        state.serialize_field("numerator", &self.numerator)?;
+        state.serialize_field("denominator", &self.denominator)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for QQPoly {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct RawQQPoly {
+            numerator: ZZPoly,
+            denominator: Natural,
+        }
+
+        let raw = RawQQPoly::deserialize(deserializer)?;
+        if raw.denominator == 0u32 {
+            return Err(serde::de::Error::custom(StalagmiteError::DivisionByZero));
+        }
+        Ok(QQPoly::from_raw(raw.numerator, raw.denominator))
+    }
+}
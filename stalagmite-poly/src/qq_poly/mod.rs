@@ -19,9 +19,12 @@ pub mod arithmetic;
 pub mod conversion;
 
 use std::fmt;
-use std::str::FromStr;
 use malachite::{Integer, Natural};
-use malachite::base::num::arithmetic::traits::{Abs, Gcd};
+use malachite::base::num::arithmetic::traits::{DivExactAssign, Gcd, UnsignedAbs};
+use malachite::rational::Rational;
+use stalagmite_base::rational::QQField;
+use stalagmite_base::traits::{Element, Parent, PolynomialRing, Ring, RingElement};
+use crate::qq_poly::conversion::from_rational_coeffs;
 use crate::zz_poly::ZZPoly;
 
 pub use arithmetic::*;
@@ -45,9 +48,60 @@ impl Default for QQPoly {
     }
 }
 
+impl QQPolyRing {
+    /// Initialize the ring of rational polynomials.
+    #[inline]
+    pub fn init() -> Self {
+        RationalPolynomialRing
+    }
+
+    /// Create the element of the ring represented by `input`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_base::traits::Element;
+    /// use stalagmite_poly::qq_poly::{QQPoly, QQPolyRing};
+    /// use malachite::Natural;
+    ///
+    /// let ring = QQPolyRing::init();
+    /// let p = ring.new((vec![1, 2], Natural::from(3u32)));
+    /// assert_eq!(p, QQPoly::from((vec![1, 2], Natural::from(3u32))));
+    /// assert_eq!(p.parent(), ring);
+    /// ```
+    #[inline]
+    pub fn new<T: Into<QQPoly>>(&self, input: T) -> QQPoly {
+        input.into()
+    }
+}
+
+impl Parent for QQPolyRing {
+    type Element = QQPoly;
+}
+
+impl Element for QQPoly {
+    type Parent = QQPolyRing;
+    #[inline]
+    fn parent(&self) -> Self::Parent {
+        RationalPolynomialRing
+    }
+}
+
+impl Ring for QQPolyRing {
+    type Element = QQPoly;
+}
+
+impl RingElement for QQPoly {
+    type Parent = QQPolyRing;
+}
+
+impl PolynomialRing<QQField> for QQPolyRing {}
+
+impl stalagmite_base::traits::Polynomial<QQField> for QQPoly {}
+
 impl fmt::Display for QQPoly {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}/{}", self.numerator, self.denominator)
+        write!(f, "{}", self.to_string_with_var("x"))
     }
 }
 
@@ -57,6 +111,27 @@ impl QQPoly {
         input.into()
     }
     
+    /// Return the string representation of the polynomial using `var` as the
+    /// variable name. `Display` uses `x`. A denominator other than one is
+    /// written after the numerator in parentheses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::qq_poly::QQPoly;
+    /// use malachite::Natural;
+    ///
+    /// assert_eq!(QQPoly::from(vec![1, 2]).to_string_with_var("t"), "2*t + 1");
+    /// assert_eq!(QQPoly::from((vec![1, 2], Natural::from(3u32))).to_string(), "(2*x + 1)/3");
+    /// ```
+    pub fn to_string_with_var(&self, var: &str) -> String {
+        if self.denominator == 1u32 {
+            self.numerator.to_string_with_var(var)
+        } else {
+            format!("({})/{}", self.numerator.to_string_with_var(var), self.denominator)
+        }
+    }
+
     pub fn normalize(&mut self) {
         // Normalize the numerator polynomial (removes leading zeros)
         self.numerator.normalize();
@@ -67,29 +142,32 @@ impl QQPoly {
             return;
         }
         
-        // Find GCD of all coefficients and denominator for simplification
+        // Find GCD of all coefficients and denominator for simplification,
+        // stopping early once it reaches one
         let mut gcd = self.denominator.clone();
         for coeff in self.numerator.iter() {
+            if gcd == 1 {
+                return;
+            }
             if *coeff != 0 {
-                // Convert abs Integer to Natural by taking abs and then converting
-                let abs_coeff = coeff.abs();
-                let nat_coeff = Natural::from_str(&abs_coeff.to_string()).unwrap();
-                gcd = Natural::gcd(gcd.clone(), nat_coeff);
+                gcd = (&gcd).gcd(coeff.unsigned_abs_ref());
             }
         }
         
         // Simplify by dividing by GCD
         if gcd > 1 {
-            // We need to create a new ZZPoly with simplified coefficients
-            let mut new_coeffs = Vec::new();
-            for coeff in self.numerator.iter() {
-                new_coeffs.push(coeff / Integer::from(&gcd));
+            let divisor = Integer::from(&gcd);
+            for coeff in self.numerator.coeffs_mut() {
+                coeff.div_exact_assign(&divisor);
             }
-            self.numerator = ZZPoly::from_raw(new_coeffs);
-            self.denominator /= &gcd;
+            self.denominator.div_exact_assign(&gcd);
         }
     }
     
+    /// Construct the polynomial `numerator/denominator` and normalize it.
+    /// Every constructor and arithmetic operation goes through
+    /// [`normalize`](QQPoly::normalize), so the derived `PartialEq` compares
+    /// reduced forms.
     pub fn from_raw(numerator: ZZPoly, denominator: Natural) -> Self {
         let mut p = QQPoly { numerator, denominator };
         p.normalize();
@@ -129,6 +207,29 @@ impl QQPoly {
         self.numerator.is_gen() && self.denominator == 1
     }
 
+    /// Return whether the polynomials are equal as rational polynomials, by
+    /// cross-multiplying numerators and denominators. Unlike `==` this does
+    /// not rely on either operand being in reduced form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::qq_poly::QQPoly;
+    /// use malachite::Natural;
+    ///
+    /// let p = QQPoly::from((vec![2, 4], Natural::from(6u32)));
+    /// let q = QQPoly::from((vec![1, 2], Natural::from(3u32)));
+    /// assert!(p.equals(&q));
+    /// assert!(!p.equals(&QQPoly::from(vec![1, 2])));
+    /// ```
+    pub fn equals(&self, other: &QQPoly) -> bool {
+        if self.denominator == other.denominator {
+            return self.numerator == other.numerator;
+        }
+        &self.numerator * Integer::from(&other.denominator)
+            == &other.numerator * Integer::from(&self.denominator)
+    }
+
     #[inline]
     pub fn length(&self) -> usize {
         self.numerator.length()
@@ -149,6 +250,12 @@ impl QQPoly {
         &self.denominator
     }
 
+    /// Return the constant term in lowest terms, which is zero for the zero
+    /// polynomial.
+    pub fn constant_term(&self) -> Rational {
+        Rational::from_integers(self.numerator.coeff(0), Integer::from(&self.denominator))
+    }
+
     // Get coefficient at given index (returns 0/1 if out of bounds)
     pub fn coeff(&self, i: usize) -> (Integer, Natural) {
         if i >= self.numerator.length() {
@@ -157,4 +264,164 @@ impl QQPoly {
             (self.numerator[i].clone(), self.denominator.clone())
         }
     }
-}
\ No newline at end of file
+
+    /// Return an iterator over the nonzero terms of the polynomial as
+    /// `(exponent, coefficient)` pairs in ascending order of exponent, with
+    /// the coefficients in lowest terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::qq_poly::QQPoly;
+    /// use malachite::Natural;
+    /// use malachite::rational::Rational;
+    ///
+    /// // (2x + 3x^3)/4
+    /// let p = QQPoly::from((vec![0, 2, 0, 3], Natural::from(4u32)));
+    /// let terms: Vec<_> = p.terms().collect();
+    /// assert_eq!(terms, vec![(1, Rational::from_signeds(1, 2)), (3, Rational::from_signeds(3, 4))]);
+    /// ```
+    pub fn terms(&self) -> impl DoubleEndedIterator<Item = (usize, Rational)> + '_ {
+        let den = Integer::from(&self.denominator);
+        self.numerator
+            .terms()
+            .map(move |(i, c)| (i, Rational::from_integers_ref(c, &den)))
+    }
+
+    /// Return `(p, c)` with `p` a primitive integer polynomial with positive
+    /// leading coefficient and `c` a rational such that `c * p == self`. The
+    /// zero polynomial gives `(0, 0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::qq_poly::QQPoly;
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Natural;
+    /// use malachite::rational::Rational;
+    ///
+    /// // 1/2 + x = 1/2 * (1 + 2x)
+    /// let p = QQPoly::from((vec![1, 2], Natural::from(2u32)));
+    /// assert_eq!(p.to_primitive_zz(), (ZZPoly::from(vec![1, 2]), Rational::from_signeds(1, 2)));
+    /// ```
+    pub fn to_primitive_zz(&self) -> (ZZPoly, Rational) {
+        if self.is_zero() {
+            return (ZZPoly::zero(), Rational::from(0));
+        }
+        let mut content = self.numerator.content();
+        if *self.numerator.leading_coefficient().unwrap() < 0 {
+            content = -content;
+        }
+        let scalar = Rational::from_integers(content, Integer::from(&self.denominator));
+        (self.numerator.primitive_part(), scalar)
+    }
+
+    /// Evaluate the polynomial at `x`, returning the result in lowest terms.
+    /// For `x = a/b` the numerator is evaluated homogeneously in `a` and `b`
+    /// with integer arithmetic and divided by `b^n` and the denominator once
+    /// at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::qq_poly::QQPoly;
+    /// use malachite::Natural;
+    /// use malachite::rational::Rational;
+    ///
+    /// // (1 + 2x)/3
+    /// let p = QQPoly::from((vec![1, 2], Natural::from(3u32)));
+    /// assert_eq!(p.evaluate(&Rational::from(1)), Rational::from(1));
+    /// assert_eq!(p.evaluate(&Rational::from_signeds(1, 2)), Rational::from_signeds(2, 3));
+    /// ```
+    pub fn evaluate(&self, x: &Rational) -> Rational {
+        let mut coeffs = self.numerator.iter().rev();
+        let Some(lead) = coeffs.next() else {
+            return Rational::from(0);
+        };
+        let a = Integer::from_sign_and_abs_ref(*x >= 0u32, x.numerator_ref());
+        let b = x.denominator_ref();
+        let mut result = lead.clone();
+        let mut b_pow = Natural::from(1u32);
+        for c in coeffs {
+            b_pow *= b;
+            result *= &a;
+            result += c * Integer::from(&b_pow);
+        }
+        Rational::from_integers(result, Integer::from(b_pow * &self.denominator))
+    }
+
+    /// Return the polynomial divided by its leading coefficient. The zero
+    /// polynomial is returned unchanged.
+    pub fn make_monic(&self) -> QQPoly {
+        let lead = match self.numerator.leading_coefficient() {
+            Some(lead) => lead,
+            None => return QQPoly::zero(),
+        };
+
+        // (N/d) / (lead/d) = N/lead
+        if *lead < 0 {
+            QQPoly::from_raw(-&self.numerator, lead.unsigned_abs())
+        } else {
+            QQPoly::from_raw(self.numerator.clone(), lead.unsigned_abs())
+        }
+    }
+
+    /// Return the polynomial of minimal degree passing through the given
+    /// `(x, y)` points, using Newton divided differences. Repeated points are
+    /// ignored and no points give the zero polynomial.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two points have the same x-coordinate but different
+    /// y-coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::qq_poly::QQPoly;
+    /// use malachite::rational::Rational;
+    ///
+    /// let points: Vec<(Rational, Rational)> = [(0, 0), (1, 1), (2, 4)]
+    ///     .into_iter()
+    ///     .map(|(x, y)| (Rational::from(x), Rational::from(y)))
+    ///     .collect();
+    /// assert_eq!(QQPoly::interpolate(&points), QQPoly::from(vec![0, 0, 1]));
+    /// ```
+    pub fn interpolate(points: &[(Rational, Rational)]) -> QQPoly {
+        let mut xs: Vec<Rational> = Vec::with_capacity(points.len());
+        let mut diffs: Vec<Rational> = Vec::with_capacity(points.len());
+        for (x, y) in points {
+            match xs.iter().position(|xi| xi == x) {
+                Some(i) if diffs[i] != *y => {
+                    panic!("Inconsistent interpolation points with x = {}", x)
+                }
+                Some(_) => {}
+                None => {
+                    xs.push(x.clone());
+                    diffs.push(y.clone());
+                }
+            }
+        }
+
+        // Divided differences in place: diffs[i] = f[x_0, ..., x_i]
+        let n = xs.len();
+        for j in 1..n {
+            for i in (j..n).rev() {
+                diffs[i] = (&diffs[i] - &diffs[i - 1]) / (&xs[i] - &xs[i - j]);
+            }
+        }
+
+        // Expand the Newton form by Horner's rule.
+        let mut coeffs: Vec<Rational> = Vec::with_capacity(n);
+        for k in (0..n).rev() {
+            // coeffs = coeffs * (x - x_k) + diffs[k]
+            coeffs.insert(0, Rational::from(0u32));
+            for i in 0..coeffs.len() - 1 {
+                let t = &coeffs[i + 1] * &xs[k];
+                coeffs[i] -= t;
+            }
+            coeffs[0] += &diffs[k];
+        }
+        from_rational_coeffs(coeffs)
+    }
+}
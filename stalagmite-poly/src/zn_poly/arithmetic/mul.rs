@@ -0,0 +1,83 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use std::ops::{Mul, MulAssign};
+use crate::zn_poly::ZnPoly;
+use crate::zn_poly::arithmetic::check_rings;
+
+impl Mul for ZnPoly {
+    type Output = ZnPoly;
+    #[inline]
+    fn mul(self, rhs: ZnPoly) -> ZnPoly {
+        &self * &rhs
+    }
+}
+
+impl Mul<&ZnPoly> for ZnPoly {
+    type Output = ZnPoly;
+    #[inline]
+    fn mul(self, rhs: &ZnPoly) -> ZnPoly {
+        &self * rhs
+    }
+}
+
+impl Mul<ZnPoly> for &ZnPoly {
+    type Output = ZnPoly;
+    #[inline]
+    fn mul(self, rhs: ZnPoly) -> ZnPoly {
+        self * &rhs
+    }
+}
+
+/// Multiply two polynomials over the same ring. The coefficients are lifted
+/// to integers, multiplied with the `ZZPoly` algorithms and reduced. Panics if
+/// the rings differ.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_poly::zz_poly::ZZPoly;
+/// use malachite::Natural;
+///
+/// let n = Natural::from(2u32);
+/// let p = ZZPoly::from(vec![1, 1]).reduce_mod(&n);   // x + 1
+/// assert_eq!(&p * &p, ZZPoly::from(vec![1, 0, 1]).reduce_mod(&n));
+/// ```
+impl Mul<&ZnPoly> for &ZnPoly {
+    type Output = ZnPoly;
+    fn mul(self, rhs: &ZnPoly) -> ZnPoly {
+        check_rings!(self, rhs);
+        if self.is_zero() || rhs.is_zero() {
+            return ZnPoly::zero(&self.ring);
+        }
+        ZnPoly::from_zz_poly(&(self.lift() * rhs.lift()), &self.ring)
+    }
+}
+
+impl MulAssign for ZnPoly {
+    #[inline]
+    fn mul_assign(&mut self, rhs: ZnPoly) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl MulAssign<&ZnPoly> for ZnPoly {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &ZnPoly) {
+        *self = &*self * rhs;
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use std::ops::Neg;
+use malachite::base::num::arithmetic::traits::NegAssign;
+use crate::zn_poly::ZnPoly;
+
+impl Neg for ZnPoly {
+    type Output = ZnPoly;
+    #[inline]
+    fn neg(mut self) -> ZnPoly {
+        self.neg_assign();
+        self
+    }
+}
+
+impl Neg for &ZnPoly {
+    type Output = ZnPoly;
+    #[inline]
+    fn neg(self) -> ZnPoly {
+        ZnPoly::from_raw(self.coeffs.iter().map(|c| -c).collect(), self.ring.clone())
+    }
+}
+
+impl NegAssign for ZnPoly {
+    fn neg_assign(&mut self) {
+        for c in self.coeffs.iter_mut() {
+            c.neg_assign();
+        }
+    }
+}
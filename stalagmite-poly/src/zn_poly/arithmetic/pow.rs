@@ -0,0 +1,57 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::Natural;
+use malachite::base::num::logic::traits::BitIterable;
+use crate::zn_poly::ZnPoly;
+use crate::zn_poly::arithmetic::check_rings;
+
+impl ZnPoly {
+    /// Compute `self^exp` modulo the polynomial `modulus` by square and
+    /// multiply, reducing after every step. The leading coefficient of
+    /// `modulus` must be a unit modulo n (for example, `modulus` is monic).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero, its leading coefficient is not
+    /// invertible, or the rings differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Natural;
+    ///
+    /// // x^2 + 1 is irreducible mod 3, so x^9 = x mod (3, x^2 + 1)
+    /// let n = Natural::from(3u32);
+    /// let x = ZZPoly::from(vec![0, 1]).reduce_mod(&n);
+    /// let f = ZZPoly::from(vec![1, 0, 1]).reduce_mod(&n);
+    /// assert_eq!(x.pow_mod(&Natural::from(9u32), &f), x);
+    /// ```
+    pub fn pow_mod(&self, exp: &Natural, modulus: &ZnPoly) -> ZnPoly {
+        check_rings!(self, modulus);
+        let base = self % modulus;
+        let mut res = &ZnPoly::one(&self.ring) % modulus;
+        for bit in exp.bits().rev() {
+            res = &(&res * &res) % modulus;
+            if bit {
+                res = &(&res * &base) % modulus;
+            }
+        }
+        res
+    }
+}
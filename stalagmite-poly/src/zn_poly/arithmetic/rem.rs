@@ -0,0 +1,124 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use std::ops::{Rem, RemAssign};
+use stalagmite_zn::ZnElem;
+use crate::zn_poly::ZnPoly;
+use crate::zn_poly::arithmetic::check_rings;
+
+// Remainder of polynomial long division by a divisor whose leading
+// coefficient is a unit. Both inputs must be normalized and the divisor
+// nonzero.
+fn polynomial_division_remainder(dividend: &[ZnElem], divisor: &[ZnElem]) -> Vec<ZnElem> {
+    let divisor_degree = divisor.len() - 1;
+    let mut remainder = dividend.to_vec();
+    if remainder.len() <= divisor_degree {
+        return remainder;
+    }
+
+    let lead_inv = divisor[divisor_degree]
+        .inverse()
+        .expect("Leading coefficient of the divisor is not invertible");
+
+    while remainder.len() > divisor_degree {
+        let remainder_degree = remainder.len() - 1;
+        let coeff = &remainder[remainder_degree] * &lead_inv;
+        let degree_diff = remainder_degree - divisor_degree;
+
+        // Subtract coeff * x^degree_diff * divisor, which cancels the leading
+        // term exactly.
+        for (i, divisor_coeff) in divisor[..divisor_degree].iter().enumerate() {
+            remainder[i + degree_diff] -= &coeff * divisor_coeff;
+        }
+        remainder.pop();
+
+        // Remove leading zeros
+        while remainder.last().is_some_and(|c| *c.value() == 0u32) {
+            remainder.pop();
+        }
+    }
+    remainder
+}
+
+impl Rem for ZnPoly {
+    type Output = ZnPoly;
+    #[inline]
+    fn rem(self, rhs: ZnPoly) -> ZnPoly {
+        &self % &rhs
+    }
+}
+
+impl Rem<&ZnPoly> for ZnPoly {
+    type Output = ZnPoly;
+    #[inline]
+    fn rem(self, rhs: &ZnPoly) -> ZnPoly {
+        &self % rhs
+    }
+}
+
+impl Rem<ZnPoly> for &ZnPoly {
+    type Output = ZnPoly;
+    #[inline]
+    fn rem(self, rhs: ZnPoly) -> ZnPoly {
+        self % &rhs
+    }
+}
+
+/// Compute the remainder of dividing by a polynomial over the same ring.
+///
+/// # Panics
+///
+/// Panics if the divisor is zero, if its leading coefficient is not a unit
+/// modulo n, or if the rings differ.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_poly::zz_poly::ZZPoly;
+/// use malachite::Natural;
+///
+/// let n = Natural::from(7u32);
+/// let a = ZZPoly::from(vec![0, 0, 0, 1]).reduce_mod(&n);   // x^3
+/// let b = ZZPoly::from(vec![1, 0, 2]).reduce_mod(&n);      // 2x^2 + 1
+/// // x^3 = (4x)(2x^2 + 1) - 4x
+/// assert_eq!(&a % &b, ZZPoly::from(vec![0, 3]).reduce_mod(&n));
+/// ```
+impl Rem<&ZnPoly> for &ZnPoly {
+    type Output = ZnPoly;
+    fn rem(self, rhs: &ZnPoly) -> ZnPoly {
+        check_rings!(self, rhs);
+        if rhs.is_zero() {
+            panic!("Division by zero polynomial");
+        }
+        let remainder = polynomial_division_remainder(&self.coeffs, &rhs.coeffs);
+        ZnPoly::from_raw(remainder, self.ring.clone())
+    }
+}
+
+impl RemAssign for ZnPoly {
+    #[inline]
+    fn rem_assign(&mut self, rhs: ZnPoly) {
+        *self = &*self % &rhs;
+    }
+}
+
+impl RemAssign<&ZnPoly> for ZnPoly {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &ZnPoly) {
+        *self = &*self % rhs;
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use std::ops::{Sub, SubAssign};
+use crate::zn_poly::ZnPoly;
+use crate::zn_poly::arithmetic::check_rings;
+
+impl Sub for ZnPoly {
+    type Output = ZnPoly;
+    #[inline]
+    fn sub(mut self, rhs: ZnPoly) -> ZnPoly {
+        self -= &rhs;
+        self
+    }
+}
+
+impl Sub<&ZnPoly> for ZnPoly {
+    type Output = ZnPoly;
+    #[inline]
+    fn sub(mut self, rhs: &ZnPoly) -> ZnPoly {
+        self -= rhs;
+        self
+    }
+}
+
+impl Sub<ZnPoly> for &ZnPoly {
+    type Output = ZnPoly;
+    #[inline]
+    fn sub(self, rhs: ZnPoly) -> ZnPoly {
+        self - &rhs
+    }
+}
+
+/// Subtract two polynomials over the same ring, reducing the coefficients.
+/// Panics if the rings differ.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_poly::zz_poly::ZZPoly;
+/// use malachite::Natural;
+///
+/// let n = Natural::from(5u32);
+/// let p1 = ZZPoly::from(vec![1, 2]).reduce_mod(&n);
+/// let p2 = ZZPoly::from(vec![2, 2, 1]).reduce_mod(&n);
+/// assert_eq!(&p1 - &p2, ZZPoly::from(vec![4, 0, 4]).reduce_mod(&n));
+/// ```
+impl Sub<&ZnPoly> for &ZnPoly {
+    type Output = ZnPoly;
+    fn sub(self, rhs: &ZnPoly) -> ZnPoly {
+        check_rings!(self, rhs);
+        let n = self.length().max(rhs.length());
+        let mut result = Vec::with_capacity(n);
+        for i in 0..n {
+            result.push(match (self.coeffs.get(i), rhs.coeffs.get(i)) {
+                (Some(a), Some(b)) => a - b,
+                (Some(a), None) => a.clone(),
+                (None, Some(b)) => -b,
+                (None, None) => unreachable!(),
+            });
+        }
+        ZnPoly::from_raw(result, self.ring.clone())
+    }
+}
+
+impl SubAssign for ZnPoly {
+    #[inline]
+    fn sub_assign(&mut self, rhs: ZnPoly) {
+        *self -= &rhs;
+    }
+}
+
+impl SubAssign<&ZnPoly> for ZnPoly {
+    fn sub_assign(&mut self, rhs: &ZnPoly) {
+        check_rings!(self, rhs);
+        let common = self.length().min(rhs.length());
+        for i in 0..common {
+            self.coeffs[i] -= &rhs.coeffs[i];
+        }
+        self.coeffs.extend(rhs.coeffs[common..].iter().map(|c| -c));
+        self.normalize();
+    }
+}
@@ -0,0 +1,306 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod arithmetic;
+
+use std::fmt;
+use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::{Mod, ModInverse, ModMul, ModSub};
+use malachite::base::num::basic::traits::Zero;
+use stalagmite_base::StalagmiteError;
+use stalagmite_zn::{ZnElem, ZnRing};
+use crate::zz_poly::ZZPoly;
+
+pub use arithmetic::*;
+
+/// A polynomial with coefficients in Z/nZ. All coefficients share the ring
+/// stored with the polynomial, which is also kept for the zero polynomial.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ModularPolynomial {
+    coeffs: Vec<ZnElem>,
+    ring: ZnRing,
+}
+
+pub type ZnPoly = ModularPolynomial;
+
+// Impl Deref but NOT DerefMut.
+impl std::ops::Deref for ZnPoly {
+    type Target = Vec<ZnElem>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.coeffs
+    }
+}
+
+impl fmt::Display for ZnPoly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.lift())
+    }
+}
+
+impl ZnPoly {
+    /// Create a polynomial from coefficients in `ring`, lowest degree first.
+    /// Panics if a coefficient belongs to a different ring.
+    pub fn new(coeffs: Vec<ZnElem>, ring: &ZnRing) -> Self {
+        for c in coeffs.iter() {
+            if c.modulus() != ring.modulus() {
+                panic!(
+                    "Cannot create polynomial over {} with coefficient in Zn({})",
+                    ring, c.modulus()
+                );
+            }
+        }
+        ZnPoly::from_raw(coeffs, ring.clone())
+    }
+
+    pub(crate) fn from_raw(coeffs: Vec<ZnElem>, ring: ZnRing) -> Self {
+        let mut p = ZnPoly { coeffs, ring };
+        p.normalize();
+        p
+    }
+
+    // Reduce the integer coefficients of `p` into `ring`.
+    pub(crate) fn from_zz_poly(p: &ZZPoly, ring: &ZnRing) -> Self {
+        let n = Integer::from(ring.modulus());
+        let coeffs = p
+            .iter()
+            .map(|c| ring.new(Natural::try_from(c.mod_op(&n)).unwrap()))
+            .collect();
+        ZnPoly::from_raw(coeffs, ring.clone())
+    }
+
+    /// Lift to an integer polynomial, mapping each coefficient to its least
+    /// non-negative representative in `[0, n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Natural;
+    ///
+    /// let p = ZZPoly::from(vec![-1, 4]).reduce_mod(&Natural::from(3u32));
+    /// assert_eq!(p.lift(), ZZPoly::from(vec![2, 1]));
+    /// ```
+    pub fn lift(&self) -> ZZPoly {
+        ZZPoly::from_raw(
+            self.coeffs.iter().map(|c| Integer::from(c.to_natural())).collect()
+        )
+    }
+
+    /// Lift to an integer polynomial, mapping each coefficient to its
+    /// representative in the symmetric range `(-n/2, n/2]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Natural;
+    ///
+    /// let p = ZZPoly::from(vec![2, 1]).reduce_mod(&Natural::from(3u32));
+    /// assert_eq!(p.lift_symmetric(), ZZPoly::from(vec![-1, 1]));
+    /// ```
+    pub fn lift_symmetric(&self) -> ZZPoly {
+        self.lift().coeffs_mod_symmetric(self.modulus())
+    }
+
+    pub fn normalize(&mut self) {
+        let mut new_len = self.coeffs.len();
+        while new_len > 0 && *self.coeffs[new_len - 1].value() == 0u32 {
+            new_len -= 1;
+        }
+        self.coeffs.truncate(new_len);
+    }
+
+    #[inline]
+    pub fn zero(ring: &ZnRing) -> Self {
+        ZnPoly { coeffs: vec![], ring: ring.clone() }
+    }
+
+    #[inline]
+    pub fn one(ring: &ZnRing) -> Self {
+        ZnPoly::from_raw(vec![ring.new(Natural::from(1u32))], ring.clone())
+    }
+
+    #[inline]
+    pub fn r#gen(ring: &ZnRing) -> Self {
+        ZnPoly::from_raw(
+            vec![ring.new(Natural::from(0u32)), ring.new(Natural::from(1u32))],
+            ring.clone()
+        )
+    }
+
+    /// Return the ring of the coefficients.
+    #[inline]
+    pub fn ring(&self) -> &ZnRing {
+        &self.ring
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> &Natural {
+        self.ring.modulus()
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.length() == 0
+    }
+
+    #[inline]
+    pub fn length(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    #[inline]
+    pub fn degree(&self) -> usize {
+        if self.length() == 0 {
+            return 0
+        }
+        self.length() - 1
+    }
+
+    /// Return the leading coefficient, or `None` for the zero polynomial.
+    #[inline]
+    pub fn leading_coefficient(&self) -> Option<&ZnElem> {
+        self.coeffs.last()
+    }
+}
+
+impl ZZPoly {
+    /// Reduce the coefficients of the polynomial modulo `n`, returning a
+    /// polynomial over Z/nZ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Natural;
+    ///
+    /// let p = ZZPoly::from(vec![5, -1, 6]).reduce_mod(&Natural::from(3u32));
+    /// assert_eq!(p.degree(), 1);
+    /// assert_eq!(*p[0].value(), Natural::from(2u32));
+    /// ```
+    pub fn reduce_mod(&self, n: &Natural) -> ZnPoly {
+        ZnPoly::from_zz_poly(self, &ZnRing::init(n.clone()))
+    }
+
+    /// Reconstruct an integer polynomial from its images modulo pairwise
+    /// coprime moduli by coefficient-wise Chinese remaindering. The result has
+    /// coefficients in the symmetric range `(-m/2, m/2]`, where `m` is the
+    /// product of the moduli, so it is exact when every coefficient is less
+    /// than `m/2` in absolute value. Images of different lengths are padded
+    /// with zeros, and no images give the zero polynomial.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the moduli are not pairwise coprime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Natural;
+    ///
+    /// let p = ZZPoly::from(vec![3, -10, 0, 17]);
+    /// let images = [
+    ///     p.reduce_mod(&Natural::from(5u32)),
+    ///     p.reduce_mod(&Natural::from(7u32)),
+    /// ];
+    /// assert_eq!(ZZPoly::crt_reconstruct(&images), p);
+    /// ```
+    pub fn crt_reconstruct(images: &[ZnPoly]) -> ZZPoly {
+        let Some((first, rest)) = images.split_first() else {
+            return ZZPoly::zero();
+        };
+        let len = images.iter().map(|p| p.length()).max().unwrap();
+        let word_moduli: Option<Vec<u64>> =
+            images.iter().map(|p| u64::try_from(p.modulus()).ok()).collect();
+        if let Some(moduli) = word_moduli {
+            return crt_reconstruct_word(images, &moduli, len);
+        }
+        let residue = |p: &ZnPoly, i: usize| {
+            p.get(i).map_or(Natural::ZERO, |c| c.to_natural())
+        };
+
+        // Combine one image at a time: x = a mod m and x = b mod n give
+        // x = a + m * ((b - a) / m mod n) mod mn.
+        let mut modulus = first.modulus().clone();
+        let mut coeffs: Vec<Natural> = (0..len).map(|i| residue(first, i)).collect();
+        for image in rest {
+            let n = image.modulus();
+            let inv = (&modulus % n).mod_inverse(n).unwrap_or_else(|| {
+                panic!("{}", StalagmiteError::InvalidModulus(n.clone()))
+            });
+            for (i, c) in coeffs.iter_mut().enumerate() {
+                let t = residue(image, i).mod_sub(&*c % n, n).mod_mul(&inv, n);
+                *c += &modulus * t;
+            }
+            modulus *= n;
+        }
+
+        ZZPoly::from_raw(coeffs.into_iter().map(Integer::from).collect())
+            .coeffs_mod_symmetric(&modulus)
+    }
+}
+
+// Chinese remaindering for images whose moduli fit in a word. The mixed-radix
+// digits of each coefficient are computed with Garner's algorithm in word
+// arithmetic, so big integers are only needed to assemble the result.
+fn crt_reconstruct_word(images: &[ZnPoly], moduli: &[u64], len: usize) -> ZZPoly {
+    let mul_mod = |a: u64, b: u64, m: u64| ((a as u128 * b as u128) % m as u128) as u64;
+
+    // inverses[j][i] is the inverse of m_i modulo m_j for i < j
+    let inverses: Vec<Vec<u64>> = moduli
+        .iter()
+        .enumerate()
+        .map(|(j, &mj)| {
+            moduli[..j]
+                .iter()
+                .map(|&mi| {
+                    (mi % mj).mod_inverse(mj).unwrap_or_else(|| {
+                        panic!("{}", StalagmiteError::InvalidModulus(Natural::from(mj)))
+                    })
+                })
+                .collect()
+        })
+        .collect();
+
+    let modulus: Natural = moduli.iter().map(|&m| Natural::from(m)).product();
+    let half = &modulus >> 1u64;
+    let mut digits = vec![0u64; moduli.len()];
+    let coeffs = (0..len)
+        .map(|k| {
+            for (j, &mj) in moduli.iter().enumerate() {
+                let mut v = images[j]
+                    .get(k)
+                    .map_or(0, |c| u64::try_from(&c.to_natural()).unwrap());
+                for (&d, &inv) in digits[..j].iter().zip(inverses[j].iter()) {
+                    v = mul_mod(v.mod_sub(d % mj, mj), inv, mj);
+                }
+                digits[j] = v;
+            }
+
+            // x = d_0 + m_0 (d_1 + m_1 (d_2 + ...))
+            let mut x = Natural::from(digits[moduli.len() - 1]);
+            for j in (0..moduli.len() - 1).rev() {
+                x *= Natural::from(moduli[j]);
+                x += Natural::from(digits[j]);
+            }
+            if x > half { Integer::from(x) - Integer::from(&modulus) } else { Integer::from(x) }
+        })
+        .collect();
+    ZZPoly::from_raw(coeffs)
+}
@@ -17,6 +17,7 @@
 
 use malachite::Integer;
 use crate::zz_poly::ZZPoly;
+use crate::zz_poly::arithmetic::par;
 use std::ops::{
     Add,
     AddAssign,
@@ -117,13 +118,9 @@ impl Add<&ZZPoly> for &ZZPoly {
             return rhs.clone();
         }
 
-        let n = self.length().max(rhs.length());
-        let mut result = Vec::with_capacity(n);
-        for i in 0..n {
-            let a = if i < self.length() { &self.coeffs[i] } else { &Integer::from(0) };
-            let b = if i < rhs.length() { &rhs.coeffs[i] } else { &Integer::from(0) };
-            result.push(a + b);
-        }
+        let (long, short) = if self.length() >= rhs.length() { (self, rhs) } else { (rhs, self) };
+        let mut result = long.coeffs.clone();
+        par::add_assign(&mut result, &short.coeffs);
         ZZPoly::from_raw(result)
     }
 }
@@ -155,9 +152,7 @@ impl AddAssign<ZZPoly> for ZZPoly {
                 swap(self, &mut rhs);
             }
         
-            for i in 0..rhs.length() {
-                self.coeffs[i] += &rhs.coeffs[i];
-            }
+            par::add_assign(&mut self.coeffs, &rhs.coeffs);
         }
         self.normalize();
     }
@@ -183,18 +178,13 @@ impl AddAssign<&ZZPoly> for ZZPoly {
             *self = rhs.clone();
         } else if self.length() < rhs.length() {
             // add the common coefficients
-            for i in 0..self.length() {
-                self.coeffs[i] += &rhs.coeffs[i];
-            }
+            let n = self.length();
+            par::add_assign(&mut self.coeffs, &rhs.coeffs[..n]);
 
             // push the remaining coefficients from rhs
-            for i in self.length()..rhs.length() {
-                self.coeffs.push(rhs.coeffs[i].clone());
-            }
+            self.coeffs.extend_from_slice(&rhs.coeffs[n..]);
         } else {
-            for i in 0..rhs.length() {
-                self.coeffs[i] += &rhs.coeffs[i];
-            }
+            par::add_assign(&mut self.coeffs, &rhs.coeffs);
         }
         self.normalize();
     }
@@ -0,0 +1,43 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::Integer;
+use crate::zz_poly::ZZPoly;
+
+impl ZZPoly {
+    /// Return the formal derivative of the polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    ///
+    /// // d/dx (1 + 2x + 3x^2) = 2 + 6x
+    /// let p = ZZPoly::from(vec![1, 2, 3]);
+    /// assert_eq!(p.derivative(), ZZPoly::from(vec![2, 6]));
+    /// assert!(ZZPoly::from(vec![5]).derivative().is_zero());
+    /// ```
+    pub fn derivative(&self) -> ZZPoly {
+        let coeffs = self
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| c * Integer::from(i))
+            .collect();
+        ZZPoly::from_raw(coeffs)
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::Integer;
+use malachite::base::num::arithmetic::traits::{DivExact, DivisibleBy, UnsignedAbs};
+use stalagmite_base::StalagmiteError;
+use crate::qq_poly::QQPoly;
+use crate::zz_poly::ZZPoly;
+
+impl ZZPoly {
+    /// Pseudo-division of `self` by `other`. Returns `(q, r)` such that
+    /// `lc(other)^(deg(self) - deg(other) + 1) * self = q * other + r` with
+    /// `deg(r) < deg(other)`. If `deg(self) < deg(other)` this is `(0, self)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    ///
+    /// // 2^2 * (x^2 + 1) = (2x - 1) * (2x + 1) + 5
+    /// let a = ZZPoly::from(vec![1, 0, 1]);
+    /// let b = ZZPoly::from(vec![1, 2]);
+    /// let (q, r) = a.pseudo_divrem(&b);
+    /// assert_eq!(q, ZZPoly::from(vec![-1, 2]));
+    /// assert_eq!(r, ZZPoly::from(vec![5]));
+    /// ```
+    pub fn pseudo_divrem(&self, other: &ZZPoly) -> (ZZPoly, ZZPoly) {
+        if other.is_zero() {
+            panic!("Division by zero polynomial");
+        }
+        if self.length() < other.length() {
+            return (ZZPoly::zero(), self.clone());
+        }
+
+        let lead = &other.coeffs[other.length() - 1];
+        let m = other.length() - 1;
+        let mut rem = self.coeffs.clone();
+        let mut quo = vec![Integer::from(0); self.length() - m];
+
+        // Each step multiplies the remainder and quotient so far by lc(other)
+        // and cancels the leading term of the remainder.
+        for k in (0..quo.len()).rev() {
+            let c = rem[k + m].clone();
+            for q in quo[k + 1..].iter_mut() {
+                *q *= lead;
+            }
+            quo[k] = c.clone();
+            for r in rem[..k + m].iter_mut() {
+                *r *= lead;
+            }
+            for (i, b) in other.coeffs[..m].iter().enumerate() {
+                rem[k + i] -= &c * b;
+            }
+            rem.truncate(k + m);
+        }
+        (ZZPoly::from_raw(quo), ZZPoly::from_raw(rem))
+    }
+
+    /// Return the pseudo-remainder of `self` by `other`. See
+    /// `ZZPoly::pseudo_divrem`.
+    #[inline]
+    pub fn pseudo_rem(&self, other: &ZZPoly) -> ZZPoly {
+        self.pseudo_divrem(other).1
+    }
+
+    /// Return the exact quotient `self / other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is the zero polynomial or does not divide `self`
+    /// in `ZZ[x]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    ///
+    /// // (2x^2 + x - 1) / (2x - 1) = x + 1
+    /// let a = ZZPoly::from(vec![-1, 1, 2]);
+    /// let b = ZZPoly::from(vec![-1, 2]);
+    /// assert_eq!(a.div_exact(&b), ZZPoly::from(vec![1, 1]));
+    /// ```
+    pub fn div_exact(&self, other: &ZZPoly) -> ZZPoly {
+        if other.is_zero() {
+            panic!("Division by zero polynomial");
+        }
+        self.try_div_exact(other).expect("Polynomial division is not exact")
+    }
+
+    /// Return true if `self` divides `other` in `ZZ[x]`. Every polynomial
+    /// divides zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    ///
+    /// let a = ZZPoly::from(vec![-1, 0, 1]);
+    /// assert!(ZZPoly::from(vec![-1, 1]).divides(&a));
+    /// assert!(!ZZPoly::from(vec![2, 1]).divides(&a));
+    /// // x^2 - 1 is not divisible by 2x - 2 over the integers
+    /// assert!(!ZZPoly::from(vec![-2, 2]).divides(&a));
+    /// ```
+    pub fn divides(&self, other: &ZZPoly) -> bool {
+        if self.is_zero() {
+            panic!("Division by zero polynomial");
+        }
+        other.try_div_exact(self).is_some()
+    }
+
+    // Exact quotient self / other for nonzero other, or None if other does
+    // not divide self.
+    fn try_div_exact(&self, other: &ZZPoly) -> Option<ZZPoly> {
+        if self.length() < other.length() {
+            return if self.is_zero() { Some(ZZPoly::zero()) } else { None };
+        }
+
+        let lead = &other.coeffs[other.length() - 1];
+        let m = other.length() - 1;
+        let mut rem = self.coeffs.clone();
+        let mut quo = vec![Integer::from(0); self.length() - m];
+        for k in (0..quo.len()).rev() {
+            if !(&rem[k + m]).divisible_by(lead) {
+                return None;
+            }
+            let c = &rem[k + m] / lead;
+            for (i, b) in other.coeffs.iter().enumerate() {
+                rem[k + i] -= &c * b;
+            }
+            quo[k] = c;
+        }
+        if rem.iter().any(|r| *r != 0) {
+            return None;
+        }
+        Some(ZZPoly::from_raw(quo))
+    }
+
+    /// Return the rational polynomial `self / d`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `d` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::qq_poly::QQPoly;
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::{Integer, Natural};
+    ///
+    /// let p = ZZPoly::from(vec![2, 4, 6]);
+    /// let q = QQPoly::from((vec![1, 2, 3], Natural::from(2u32)));
+    /// assert_eq!(p.div_scalar(&Integer::from(4)), q);
+    /// ```
+    pub fn div_scalar(&self, d: &Integer) -> QQPoly {
+        if *d == 0 {
+            panic!("{}", StalagmiteError::DivisionByZero);
+        }
+        if *d < 0 {
+            QQPoly::from_raw(-self, d.unsigned_abs())
+        } else {
+            QQPoly::from_raw(self.clone(), d.unsigned_abs())
+        }
+    }
+
+    /// Return `self / d` if `d` divides every coefficient, or `None`
+    /// otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `d` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Integer;
+    ///
+    /// let p = ZZPoly::from(vec![2, 4, 6]);
+    /// assert_eq!(p.exact_div_scalar(&Integer::from(-2)), Some(ZZPoly::from(vec![-1, -2, -3])));
+    /// assert_eq!(p.exact_div_scalar(&Integer::from(4)), None);
+    /// ```
+    pub fn exact_div_scalar(&self, d: &Integer) -> Option<ZZPoly> {
+        if *d == 0 {
+            panic!("{}", StalagmiteError::DivisionByZero);
+        }
+        if !self.coeffs.iter().all(|c| c.divisible_by(d)) {
+            return None;
+        }
+        Some(ZZPoly::from_raw(self.coeffs.iter().map(|c| c.div_exact(d)).collect()))
+    }
+}
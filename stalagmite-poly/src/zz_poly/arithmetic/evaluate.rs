@@ -0,0 +1,194 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::Integer;
+use crate::zz_poly::ZZPoly;
+use crate::zz_poly::arithmetic::mullow::mullow;
+
+// Below this many points evaluate_multi uses Horner's rule at each point.
+const MULTIPOINT_EVAL_THRESHOLD: usize = 32;
+
+// Below this divisor length remainders use classical division instead of
+// Newton inversion.
+const FAST_REM_THRESHOLD: usize = 32;
+
+// Power series inverse of f modulo x^n by Newton iteration. The constant term
+// of f must be 1 or -1, so that the inverse has integer coefficients.
+fn inv_series_unit(f: &ZZPoly, n: usize) -> ZZPoly {
+    let two = ZZPoly::from(vec![2]);
+    let mut g = ZZPoly::from(vec![f.coeff(0)]);
+    let mut prec = 1;
+    while prec < n {
+        prec = (2 * prec).min(n);
+        let t = &two - mullow(f, &g, prec);
+        g = mullow(&g, &t, prec);
+    }
+    g
+}
+
+// Remainder of a modulo the monic polynomial b. Large divisors use the
+// reversed quotient rev(a) / rev(b) mod x^(deg a - deg b + 1).
+fn rem_monic(a: &ZZPoly, b: &ZZPoly) -> ZZPoly {
+    if a.length() < b.length() {
+        return a.clone();
+    } else if b.length() < FAST_REM_THRESHOLD {
+        return a % b;
+    }
+
+    let len_q = a.length() - b.length() + 1;
+    let inv = inv_series_unit(&b.reverse(b.length()), len_q);
+    let q = mullow(&a.reverse(a.length()), &inv, len_q).reverse(len_q);
+    a - b * q
+}
+
+/// The subproduct tree of the linear factors `x - a_i` for a list of points,
+/// stored level by level from the leaves to the root.
+pub(crate) struct SubproductTree {
+    levels: Vec<Vec<ZZPoly>>,
+}
+
+impl SubproductTree {
+    /// Build the tree for a non-empty list of points. Each node is the product
+    /// of its two children; an unpaired node is carried up unchanged.
+    pub(crate) fn new(points: &[Integer]) -> Self {
+        let leaves: Vec<ZZPoly> = points.iter()
+            .map(|a| ZZPoly::from(vec![-a, Integer::from(1)]))
+            .collect();
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels.last().unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => left * right,
+                    [node] => node.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        SubproductTree { levels }
+    }
+
+    /// Reduce `f` modulo every leaf, working down from the root. The result
+    /// holds the constant `f(a_i)` for each point.
+    pub(crate) fn leaf_remainders(&self, f: &ZZPoly) -> Vec<ZZPoly> {
+        let mut rems = vec![rem_monic(f, &self.levels[self.levels.len() - 1][0])];
+        for level in self.levels.iter().rev().skip(1) {
+            rems = level.iter()
+                .enumerate()
+                .map(|(i, node)| rem_monic(&rems[i / 2], node))
+                .collect();
+        }
+        rems
+    }
+}
+
+impl ZZPoly {
+    /// Evaluate the polynomial at `x` using Horner's rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Integer;
+    ///
+    /// // 1 - 2x + 3x^2 at x = 2
+    /// let p = ZZPoly::from(vec![1, -2, 3]);
+    /// assert_eq!(p.evaluate(&Integer::from(2)), Integer::from(9));
+    /// assert_eq!(ZZPoly::zero().evaluate(&Integer::from(2)), Integer::from(0));
+    /// ```
+    pub fn evaluate(&self, x: &Integer) -> Integer {
+        let mut result = Integer::from(0);
+        for coeff in self.coeffs.iter().rev() {
+            result *= x;
+            result += coeff;
+        }
+        result
+    }
+
+    /// Evaluate the polynomial at each of `points`. Large point sets are
+    /// handled by reducing the polynomial down a subproduct tree, which is
+    /// asymptotically faster than evaluating at each point separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Integer;
+    ///
+    /// let p = ZZPoly::from(vec![1, -2, 3]);
+    /// let points = vec![Integer::from(0), Integer::from(2), Integer::from(-1)];
+    /// assert_eq!(
+    ///     p.evaluate_multi(&points),
+    ///     vec![Integer::from(1), Integer::from(9), Integer::from(6)]
+    /// );
+    /// ```
+    pub fn evaluate_multi(&self, points: &[Integer]) -> Vec<Integer> {
+        self.rem_many(points)
+    }
+
+    /// The remainder of the polynomial modulo `x - root`, computed by
+    /// synthetic division. By the remainder theorem this equals
+    /// `self.evaluate(root)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Integer;
+    ///
+    /// // x^2 - 3x + 5 = (x - 2)(x - 1) + 3
+    /// let p = ZZPoly::from(vec![5, -3, 1]);
+    /// assert_eq!(p.rem_linear(&Integer::from(2)), Integer::from(3));
+    /// ```
+    pub fn rem_linear(&self, root: &Integer) -> Integer {
+        // Each step brings down the next coefficient and adds root times the
+        // running value; the final value is the remainder.
+        let mut rem = Integer::from(0);
+        for coeff in self.coeffs.iter().rev() {
+            rem *= root;
+            rem += coeff;
+        }
+        rem
+    }
+
+    /// The remainders of the polynomial modulo `x - root` for each of
+    /// `roots`. Large root sets are reduced down a subproduct tree of the
+    /// linear factors, smaller ones use [`rem_linear`](ZZPoly::rem_linear) at
+    /// each root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Integer;
+    ///
+    /// let p = ZZPoly::from(vec![5, -3, 1]);
+    /// let roots = vec![Integer::from(2), Integer::from(-1)];
+    /// assert_eq!(p.rem_many(&roots), vec![Integer::from(3), Integer::from(9)]);
+    /// ```
+    pub fn rem_many(&self, roots: &[Integer]) -> Vec<Integer> {
+        if roots.len() < MULTIPOINT_EVAL_THRESHOLD || self.length() <= 1 {
+            return roots.iter().map(|root| self.rem_linear(root)).collect();
+        }
+        SubproductTree::new(roots)
+            .leaf_remainders(self)
+            .iter()
+            .map(|r| r.coeff(0))
+            .collect()
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::Integer;
+use malachite::base::num::arithmetic::traits::{Gcd, UnsignedAbs};
+use crate::zz_poly::ZZPoly;
+
+impl ZZPoly {
+    /// Return the greatest common divisor of `self` and `other`, normalized
+    /// to have a positive leading coefficient. The GCD of two zero polynomials
+    /// is zero.
+    ///
+    /// Uses the primitive polynomial remainder sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    ///
+    /// // gcd(2x^2 - 2, 4x - 4) = 2x - 2
+    /// let a = ZZPoly::from(vec![-2, 0, 2]);
+    /// let b = ZZPoly::from(vec![-4, 4]);
+    /// assert_eq!(a.gcd(&b), ZZPoly::from(vec![-2, 2]));
+    /// ```
+    pub fn gcd(&self, other: &ZZPoly) -> ZZPoly {
+        let content = self.content().unsigned_abs().gcd(other.content().unsigned_abs());
+        let (mut a, mut b) = if self.length() >= other.length() {
+            (self.primitive_part(), other.primitive_part())
+        } else {
+            (other.primitive_part(), self.primitive_part())
+        };
+
+        while !b.is_zero() {
+            let r = a.pseudo_rem(&b).primitive_part();
+            a = b;
+            b = r;
+        }
+        a * Integer::from(content)
+    }
+}
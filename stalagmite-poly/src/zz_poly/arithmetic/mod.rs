@@ -23,24 +23,30 @@ pub mod mul_classical;
 pub mod mul_karatsuba;
 pub mod mul_ks;
 pub mod mul_ss;
+pub mod mul_multimodular;
 pub mod sqr;
 pub mod mullow;
 pub mod rem;
+pub mod par;
+pub mod thresholds;
+pub mod div;
+pub mod gcd;
+pub mod derivative;
+pub mod evaluate;
+pub mod resultant;
 
 // TODO: Generated macros promote pimitive integers, but should instead work with 
 // Integer coefficients and primitive integer types directly.
 
-
-/*
 /// Macro for implementing binary operations between ZZPoly and primitive integer types.
 /// 
-/// This macro generates implementations for Add, Sub, Mul, Div operations and their
-/// assignment variants (AddAssign, SubAssign, etc.) between ZZPoly and primitive
+/// This macro generates implementations for Add, Sub and Mul operations and their
+/// assignment variants (AddAssign, SubAssign, MulAssign) between ZZPoly and primitive
 /// integer types (u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize).
 ///
 /// # Arguments
-/// * `$op_trait` - The operation trait (Add, Sub, Mul, Div)
-/// * `$op_method` - The method name (add, sub, mul, div)
+/// * `$op_trait` - The operation trait (Add, Sub, Mul)
+/// * `$op_method` - The method name (add, sub, mul)
 /// * `$op_assign_trait` - The assignment operation trait (AddAssign, SubAssign, etc.)
 /// * `$op_assign_method` - The assignment method name (add_assign, sub_assign, etc.)
 /// * `$prim_type` - The primitive integer type (u8, i16, etc.)
@@ -57,14 +63,13 @@ pub mod rem;
 /// - `&PrimType $op &ZZPoly -> ZZPoly`
 /// - `ZZPoly $op= PrimType`
 /// - `ZZPoly $op= &PrimType`
-#[macro_export]
 macro_rules! impl_zz_poly_primitive_binop {
     ($op_trait:ident, $op_method:ident, $op_assign_trait:ident, $op_assign_method:ident, $prim_type:ty) => {
         // ZZPoly op PrimType -> ZZPoly
         impl std::ops::$op_trait<$prim_type> for crate::zz_poly::ZZPoly {
             type Output = crate::zz_poly::ZZPoly;
             fn $op_method(self, rhs: $prim_type) -> crate::zz_poly::ZZPoly {
-                self.$op_method(malachite::Integer::from(rhs))
+                std::ops::$op_trait::$op_method(self, malachite::Integer::from(rhs))
             }
         }
 
@@ -72,99 +77,95 @@ macro_rules! impl_zz_poly_primitive_binop {
         impl std::ops::$op_trait<$prim_type> for &crate::zz_poly::ZZPoly {
             type Output = crate::zz_poly::ZZPoly;
             fn $op_method(self, rhs: $prim_type) -> crate::zz_poly::ZZPoly {
-                self.$op_method(malachite::Integer::from(rhs))
+                std::ops::$op_trait::$op_method(self, malachite::Integer::from(rhs))
             }
         }
 
-        // // ZZPoly op &PrimType -> ZZPoly
-        // impl std::ops::$op_trait<&$prim_type> for crate::zz_poly::ZZPoly {
-        //     type Output = crate::zz_poly::ZZPoly;
-        //     fn $op_method(self, rhs: &$prim_type) -> crate::zz_poly::ZZPoly {
-        //         self.$op_method(malachite::Integer::from(*rhs))
-        //     }
-        // }
+        // ZZPoly op &PrimType -> ZZPoly
+        impl std::ops::$op_trait<&$prim_type> for crate::zz_poly::ZZPoly {
+            type Output = crate::zz_poly::ZZPoly;
+            fn $op_method(self, rhs: &$prim_type) -> crate::zz_poly::ZZPoly {
+                std::ops::$op_trait::$op_method(self, malachite::Integer::from(*rhs))
+            }
+        }
 
-        // // &ZZPoly op &PrimType -> ZZPoly
-        // impl std::ops::$op_trait<&$prim_type> for &crate::zz_poly::ZZPoly {
-        //     type Output = crate::zz_poly::ZZPoly;
-        //     fn $op_method(self, rhs: &$prim_type) -> crate::zz_poly::ZZPoly {
-        //         self.$op_method(malachite::Integer::from(*rhs))
-        //     }
-        // }
+        // &ZZPoly op &PrimType -> ZZPoly
+        impl std::ops::$op_trait<&$prim_type> for &crate::zz_poly::ZZPoly {
+            type Output = crate::zz_poly::ZZPoly;
+            fn $op_method(self, rhs: &$prim_type) -> crate::zz_poly::ZZPoly {
+                std::ops::$op_trait::$op_method(self, malachite::Integer::from(*rhs))
+            }
+        }
 
         // PrimType op ZZPoly -> ZZPoly
         impl std::ops::$op_trait<crate::zz_poly::ZZPoly> for $prim_type {
             type Output = crate::zz_poly::ZZPoly;
             fn $op_method(self, rhs: crate::zz_poly::ZZPoly) -> crate::zz_poly::ZZPoly {
-                malachite::Integer::from(self).$op_method(rhs)
+                std::ops::$op_trait::$op_method(malachite::Integer::from(self), rhs)
             }
         }
 
-        // // &PrimType op ZZPoly -> ZZPoly
-        // impl std::ops::$op_trait<crate::zz_poly::ZZPoly> for &$prim_type {
-        //     type Output = crate::zz_poly::ZZPoly;
-        //     fn $op_method(self, rhs: crate::zz_poly::ZZPoly) -> crate::zz_poly::ZZPoly {
-        //         malachite::Integer::from(*self).$op_method(rhs)
-        //     }
-        // }
+        // &PrimType op ZZPoly -> ZZPoly
+        impl std::ops::$op_trait<crate::zz_poly::ZZPoly> for &$prim_type {
+            type Output = crate::zz_poly::ZZPoly;
+            fn $op_method(self, rhs: crate::zz_poly::ZZPoly) -> crate::zz_poly::ZZPoly {
+                std::ops::$op_trait::$op_method(malachite::Integer::from(*self), rhs)
+            }
+        }
 
         // PrimType op &ZZPoly -> ZZPoly
         impl std::ops::$op_trait<&crate::zz_poly::ZZPoly> for $prim_type {
             type Output = crate::zz_poly::ZZPoly;
             fn $op_method(self, rhs: &crate::zz_poly::ZZPoly) -> crate::zz_poly::ZZPoly {
-                malachite::Integer::from(self).$op_method(rhs)
+                std::ops::$op_trait::$op_method(malachite::Integer::from(self), rhs)
             }
         }
 
-        // // &PrimType op &ZZPoly -> ZZPoly
-        // impl std::ops::$op_trait<&crate::zz_poly::ZZPoly> for &$prim_type {
-        //     type Output = crate::zz_poly::ZZPoly;
-        //     fn $op_method(self, rhs: &crate::zz_poly::ZZPoly) -> crate::zz_poly::ZZPoly {
-        //         malachite::Integer::from(*self).$op_method(rhs)
-        //     }
-        // }
+        // &PrimType op &ZZPoly -> ZZPoly
+        impl std::ops::$op_trait<&crate::zz_poly::ZZPoly> for &$prim_type {
+            type Output = crate::zz_poly::ZZPoly;
+            fn $op_method(self, rhs: &crate::zz_poly::ZZPoly) -> crate::zz_poly::ZZPoly {
+                std::ops::$op_trait::$op_method(malachite::Integer::from(*self), rhs)
+            }
+        }
 
         // ZZPoly op= PrimType
         impl std::ops::$op_assign_trait<$prim_type> for crate::zz_poly::ZZPoly {
             fn $op_assign_method(&mut self, rhs: $prim_type) {
-                self.$op_assign_method(malachite::Integer::from(rhs));
+                std::ops::$op_assign_trait::$op_assign_method(self, malachite::Integer::from(rhs));
             }
         }
 
-        // // ZZPoly op= &PrimType
-        // impl std::ops::$op_assign_trait<&$prim_type> for crate::zz_poly::ZZPoly {
-        //     fn $op_assign_method(&mut self, rhs: &$prim_type) {
-        //         self.$op_assign_method(malachite::Integer::from(*rhs));
-        //     }
-        // }
+        // ZZPoly op= &PrimType
+        impl std::ops::$op_assign_trait<&$prim_type> for crate::zz_poly::ZZPoly {
+            fn $op_assign_method(&mut self, rhs: &$prim_type) {
+                std::ops::$op_assign_trait::$op_assign_method(self, malachite::Integer::from(*rhs));
+            }
+        }
     };
 }
 
 /// Convenience macro to implement all binary operations for a primitive type.
 /// 
-/// This macro calls `impl_zz_poly_primitive_binop!` for Add, Sub, Mul, and Div operations.
-#[macro_export]
+/// This macro calls `impl_zz_poly_primitive_binop!` for Add, Sub and Mul operations.
 macro_rules! impl_zz_poly_primitive_ops {
     ($prim_type:ty) => {
         impl_zz_poly_primitive_binop!(Add, add, AddAssign, add_assign, $prim_type);
         impl_zz_poly_primitive_binop!(Sub, sub, SubAssign, sub_assign, $prim_type);
         impl_zz_poly_primitive_binop!(Mul, mul, MulAssign, mul_assign, $prim_type);
-        impl_zz_poly_primitive_binop!(Div, div, DivAssign, div_assign, $prim_type);
     };
 }
 
-/// Macro to implement operations for all standard primitive integer types.
-
-// impl_zz_poly_primitive_ops!(u8);
-// impl_zz_poly_primitive_ops!(u16);
-// impl_zz_poly_primitive_ops!(u32);
-// impl_zz_poly_primitive_ops!(u64);
-// impl_zz_poly_primitive_ops!(u128);
-// impl_zz_poly_primitive_ops!(usize);
-// impl_zz_poly_primitive_ops!(i8);
-// impl_zz_poly_primitive_ops!(i16);
-// impl_zz_poly_primitive_ops!(i32);
-// impl_zz_poly_primitive_ops!(i64);
-// impl_zz_poly_primitive_ops!(i128);
-// impl_zz_poly_primitive_ops!(isize);
-// */
+// Implement operations for all standard primitive integer types.
+impl_zz_poly_primitive_ops!(u8);
+impl_zz_poly_primitive_ops!(u16);
+impl_zz_poly_primitive_ops!(u32);
+impl_zz_poly_primitive_ops!(u64);
+impl_zz_poly_primitive_ops!(u128);
+impl_zz_poly_primitive_ops!(usize);
+impl_zz_poly_primitive_ops!(i8);
+impl_zz_poly_primitive_ops!(i16);
+impl_zz_poly_primitive_ops!(i32);
+impl_zz_poly_primitive_ops!(i64);
+impl_zz_poly_primitive_ops!(i128);
+impl_zz_poly_primitive_ops!(isize);
@@ -26,23 +26,12 @@ use std::ops::{
 use crate::zz_poly::arithmetic::mul_classical;
 use crate::zz_poly::arithmetic::mul_karatsuba;
 use crate::zz_poly::arithmetic::mul_ks;
+use crate::zz_poly::arithmetic::mul_multimodular;
+use crate::zz_poly::arithmetic::mul_ss;
 use crate::zz_poly::arithmetic::sqr;
-
-/// Get the maximum number of bits needed to represent any coefficient.
-/// 
-/// This is used for algorithm selection - larger coefficients may benefit
-/// from different multiplication algorithms.
-fn max_coefficient_bits(poly: &[Integer]) -> usize {
-    poly.iter()
-        .map(|coeff| {
-            // Simple approximation: convert to string length * 3.32 (log base 2 of 10)
-            // This gives a rough estimate of bit size
-            let str_len = coeff.to_string().len();
-            if str_len <= 1 { 1 } else { (str_len as f64 * 3.32) as usize }
-        })
-        .max()
-        .unwrap_or(1)
-}
+use crate::zz_poly::arithmetic::sqr::max_coefficient_bits;
+use crate::zz_poly::arithmetic::par::{scale, scale_assign, shl_assign};
+use crate::zz_poly::arithmetic::thresholds::MULTIMODULAR_THRESHOLD;
 
 /// Intelligent algorithm selection for polynomial multiplication.
 /// 
@@ -56,6 +45,7 @@ fn max_coefficient_bits(poly: &[Integer]) -> usize {
 /// - Very small polynomials: classical multiplication
 /// - Medium polynomials with large coefficients: Karatsuba  
 /// - Large polynomials with small coefficients: Kronecker substitution
+/// - Very long polynomials with large coefficients: multi-modular
 ///
 /// Eventually Schönhage-Strassen and NTT, hopefully.
 /// 
@@ -79,11 +69,29 @@ fn auto_mul(poly1: &[Integer], len1: usize, poly2: &[Integer], len2: usize) -> V
         return mul_classical::classical_mul(poly1, len1, poly2, len2);
     }
     
-    // Use squaring algorithm if polynomials are identical
-    if len1 == len2 && poly1 == poly2 {
+    // Use squaring algorithm if polynomials are identical. Checking the
+    // pointers first avoids comparing every coefficient of an operand with
+    // itself.
+    if len1 == len2 && (std::ptr::eq(poly1.as_ptr(), poly2.as_ptr()) || poly1 == poly2) {
         return sqr::auto_sqr(poly1, len1);
     }
     
+    match select_mul_algorithm(poly1, len1, poly2, len2) {
+        MulAlgorithm::Classical => mul_classical::classical_mul(poly1, len1, poly2, len2),
+        MulAlgorithm::KS => mul_ks::ks_mul(poly1, len1, poly2, len2),
+        MulAlgorithm::Multimodular => mul_multimodular::multimodular_mul(poly1, len1, poly2, len2),
+        _ => mul_karatsuba::karatsuba_mul(poly1, len1, poly2, len2),
+    }
+}
+
+/// Choose the algorithm [`auto_mul`] uses for two distinct operands of
+/// length at least two.
+fn select_mul_algorithm(
+    poly1: &[Integer],
+    len1: usize,
+    poly2: &[Integer],
+    len2: usize,
+) -> MulAlgorithm {
     let min_len = len1.min(len2);
     let max_len = len1.max(len2);
     
@@ -95,23 +103,134 @@ fn auto_mul(poly1: &[Integer], len1: usize, poly2: &[Integer], len2: usize) -> V
     // Algorithm selection logic based on flint's approach
     if max_len <= 6 && total_bits <= 5000 {
         // Very small polynomials: classical is most efficient
-        mul_classical::classical_mul(poly1, len1, poly2, len2)
+        MulAlgorithm::Classical
     } else if max_len <= 8 && total_bits >= 1500 && total_bits <= 10000 {
         // Medium size with large coefficients: Karatsuba
-        mul_karatsuba::karatsuba_mul(poly1, len1, poly2, len2)
+        MulAlgorithm::Karatsuba
     // } else if max_len >= 8 && max_len <= 75 && total_bits >= 800 && total_bits <= 4000 {
     //     // Medium-large with medium coefficients: Schönhage-Strassen region
     } else if min_len < 16 && (bits1 > 1000 || bits2 > 1000) {
         // One small polynomial with large coefficients: Karatsuba
-        mul_karatsuba::karatsuba_mul(poly1, len1, poly2, len2)
+        MulAlgorithm::Karatsuba
     } else if total_bits <= 800 || max_len < 50 {
         // Small total bit complexity or medium size: Kronecker substitution
-        mul_ks::ks_mul(poly1, len1, poly2, len2)
+        MulAlgorithm::KS
     // } else if max_len >= 1000 {
     //     // Very large polynomials: Schönhage-Strassen
+    } else if min_len >= MULTIMODULAR_THRESHOLD.get() {
+        // Long polynomials with large coefficients: multi-modular, which
+        // grows more slowly with the length than Karatsuba
+        MulAlgorithm::Multimodular
     } else {
         // Default fallback: Karatsuba for medium cases
-        mul_karatsuba::karatsuba_mul(poly1, len1, poly2, len2)
+        MulAlgorithm::Karatsuba
+    }
+}
+
+/// A multiplication algorithm for [`ZZPoly::mul_with`].
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub enum MulAlgorithm {
+    /// Schoolbook multiplication.
+    Classical,
+    /// Karatsuba's divide and conquer multiplication.
+    Karatsuba,
+    /// Kronecker substitution.
+    KS,
+    /// Schönhage-Strassen multiplication.
+    SS,
+    /// Multi-modular multiplication with CRT reconstruction.
+    Multimodular,
+    /// The algorithm chosen by the `*` operator.
+    #[default]
+    Auto,
+}
+
+impl ZZPoly {
+    /// Multiply by `other` using the given algorithm, regardless of which
+    /// algorithm the `*` operator would choose. The product is the same for
+    /// every algorithm; this is intended for benchmarking and for working
+    /// around a poor automatic choice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use stalagmite_poly::zz_poly::arithmetic::mul::MulAlgorithm;
+    ///
+    /// let p1 = ZZPoly::from(vec![1, 2]);
+    /// let p2 = ZZPoly::from(vec![3, 4]);
+    /// assert_eq!(p1.mul_with(&p2, MulAlgorithm::Karatsuba), ZZPoly::from(vec![3, 10, 8]));
+    /// assert_eq!(p1.mul_with(&p2, MulAlgorithm::Auto), &p1 * &p2);
+    /// ```
+    pub fn mul_with(&self, other: &ZZPoly, algo: MulAlgorithm) -> ZZPoly {
+        match algo {
+            MulAlgorithm::Classical => mul_classical::mul_classical(self, other),
+            MulAlgorithm::Karatsuba => mul_karatsuba::mul_karatsuba(self, other),
+            MulAlgorithm::KS => mul_ks::mul_ks(self, other),
+            MulAlgorithm::SS => mul_ss::mul_ss(self, other),
+            MulAlgorithm::Multimodular => mul_multimodular::mul_multimodular(self, other),
+            MulAlgorithm::Auto => self * other,
+        }
+    }
+
+    /// The algorithm `mul_with(other, MulAlgorithm::Auto)` uses to multiply
+    /// `self` by `other`, taking the current
+    /// [`thresholds`](crate::zz_poly::arithmetic::thresholds) into account.
+    /// Products with a zero or constant operand are reported as classical.
+    /// Equal operands are squared instead, so the result does not apply to
+    /// them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use stalagmite_poly::zz_poly::arithmetic::mul::MulAlgorithm;
+    ///
+    /// let p1 = ZZPoly::from(vec![1, 2]);
+    /// let p2 = ZZPoly::from(vec![3, 4]);
+    /// assert_eq!(p1.mul_algorithm(&p2), MulAlgorithm::Classical);
+    /// ```
+    pub fn mul_algorithm(&self, other: &ZZPoly) -> MulAlgorithm {
+        let len1 = self.length();
+        let len2 = other.length();
+        if len1 <= 1 || len2 <= 1 {
+            return MulAlgorithm::Classical;
+        }
+        select_mul_algorithm(&self.coeffs, len1, &other.coeffs, len2)
+    }
+
+    /// Multiply every coefficient by `factor` in place. Unlike `*=` this does
+    /// not special-case zero or one, which saves the comparisons when scaling
+    /// repeatedly by the same generic constant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Integer;
+    ///
+    /// let mut p = ZZPoly::from(vec![1, -2, 3]);
+    /// p.scale_assign(&Integer::from(-4));
+    /// assert_eq!(p, ZZPoly::from(vec![-4, 8, -12]));
+    /// ```
+    pub fn scale_assign(&mut self, factor: &Integer) {
+        scale_assign(&mut self.coeffs, factor);
+        self.normalize();
+    }
+
+    /// Multiply every coefficient by `2^shift` in place using bit shifts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    ///
+    /// let mut p = ZZPoly::from(vec![1, -3, 5]);
+    /// p.scale_pow2_assign(4);
+    /// assert_eq!(p, ZZPoly::from(vec![16, -48, 80]));
+    /// ```
+    pub fn scale_pow2_assign(&mut self, shift: u64) {
+        shl_assign(&mut self.coeffs, shift);
     }
 }
 
@@ -237,10 +356,17 @@ impl Mul<ZZPoly> for &ZZPoly {
 /// let poly = ZZPoly::from(vec![1, 2, 3]);
 /// assert_eq!(&poly * &one, poly);
 /// assert_eq!(&one * &poly, poly);
+///
+/// // Multiplying a reference by itself uses the squaring algorithm
+/// assert_eq!(&poly * &poly, ZZPoly::from(vec![1, 4, 10, 12, 9]));
 /// ```
 impl Mul<&ZZPoly> for &ZZPoly {
     type Output = ZZPoly;
     fn mul(self, rhs: &ZZPoly) -> ZZPoly {
+        // `&p * &p` squares directly
+        if std::ptr::eq(self, rhs) {
+            return sqr::sqr(self);
+        }
         if self.is_zero() || rhs.is_zero() {
             return ZZPoly::zero();
         }
@@ -358,9 +484,7 @@ impl Mul<Integer> for ZZPoly {
             return self;
         }
 
-        for coeff in &mut self.coeffs {
-            *coeff *= &rhs;
-        }
+        scale_assign(&mut self.coeffs, &rhs);
         self
     }
 }
@@ -388,9 +512,7 @@ impl Mul<&Integer> for ZZPoly {
             return self;
         }
 
-        for coeff in &mut self.coeffs {
-            *coeff *= rhs;
-        }
+        scale_assign(&mut self.coeffs, rhs);
         self
     }
 }
@@ -463,7 +585,7 @@ impl Mul<&ZZPoly> for Integer {
             return rhs.clone();
         }
 
-        let coeffs = rhs.coeffs.iter().map(|c| &self * c).collect();
+        let coeffs = scale(&rhs.coeffs, &self);
         ZZPoly::from_raw(coeffs)
     }
 }
@@ -491,7 +613,7 @@ impl Mul<&ZZPoly> for &Integer {
             return rhs.clone();
         }
 
-        let coeffs = rhs.coeffs.iter().map(|c| self * c).collect();
+        let coeffs = scale(&rhs.coeffs, self);
         ZZPoly::from_raw(coeffs)
     }
 }
@@ -518,7 +640,7 @@ impl Mul<Integer> for &ZZPoly {
             return self.clone();
         }
 
-        let coeffs = self.coeffs.iter().map(|c| c * &rhs).collect();
+        let coeffs = scale(&self.coeffs, &rhs);
         ZZPoly::from_raw(coeffs)
     }
 }
@@ -546,7 +668,7 @@ impl Mul<&Integer> for &ZZPoly {
             return self.clone();
         }
 
-        let coeffs = self.coeffs.iter().map(|c| c * rhs).collect();
+        let coeffs = scale(&self.coeffs, rhs);
         ZZPoly::from_raw(coeffs)
     }
 }
@@ -583,9 +705,7 @@ impl MulAssign<Integer> for ZZPoly {
             return;
         }
 
-        for coeff in &mut self.coeffs {
-            *coeff *= &rhs;
-        }
+        scale_assign(&mut self.coeffs, &rhs);
     }
 }
 
@@ -612,9 +732,7 @@ impl MulAssign<&Integer> for ZZPoly {
             return;
         }
 
-        for coeff in &mut self.coeffs {
-            *coeff *= rhs;
-        }
+        scale_assign(&mut self.coeffs, rhs);
     }
 }
 
@@ -28,10 +28,8 @@
 use malachite::Integer;
 use crate::zz_poly::ZZPoly;
 use crate::zz_poly::arithmetic::mul_classical::classical_mul;
+use crate::zz_poly::arithmetic::thresholds::KARATSUBA_THRESHOLD;
 
-/// Threshold below which we use classical multiplication instead of Karatsuba.
-/// This avoids the overhead of recursion for small polynomials.
-const KARATSUBA_THRESHOLD: usize = 8;
 
 /// Add polynomial `src` to `dst` starting at position `offset`.
 /// 
@@ -95,7 +93,8 @@ pub fn karatsuba_mul(poly1: &[Integer], len1: usize, poly2: &[Integer], len2: us
     }
     
     // Use classical multiplication for small inputs
-    if len1 < KARATSUBA_THRESHOLD || len2 < KARATSUBA_THRESHOLD {
+    let threshold = KARATSUBA_THRESHOLD.get();
+    if len1 < threshold || len2 < threshold {
         return classical_mul(poly1, len1, poly2, len2);
     }
     
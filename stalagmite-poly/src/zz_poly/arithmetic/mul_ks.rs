@@ -29,12 +29,12 @@
 //! algorithms.
 
 use malachite::Integer;
+use malachite::base::num::arithmetic::traits::{ModPowerOf2, PowerOf2};
+use malachite::base::num::logic::traits::{BitAccess, SignificantBits};
 use crate::zz_poly::ZZPoly;
 use crate::zz_poly::arithmetic::mul_classical::classical_mul;
+use crate::zz_poly::arithmetic::thresholds::KS_THRESHOLD;
 
-/// Minimum length threshold for using Kronecker substitution.
-/// Below this threshold, classical multiplication is more efficient.
-const KS_THRESHOLD: usize = 16;
 
 /// Evaluate a polynomial at a given base using Horner's method.
 /// 
@@ -124,48 +124,63 @@ pub fn extract_coefficients(value: &Integer, base: &Integer, expected_len: usize
     coeffs
 }
 
-/// Choose an appropriate base for Kronecker substitution.
-/// 
-/// The base must be large enough to prevent overflow between coefficients
-/// in the product polynomial. A safe choice is to make the base larger than
-/// the maximum possible coefficient in the product.
-/// 
-/// # Arguments
-/// 
-/// * `poly1` - First polynomial coefficients
-/// * `len1` - Length of first polynomial
-/// * `poly2` - Second polynomial coefficients  
-/// * `len2` - Length of second polynomial
-/// 
-/// # Returns
-/// 
-/// A base suitable for Kronecker substitution.
-fn choose_base(poly1: &[Integer], len1: usize, poly2: &[Integer], len2: usize) -> Integer {
-    // Find maximum absolute coefficients using simple approach
-    let max1 = poly1.iter().take(len1).map(|x| if *x >= 0 { x.clone() } else { -x }).max()
-        .unwrap_or_else(|| Integer::from(1));
-    let max2 = poly2.iter().take(len2).map(|x| if *x >= 0 { x.clone() } else { -x }).max()
-        .unwrap_or_else(|| Integer::from(1));
+/// Number of bits per coefficient when packing at a power of two, enough to
+/// hold any coefficient of the product together with its sign.
+pub(crate) fn packing_bits(poly1: &[Integer], len1: usize, poly2: &[Integer], len2: usize) -> u64 {
+    let bits1 = poly1[..len1].iter().map(|x| x.significant_bits()).max().unwrap_or(0);
+    let bits2 = poly2[..len2].iter().map(|x| x.significant_bits()).max().unwrap_or(0);
+    bits1 + bits2 + (len1.min(len2) as u64).significant_bits() + 1
+}
+
+/// Evaluate a polynomial at `2^bits`.
+/// 
+/// The coefficients are combined by halves so that the work is done in a few
+/// large shifts and additions rather than one per coefficient.
+pub(crate) fn pack(poly: &[Integer], bits: u64) -> Integer {
+    match poly.len() {
+        0 => Integer::from(0),
+        1 => poly[0].clone(),
+        len => {
+            let half = len / 2;
+            pack(&poly[..half], bits) + (pack(&poly[half..], bits) << (half as u64 * bits))
+        }
+    }
+}
+
+/// Extract `len` coefficients from a value produced by [`pack`], appending
+/// them to `out`. Each coefficient must have absolute value below
+/// `2^(bits - 1)`.
+pub(crate) fn unpack(value: Integer, bits: u64, len: usize, out: &mut Vec<Integer>) {
+    if len == 0 {
+        return;
+    } else if len == 1 {
+        out.push(value);
+        return;
+    }
     
-    // In the product, each coefficient is a sum of at most min(len1, len2) terms,
-    // each term being a product of coefficients from the input polynomials.
-    // So the maximum coefficient magnitude is approximately:
-    // min(len1, len2) * max1 * max2
-    let max_terms = Integer::from(len1.min(len2));
-    let max_product_coeff = &max_terms * &max1 * &max2;
+    // The low half is the balanced remainder modulo 2^shift, which is
+    // exactly the packed value of the low coefficients.
+    let half = len / 2;
+    let shift = half as u64 * bits;
+    let low_bits = (&value).mod_power_of_2(shift);
+    let low = if low_bits.get_bit(shift - 1) {
+        Integer::from(low_bits) - Integer::power_of_2(shift)
+    } else {
+        Integer::from(low_bits)
+    };
+    let high = (value - &low) >> shift;
     
-    // Choose base to be larger than the maximum possible coefficient
-    // We multiply by 2 for safety margin
-    &max_product_coeff * Integer::from(2) + Integer::from(1)
+    unpack(low, bits, half, out);
+    unpack(high, bits, len - half, out);
 }
 
 /// Kronecker substitution multiplication algorithm.
 /// 
 /// Converts polynomial multiplication to integer multiplication by:
-/// 1. Choosing a suitable base B
+/// 1. Choosing a power of two B large enough to hold every product coefficient
 /// 2. Evaluating both polynomials at B to get integers
 /// 3. Multiplying the integers
-/// 4. Extracting coefficients from the product
+/// 4. Extracting the coefficients of the product as balanced digits in base B
 /// 
 /// # Arguments
 /// 
@@ -196,23 +211,25 @@ pub fn ks_mul(poly1: &[Integer], len1: usize, poly2: &[Integer], len2: usize) ->
     }
     
     // Use classical multiplication for small polynomials
-    if len1 < KS_THRESHOLD || len2 < KS_THRESHOLD {
+    let threshold = KS_THRESHOLD.get();
+    if len1 < threshold || len2 < threshold {
         return classical_mul(poly1, len1, poly2, len2);
     }
     
-    // Choose appropriate base
-    let base = choose_base(poly1, len1, poly2, len2);
-    
-    // Evaluate polynomials at the base
-    let val1 = evaluate_at_base(poly1, len1, &base);
-    let val2 = evaluate_at_base(poly2, len2, &base);
+    // Evaluate both polynomials at a power of two large enough to hold
+    // every product coefficient
+    let bits = packing_bits(poly1, len1, poly2, len2);
+    let val1 = pack(&poly1[..len1], bits);
+    let val2 = pack(&poly2[..len2], bits);
     
     // Multiply the evaluated values
     let product = val1 * val2;
     
     // Extract coefficients from the product
     let result_len = len1 + len2 - 1;
-    extract_coefficients(&product, &base, result_len)
+    let mut result = Vec::with_capacity(result_len);
+    unpack(product, bits, result_len, &mut result);
+    result
 }
 
 /// Kronecker substitution multiplication for ZZPoly.
@@ -276,18 +293,6 @@ mod tests {
         assert_eq!(result, vec![Integer::from(1), Integer::from(2), Integer::from(3)]);
     }
     
-    #[test]
-    fn test_choose_base() {
-        let poly1 = vec![Integer::from(1), Integer::from(2)];
-        let poly2 = vec![Integer::from(3), Integer::from(4)];
-        let base = choose_base(&poly1, 2, &poly2, 2);
-        
-        // Base should be large enough to handle the multiplication
-        // Maximum coefficient in (1+2x)(3+4x) = 3+10x+8x² is 10
-        // So base should be > 10
-        assert!(base > Integer::from(10));
-    }
-    
     #[test]
     fn test_ks_mul_basic() {
         let poly1 = vec![Integer::from(1), Integer::from(2)]; // 1 + 2x
@@ -309,6 +314,27 @@ mod tests {
         assert_eq!(ks_result, classical_result);
     }
     
+    #[test]
+    fn test_ks_mul_mixed_signs() {
+        let poly1: Vec<Integer> = (-10..10).map(|i| Integer::from(i * i - 7 * i)).collect();
+        let poly2: Vec<Integer> = (0..20).map(|i| Integer::from(if i % 2 == 0 { -i } else { i + 3 })).collect();
+        
+        let ks_result = ks_mul(&poly1, 20, &poly2, 20);
+        let classical_result = classical_mul(&poly1, 20, &poly2, 20);
+        
+        assert_eq!(ks_result, classical_result);
+    }
+    
+    #[test]
+    fn test_pack_unpack_roundtrip() {
+        let original: Vec<Integer> = (-6..7).map(|i| Integer::from(i * 1000 + 1)).collect();
+        let packed = pack(&original, 16);
+        
+        let mut unpacked = Vec::new();
+        unpack(packed, 16, original.len(), &mut unpacked);
+        assert_eq!(original, unpacked);
+    }
+    
     #[test]
     fn test_ks_mul_small_fallback() {
         // Small polynomials should fall back to classical
@@ -0,0 +1,404 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+//! Multi-modular polynomial multiplication.
+//!
+//! The operands are reduced modulo enough word-sized primes that their
+//! product modulo all of them determines the integer product, the images are
+//! multiplied over Z/pZ, and the product is recovered coefficient-wise with
+//! the Chinese remainder theorem.
+//!
+//! Every prime is of the form `c 2^32 + 1`, so each image is multiplied with a
+//! number-theoretic transform in word arithmetic, using Montgomery reduction
+//! for the products. The cost grows linearly with the number of primes, and
+//! the method suits long polynomials whose coefficients are very large.
+
+use std::sync::{OnceLock, RwLock};
+use malachite::{Integer, Natural};
+use malachite::base::num::logic::traits::SignificantBits;
+use stalagmite_base::LIMB_BITS;
+use stalagmite_base::is_prime::is_prime;
+use crate::zz_poly::ZZPoly;
+use crate::zz_poly::arithmetic::mul_classical::classical_mul;
+
+/// Every modulus used is a prime just below `2^MULTIMODULAR_PRIME_BITS`.
+const MULTIMODULAR_PRIME_BITS: u64 = 62;
+
+/// Every modulus is one more than a multiple of `2^NTT_ORDER_BITS`, which
+/// bounds the length of a product.
+const NTT_ORDER_BITS: u32 = 32;
+
+// A prime p = c 2^32 + 1 with the constants for Montgomery arithmetic modulo
+// p, where R = 2^64.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NttPrime {
+    p: u64,
+    // -p^(-1) mod R
+    p_neg_inv: u64,
+    // R^2 mod p
+    r2: u64,
+    // an element of order 2^NTT_ORDER_BITS
+    root: u64,
+}
+
+impl NttPrime {
+    fn new(p: u64) -> Self {
+        // Newton iteration doubles the number of correct low bits of p^(-1),
+        // starting from the three given by p itself.
+        let mut inv = p;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(inv)));
+        }
+        let r = ((1u128 << 64) % p as u128) as u64;
+        let mut prime = NttPrime { p, p_neg_inv: inv.wrapping_neg(), r2: 0, root: 0 };
+        prime.r2 = prime.mul_slow(r, r);
+
+        let c = p >> NTT_ORDER_BITS;
+        prime.root = (2..)
+            .map(|x| prime.pow_slow(x, c))
+            .find(|&w| prime.pow_slow(w, 1 << (NTT_ORDER_BITS - 1)) != 1)
+            .unwrap();
+        prime
+    }
+
+    // a b mod p by division, for precomputation.
+    fn mul_slow(&self, a: u64, b: u64) -> u64 {
+        ((a as u128 * b as u128) % self.p as u128) as u64
+    }
+
+    fn pow_slow(&self, mut a: u64, mut e: u64) -> u64 {
+        let mut result = 1;
+        while e > 0 {
+            if e & 1 == 1 {
+                result = self.mul_slow(result, a);
+            }
+            a = self.mul_slow(a, a);
+            e >>= 1;
+        }
+        result
+    }
+
+    // Montgomery reduction: given t < pR return tR^(-1) mod p.
+    #[inline]
+    fn redc(&self, t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(self.p_neg_inv);
+        let u = ((t + m as u128 * self.p as u128) >> 64) as u64;
+        reduce_once(u, self.p)
+    }
+
+    // aR mod p, so that redc(b * to_montgomery(a)) = ab mod p.
+    #[inline]
+    fn to_montgomery(&self, a: u64) -> u64 {
+        self.redc(a as u128 * self.r2 as u128)
+    }
+
+    #[inline]
+    fn add(&self, a: u64, b: u64) -> u64 {
+        reduce_once(a + b, self.p)
+    }
+
+    #[inline]
+    fn sub(&self, a: u64, b: u64) -> u64 {
+        reduce_once(a + self.p - b, self.p)
+    }
+
+    // Residues of the coefficients modulo p, padded with zeros to length n.
+    // Limb j of a coefficient contributes redc(limb * 2^(j LIMB_BITS) R).
+    fn reduce(&self, poly: &[Integer], n: usize) -> Vec<u64> {
+        let limb_radix = self.pow_slow(2, LIMB_BITS as u64);
+        let mut radix_powers = vec![self.to_montgomery(1)];
+        let mut residues = Vec::with_capacity(n);
+        for c in poly {
+            let abs = c.unsigned_abs_ref();
+            let mut r = 0;
+            for (j, limb) in abs.limbs().enumerate() {
+                if j == radix_powers.len() {
+                    radix_powers.push(self.mul_slow(radix_powers[j - 1], limb_radix));
+                }
+                r = self.add(r, self.redc(limb as u128 * radix_powers[j] as u128));
+            }
+            residues.push(if *c < 0 { self.sub(0, r) } else { r });
+        }
+        residues.resize(n, 0);
+        residues
+    }
+
+    // In-place number-theoretic transform of a power-of-two length vector,
+    // evaluating at the powers of `root`, an element of order `a.len()`.
+    fn ntt(&self, a: &mut [u64], root: u64) {
+        let n = a.len();
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j |= bit;
+            if i < j {
+                a.swap(i, j);
+            }
+        }
+
+        // twiddles[i] = root^i in Montgomery form; a butterfly of width `len`
+        // uses every (n / len)-th entry
+        let root = self.to_montgomery(root);
+        let mut twiddles = Vec::with_capacity(n / 2);
+        let mut w = self.to_montgomery(1);
+        for _ in 0..n / 2 {
+            twiddles.push(w);
+            w = self.redc(w as u128 * root as u128);
+        }
+
+        let mut len = 2;
+        while len <= n {
+            let step = n / len;
+            for block in a.chunks_exact_mut(len) {
+                let (lo, hi) = block.split_at_mut(len / 2);
+                for ((u, v), w) in lo.iter_mut().zip(hi.iter_mut()).zip(twiddles.iter().step_by(step)) {
+                    let t = self.redc(*v as u128 * *w as u128);
+                    *v = self.sub(*u, t);
+                    *u = self.add(*u, t);
+                }
+            }
+            len <<= 1;
+        }
+    }
+
+    // Product of two polynomials modulo p, as `len` residues.
+    fn mul(&self, poly1: &[Integer], poly2: &[Integer], len: usize) -> Vec<u64> {
+        let n = len.next_power_of_two();
+        assert!(n.trailing_zeros() <= NTT_ORDER_BITS, "product too long for multi-modular multiplication");
+        let root = self.pow_slow(self.root, 1 << (NTT_ORDER_BITS - n.trailing_zeros()));
+
+        let mut a = self.reduce(poly1, n);
+        let mut b = self.reduce(poly2, n);
+        self.ntt(&mut a, root);
+        self.ntt(&mut b, root);
+        for (x, y) in a.iter_mut().zip(b.iter()) {
+            *x = self.redc(*x as u128 * self.to_montgomery(*y) as u128);
+        }
+
+        // The inverse transform evaluates at the inverse root and divides by n.
+        self.ntt(&mut a, self.pow_slow(root, n as u64 - 1));
+        let n_inv = self.to_montgomery(self.pow_slow(n as u64, self.p - 2));
+        a.truncate(len);
+        for x in a.iter_mut() {
+            *x = self.redc(*x as u128 * n_inv as u128);
+        }
+        a
+    }
+}
+
+// Primes c 2^32 + 1 below 2^62 in decreasing order, extended as needed.
+static MULTIMODULAR_PRIMES: OnceLock<RwLock<Vec<NttPrime>>> = OnceLock::new();
+
+// Return the first `count` primes c 2^32 + 1 below 2^62, largest first.
+fn multimodular_primes(count: usize) -> Vec<NttPrime> {
+    let primes = MULTIMODULAR_PRIMES.get_or_init(|| RwLock::new(Vec::new()));
+    let cache = primes.read().unwrap();
+    if cache.len() >= count {
+        return cache[..count].to_vec();
+    }
+    drop(cache);
+
+    let mut cache = primes.write().unwrap();
+    let mut c = cache
+        .last()
+        .map_or(1 << (MULTIMODULAR_PRIME_BITS - NTT_ORDER_BITS as u64), |prime| prime.p >> NTT_ORDER_BITS);
+    while cache.len() < count {
+        c -= 1;
+        let p = (c << NTT_ORDER_BITS) + 1;
+        if is_prime(Natural::from(p)) {
+            cache.push(NttPrime::new(p));
+        }
+    }
+    cache[..count].to_vec()
+}
+
+// a mod p for a < 2p. The residues are random, so a branch here is
+// mispredicted half the time; the wrapping subtraction compiles to a cmov.
+#[inline]
+fn reduce_once(a: u64, p: u64) -> u64 {
+    a.min(a.wrapping_sub(p))
+}
+
+// Reconstruct each coefficient from its residues with Garner's algorithm. The
+// mixed-radix digits are computed in word arithmetic, and the result is
+// lifted to the symmetric range modulo the product of the primes.
+fn crt_reconstruct(images: Vec<Vec<u64>>, primes: &[NttPrime], len: usize) -> Vec<Integer> {
+    // inverses[j][i] is the inverse of p_i modulo p_j for i < j, in
+    // Montgomery form
+    let inverses: Vec<Vec<u64>> = primes
+        .iter()
+        .enumerate()
+        .map(|(j, pj)| {
+            primes[..j]
+                .iter()
+                .map(|pi| pj.to_montgomery(pj.pow_slow(pi.p % pj.p, pj.p - 2)))
+                .collect()
+        })
+        .collect();
+
+    let modulus: Natural = primes.iter().map(|prime| Natural::from(prime.p)).product();
+    let half = &modulus >> 1u64;
+    let modulus = Integer::from(modulus);
+
+    // Garner's digits, computed one prime at a time across all coefficients
+    let mut digits = images;
+    for (j, pj) in primes.iter().enumerate() {
+        let (done, rest) = digits.split_at_mut(j);
+        for (di, &inv) in done.iter().zip(inverses[j].iter()) {
+            for (v, &d) in rest[0].iter_mut().zip(di.iter()) {
+                // every prime is above 2^61, so d < 2 p_j
+                *v = pj.redc(pj.sub(*v, reduce_once(d, pj.p)) as u128 * inv as u128);
+            }
+        }
+    }
+
+    (0..len)
+        .map(|k| {
+            // x = d_0 + p_0 (d_1 + p_1 (d_2 + ...)), accumulated in limbs
+            let mut limbs = Vec::with_capacity(primes.len());
+            for (dj, pj) in digits.iter().zip(primes.iter()).rev() {
+                let mut carry = dj[k];
+                for limb in limbs.iter_mut() {
+                    let t = *limb as u128 * pj.p as u128 + carry as u128;
+                    *limb = t as u64;
+                    carry = (t >> 64) as u64;
+                }
+                if carry != 0 {
+                    limbs.push(carry);
+                }
+            }
+            let x = Natural::from_owned_limbs_asc(limbs);
+            if x > half { Integer::from(x) - &modulus } else { Integer::from(x) }
+        })
+        .collect()
+}
+
+// Number of bits in the largest absolute value of a coefficient.
+fn max_bits(poly: &[Integer]) -> u64 {
+    poly.iter().map(|c| c.unsigned_abs_ref().significant_bits()).max().unwrap_or(0)
+}
+
+/// Multiply two polynomials by multiplying their images modulo several primes
+/// and reconstructing the product with the Chinese remainder theorem.
+///
+/// # Arguments
+///
+/// * `poly1` - First polynomial coefficients
+/// * `len1` - Length of first polynomial
+/// * `poly2` - Second polynomial coefficients
+/// * `len2` - Length of second polynomial
+///
+/// # Returns
+///
+/// Vector of coefficients for the product polynomial.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_poly::zz_poly::arithmetic::mul_multimodular::multimodular_mul;
+/// use malachite::Integer;
+///
+/// let poly1 = vec![Integer::from(1), Integer::from(2)]; // 1 + 2x
+/// let poly2 = vec![Integer::from(3), Integer::from(4)]; // 3 + 4x
+/// let result = multimodular_mul(&poly1, poly1.len(), &poly2, poly2.len());
+/// // (1 + 2x)(3 + 4x) = 3 + 10x + 8x²
+/// assert_eq!(result, vec![Integer::from(3), Integer::from(10), Integer::from(8)]);
+/// ```
+pub fn multimodular_mul(poly1: &[Integer], len1: usize, poly2: &[Integer], len2: usize) -> Vec<Integer> {
+    if len1 == 0 || len2 == 0 {
+        return Vec::new();
+    }
+    if len1 == 1 || len2 == 1 {
+        return classical_mul(poly1, len1, poly2, len2);
+    }
+
+    // Every product coefficient is a sum of min(len1, len2) terms, so its
+    // absolute value is below 2^(bits1 + bits2) * min(len1, len2). The
+    // primes must multiply to more than twice that for the symmetric lift.
+    let log_len = (len1.min(len2) as u64).significant_bits();
+    let bits = max_bits(&poly1[..len1]) + max_bits(&poly2[..len2]) + log_len + 1;
+    let count = bits.div_ceil(MULTIMODULAR_PRIME_BITS - 1) as usize;
+
+    let len = len1 + len2 - 1;
+    let primes = multimodular_primes(count);
+    let images: Vec<Vec<u64>> = primes
+        .iter()
+        .map(|prime| prime.mul(&poly1[..len1], &poly2[..len2], len))
+        .collect();
+    crt_reconstruct(images, &primes, len)
+}
+
+/// Multi-modular multiplication for ZZPoly.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_poly::zz_poly::ZZPoly;
+/// use stalagmite_poly::zz_poly::arithmetic::mul_classical::mul_classical;
+/// use stalagmite_poly::zz_poly::arithmetic::mul_multimodular::mul_multimodular;
+/// use malachite::Integer;
+///
+/// let big = Integer::from(1) << 200u64;
+/// let poly1 = ZZPoly::from(vec![big.clone(), Integer::from(-3), -&big]);
+/// let poly2 = ZZPoly::from(vec![Integer::from(7), big.clone(), Integer::from(1)]);
+/// assert_eq!(mul_multimodular(&poly1, &poly2), mul_classical(&poly1, &poly2));
+/// assert!(mul_multimodular(&poly1, &ZZPoly::zero()).is_zero());
+/// ```
+pub fn mul_multimodular(poly1: &ZZPoly, poly2: &ZZPoly) -> ZZPoly {
+    if poly1.is_zero() || poly2.is_zero() {
+        return ZZPoly::zero();
+    }
+
+    let coeffs = multimodular_mul(&poly1.coeffs, poly1.length(), &poly2.coeffs, poly2.length());
+    ZZPoly::from_raw(coeffs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multimodular_primes() {
+        let primes = multimodular_primes(3);
+        assert_eq!(primes.len(), 3);
+        assert!(primes.windows(2).all(|w| w[0].p > w[1].p));
+        for prime in &primes {
+            assert_eq!(prime.p.significant_bits(), MULTIMODULAR_PRIME_BITS);
+            assert_eq!(prime.p % (1 << NTT_ORDER_BITS), 1);
+            assert_eq!(prime.p.wrapping_mul(prime.p_neg_inv), u64::MAX);
+            assert_eq!(prime.pow_slow(prime.root, 1 << (NTT_ORDER_BITS - 1)), prime.p - 1);
+        }
+        assert_eq!(multimodular_primes(2), primes[..2]);
+    }
+
+    #[test]
+    fn test_multimodular_mul_extremes() {
+        // coefficients exactly at the bound, all of one sign
+        let big = (Integer::from(1) << 500u64) - Integer::from(1);
+        let poly1 = vec![big.clone(); 8];
+        let poly2 = vec![-&big; 8];
+        assert_eq!(multimodular_mul(&poly1, 8, &poly2, 8), classical_mul(&poly1, 8, &poly2, 8));
+
+        let poly1 = vec![Integer::from(0), Integer::from(0), Integer::from(1)];
+        assert_eq!(multimodular_mul(&poly1, 3, &poly1, 3), classical_mul(&poly1, 3, &poly1, 3));
+        assert!(multimodular_mul(&poly1, 0, &poly1, 3).is_empty());
+    }
+}
@@ -26,11 +26,10 @@
 //! product when the truncation point is much smaller than the full product length.
 
 use malachite::Integer;
+use malachite::base::num::arithmetic::traits::AddMulAssign;
 use crate::zz_poly::ZZPoly;
 use crate::zz_poly::arithmetic::mul_karatsuba::karatsuba_mul;
-
-/// Threshold for using Karatsuba in truncated multiplication.
-const KARATSUBA_MULLOW_THRESHOLD: usize = 16;
+use crate::zz_poly::arithmetic::thresholds::KARATSUBA_MULLOW_THRESHOLD;
 
 /// Classical truncated multiplication algorithm.
 /// 
@@ -65,26 +64,37 @@ const KARATSUBA_MULLOW_THRESHOLD: usize = 16;
 /// assert_eq!(result, vec![Integer::from(4), Integer::from(13), Integer::from(28)]);
 /// ```
 pub fn classical_mullow(poly1: &[Integer], len1: usize, poly2: &[Integer], len2: usize, n: usize) -> Vec<Integer> {
-    if len1 == 0 || len2 == 0 || n == 0 {
-        return vec![Integer::from(0); n];
+    let mut result = Vec::with_capacity(n);
+    classical_mullow_into(poly1, len1, poly2, len2, n, &mut result);
+    result
+}
+
+// Classical truncated multiplication into `out`, which is resized to `n`
+// zeros first. Products are accumulated in place, so no temporaries are
+// allocated per term.
+fn classical_mullow_into(
+    poly1: &[Integer],
+    len1: usize,
+    poly2: &[Integer],
+    len2: usize,
+    n: usize,
+    out: &mut Vec<Integer>,
+) {
+    out.clear();
+    out.resize(n, Integer::from(0));
+    if len1 == 0 || len2 == 0 {
+        return;
     }
     
-    let mut result = vec![Integer::from(0); n];
-    
-    for k in 0..n {
+    for (k, coeff) in out.iter_mut().enumerate() {
         // For coefficient k, sum a[i] * b[k-i] for valid i
         let start_i = k.saturating_sub(len2 - 1);
         let end_i = (len1 - 1).min(k);
         
         for i in start_i..=end_i {
-            let j = k - i;
-            if i < len1 && j < len2 {
-                result[k] += &poly1[i] * &poly2[j];
-            }
+            coeff.add_mul_assign(&poly1[i], &poly2[k - i]);
         }
     }
-    
-    result
 }
 
 /// Karatsuba-based truncated multiplication.
@@ -121,8 +131,25 @@ pub fn classical_mullow(poly1: &[Integer], len1: usize, poly2: &[Integer], len2:
 /// assert_eq!(result, expected);
 /// ```
 pub fn karatsuba_mullow(poly1: &[Integer], len1: usize, poly2: &[Integer], len2: usize, n: usize) -> Vec<Integer> {
-    if len1 < KARATSUBA_MULLOW_THRESHOLD || len2 < KARATSUBA_MULLOW_THRESHOLD || n < KARATSUBA_MULLOW_THRESHOLD {
-        return classical_mullow(poly1, len1, poly2, len2, n);
+    let mut result = Vec::with_capacity(n);
+    karatsuba_mullow_into(poly1, len1, poly2, len2, n, &mut result);
+    result
+}
+
+// Karatsuba truncated multiplication into `out`. Below the split point the
+// low parts are multiplied recursively; otherwise the full product is still
+// computed in a temporary and its low coefficients are moved into `out`.
+fn karatsuba_mullow_into(
+    poly1: &[Integer],
+    len1: usize,
+    poly2: &[Integer],
+    len2: usize,
+    n: usize,
+    out: &mut Vec<Integer>,
+) {
+    let threshold = KARATSUBA_MULLOW_THRESHOLD.get();
+    if len1 < threshold || len2 < threshold || n < threshold {
+        return classical_mullow_into(poly1, len1, poly2, len2, n, out);
     }
     
     let split = len1.max(len2) / 2;
@@ -133,17 +160,16 @@ pub fn karatsuba_mullow(poly1: &[Integer], len1: usize, poly2: &[Integer], len2:
         // Only need to compute low parts
         let b1 = &poly1[0..len1.min(split)];
         let b2 = &poly2[0..len2.min(split)];
-        return karatsuba_mullow(b1, b1.len(), b2, b2.len(), n);
+        return karatsuba_mullow_into(b1, b1.len(), b2, b2.len(), n, out);
     }
     
     // Otherwise, fall back to regular truncated computation
     // A full optimized implementation would handle more cases
-    let full_result = karatsuba_mul(poly1, len1, poly2, len2);
-    let mut result = vec![Integer::from(0); n];
-    for i in 0..n.min(full_result.len()) {
-        result[i] = full_result[i].clone();
-    }
-    result
+    let mut full_result = karatsuba_mul(poly1, len1, poly2, len2);
+    full_result.truncate(n);
+    out.clear();
+    out.append(&mut full_result);
+    out.resize(n, Integer::from(0));
 }
 
 /// Automatic algorithm selection for truncated multiplication.
@@ -164,16 +190,44 @@ pub fn karatsuba_mullow(poly1: &[Integer], len1: usize, poly2: &[Integer], len2:
 /// assert_eq!(result, vec![Integer::from(4), Integer::from(13)]);
 /// ```
 pub fn auto_mullow(poly1: &[Integer], len1: usize, poly2: &[Integer], len2: usize, n: usize) -> Vec<Integer> {
-    if len1 == 0 || len2 == 0 || n == 0 {
-        return vec![Integer::from(0); n];
-    }
-    
-    // For very small truncation or small polynomials, use classical
-    if n < KARATSUBA_MULLOW_THRESHOLD || len1 < KARATSUBA_MULLOW_THRESHOLD || len2 < KARATSUBA_MULLOW_THRESHOLD {
-        classical_mullow(poly1, len1, poly2, len2, n)
-    } else {
-        karatsuba_mullow(poly1, len1, poly2, len2, n)
-    }
+    let mut result = Vec::with_capacity(n);
+    mullow_into(poly1, len1, poly2, len2, n, &mut result);
+    result
+}
+
+/// Truncated multiplication into a caller-provided buffer.
+/// 
+/// Like [`auto_mullow`], but `out` is cleared and filled with the first `n`
+/// coefficients of the product instead of allocating a new vector. Iterative
+/// algorithms such as Newton iteration can reuse one buffer across steps.
+/// 
+/// Below [`KARATSUBA_MULLOW_THRESHOLD`] no temporaries are allocated. Above
+/// it, Karatsuba still allocates the full product internally before its low
+/// coefficients are moved into `out`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use stalagmite_poly::zz_poly::arithmetic::mullow::{auto_mullow, mullow_into};
+/// use malachite::Integer;
+/// 
+/// let poly1: Vec<Integer> = (1..=5).map(Integer::from).collect();
+/// let poly2: Vec<Integer> = (6..=10).map(Integer::from).collect();
+/// let mut out = vec![Integer::from(99); 8];
+/// mullow_into(&poly1, 5, &poly2, 5, 3, &mut out);
+/// assert_eq!(out, auto_mullow(&poly1, 5, &poly2, 5, 3));
+/// ```
+pub fn mullow_into(
+    poly1: &[Integer],
+    len1: usize,
+    poly2: &[Integer],
+    len2: usize,
+    n: usize,
+    out: &mut Vec<Integer>,
+) {
+    // Empty, short or barely truncated inputs fall through to classical
+    // multiplication inside the Karatsuba routine
+    karatsuba_mullow_into(poly1, len1, poly2, len2, n, out);
 }
 
 /// Truncated multiplication for ZZPoly.
@@ -302,6 +356,23 @@ mod tests {
         assert_eq!(result, expected);
     }
     
+    #[test]
+    fn test_mullow_into() {
+        let poly1: Vec<Integer> = (0..40).map(|i| Integer::from(i * i - 17 * i + 3)).collect();
+        let poly2: Vec<Integer> = (0..30).map(|i| Integer::from(5 - 3 * i)).collect();
+        
+        // Reuse one buffer across shrinking and growing truncations, covering
+        // both the classical and Karatsuba paths
+        let mut out = Vec::new();
+        for &(len1, len2, n) in &[(40, 30, 60), (3, 2, 2), (40, 30, 20), (0, 30, 5), (40, 30, 0), (2, 3, 10)] {
+            mullow_into(&poly1, len1, &poly2, len2, n, &mut out);
+            let mut expected = crate::zz_poly::arithmetic::mul_classical::classical_mul(&poly1, len1, &poly2, len2);
+            expected.resize(n, Integer::from(0));
+            assert_eq!(out, expected, "lengths {} and {}, n = {}", len1, len2, n);
+            assert_eq!(out, auto_mullow(&poly1, len1, &poly2, len2, n));
+        }
+    }
+    
     #[test]
     fn test_mullow_zz_poly() {
         let poly1 = ZZPoly::from(vec![1, 2, 3, 4, 5]);
@@ -44,9 +44,7 @@ impl Neg for ZZPoly {
     type Output = ZZPoly;
     
     fn neg(mut self) -> ZZPoly {
-        for coeff in &mut self.coeffs {
-            *coeff = -&*coeff;
-        }
+        self.negate_assign();
         self
     }
 }
@@ -83,9 +81,27 @@ impl Neg for &ZZPoly {
 }
 
 impl NegAssign for ZZPoly {
+    #[inline]
     fn neg_assign(&mut self) {
-        for coeff in &mut self.coeffs {
-            *coeff = -&*coeff;
+        self.negate_assign();
+    }
+}
+
+impl ZZPoly {
+    /// Negate the polynomial in place, without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    ///
+    /// let mut poly = ZZPoly::from(vec![1, -2, 3]);
+    /// poly.negate_assign();
+    /// assert_eq!(poly, ZZPoly::from(vec![-1, 2, -3]));
+    /// ```
+    pub fn negate_assign(&mut self) {
+        for coeff in self.coeffs.iter_mut() {
+            coeff.neg_assign();
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,88 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+//! Coefficient-wise loops shared by the scalar multiplication, addition and
+//! subtraction code. With the `rayon` feature enabled, slices of at least
+//! `PARALLEL_THRESHOLD` coefficients are processed in parallel; each
+//! coefficient is computed exactly as in the serial loop.
+
+use malachite::Integer;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Minimum number of coefficients before the coefficient loops run in
+/// parallel when the `rayon` feature is enabled.
+pub const PARALLEL_THRESHOLD: usize = 10_000;
+
+// coeffs[i] *= c
+pub(crate) fn scale_assign(coeffs: &mut [Integer], c: &Integer) {
+    #[cfg(feature = "rayon")]
+    if coeffs.len() >= PARALLEL_THRESHOLD {
+        coeffs.par_iter_mut().for_each(|coeff| *coeff *= c);
+        return;
+    }
+    for coeff in coeffs.iter_mut() {
+        *coeff *= c;
+    }
+}
+
+// coeffs[i] *= 2^shift
+pub(crate) fn shl_assign(coeffs: &mut [Integer], shift: u64) {
+    #[cfg(feature = "rayon")]
+    if coeffs.len() >= PARALLEL_THRESHOLD {
+        coeffs.par_iter_mut().for_each(|coeff| *coeff <<= shift);
+        return;
+    }
+    for coeff in coeffs.iter_mut() {
+        *coeff <<= shift;
+    }
+}
+
+// Return [coeffs[i] * c]
+pub(crate) fn scale(coeffs: &[Integer], c: &Integer) -> Vec<Integer> {
+    #[cfg(feature = "rayon")]
+    if coeffs.len() >= PARALLEL_THRESHOLD {
+        return coeffs.par_iter().map(|coeff| coeff * c).collect();
+    }
+    coeffs.iter().map(|coeff| coeff * c).collect()
+}
+
+// a[i] += b[i] for i < b.len(), where a.len() >= b.len()
+pub(crate) fn add_assign(a: &mut [Integer], b: &[Integer]) {
+    let a = &mut a[..b.len()];
+    #[cfg(feature = "rayon")]
+    if b.len() >= PARALLEL_THRESHOLD {
+        a.par_iter_mut().zip(b.par_iter()).for_each(|(x, y)| *x += y);
+        return;
+    }
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x += y;
+    }
+}
+
+// a[i] -= b[i] for i < b.len(), where a.len() >= b.len()
+pub(crate) fn sub_assign(a: &mut [Integer], b: &[Integer]) {
+    let a = &mut a[..b.len()];
+    #[cfg(feature = "rayon")]
+    if b.len() >= PARALLEL_THRESHOLD {
+        a.par_iter_mut().zip(b.par_iter()).for_each(|(x, y)| *x -= y);
+        return;
+    }
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x -= y;
+    }
+}
@@ -15,13 +15,67 @@
 // You should have received a copy of the GNU General Public License
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
 
-use malachite::Integer;
+use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::Mod;
+use stalagmite_base::StalagmiteError;
 use crate::zz_poly::ZZPoly;
 use std::ops::{
     Rem,
     RemAssign,
 };
 
+impl ZZPoly {
+    /// Reduce each coefficient into the range `0..m`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Natural;
+    ///
+    /// let p = ZZPoly::from(vec![5, -7, 3]);
+    /// assert_eq!(p.coeffs_mod(&Natural::from(3u32)), ZZPoly::from(vec![2, 2]));
+    /// ```
+    pub fn coeffs_mod(&self, m: &Natural) -> ZZPoly {
+        if *m == 0u32 {
+            panic!("{}", StalagmiteError::DivisionByZero);
+        }
+        let m = Integer::from(m);
+        ZZPoly::from_raw(self.coeffs.iter().map(|c| c.mod_op(&m)).collect())
+    }
+
+    /// Reduce each coefficient into the symmetric range `(-m/2, m/2]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Natural;
+    ///
+    /// let p = ZZPoly::from(vec![5, 7, 3, 10]);
+    /// assert_eq!(p.coeffs_mod_symmetric(&Natural::from(5u32)), ZZPoly::from(vec![0, 2, -2]));
+    /// ```
+    pub fn coeffs_mod_symmetric(&self, m: &Natural) -> ZZPoly {
+        if *m == 0u32 {
+            panic!("{}", StalagmiteError::DivisionByZero);
+        }
+        let m = Integer::from(m);
+        let half = &m >> 1u64;
+        ZZPoly::from_raw(self.coeffs.iter().map(|c| {
+            let r = c.mod_op(&m);
+            if r > half { r - &m } else { r }
+        }).collect())
+    }
+}
+
 /// Polynomial division with remainder.
 /// 
 /// Computes the remainder when dividing `dividend` by `divisor`.
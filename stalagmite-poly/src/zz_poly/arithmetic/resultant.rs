@@ -0,0 +1,133 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::Integer;
+use malachite::base::num::arithmetic::traits::{DivExact, Pow};
+use crate::zz_poly::ZZPoly;
+
+impl ZZPoly {
+    /// Return the resultant of `self` and `other`, computed with the
+    /// subresultant polynomial remainder sequence. The resultant is zero if
+    /// either polynomial is zero or they share a root, and the resultant of a
+    /// nonzero constant `c` with a polynomial of degree `n` is `c^n`.
+    ///
+    /// The sign follows the usual convention
+    /// `res(a, b) = (-1)^(deg(a) deg(b)) res(b, a)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Integer;
+    ///
+    /// // res(x - 3, x^2 + 1) = 3^2 + 1
+    /// let a = ZZPoly::from(vec![-3, 1]);
+    /// let b = ZZPoly::from(vec![1, 0, 1]);
+    /// assert_eq!(a.resultant(&b), Integer::from(10));
+    ///
+    /// // x^2 - 1 and x^2 + x share the root -1
+    /// let a = ZZPoly::from(vec![-1, 0, 1]);
+    /// let b = ZZPoly::from(vec![0, 1, 1]);
+    /// assert_eq!(a.resultant(&b), Integer::from(0));
+    /// ```
+    pub fn resultant(&self, other: &ZZPoly) -> Integer {
+        if self.is_zero() || other.is_zero() {
+            return Integer::from(0);
+        }
+        let m = (self.length() - 1) as u64;
+        let n = (other.length() - 1) as u64;
+        if m == 0 {
+            return (&self.coeffs[0]).pow(n);
+        } else if n == 0 {
+            return (&other.coeffs[0]).pow(m);
+        }
+
+        // Pull out the (signed) contents: res(ca, db) = c^n d^m res(a, b).
+        let signed_content = |p: &ZZPoly| {
+            let c = p.content();
+            if p.coeffs[p.length() - 1] < 0 { -c } else { c }
+        };
+        let t = signed_content(self).pow(n) * signed_content(other).pow(m);
+
+        let (mut a, mut b) = (self.primitive_part(), other.primitive_part());
+        let mut negate = false;
+        if m < n {
+            std::mem::swap(&mut a, &mut b);
+            negate = m % 2 == 1 && n % 2 == 1;
+        }
+
+        let mut g = Integer::from(1);
+        let mut h = Integer::from(1);
+        loop {
+            let da = (a.length() - 1) as u64;
+            let db = (b.length() - 1) as u64;
+            let delta = da - db;
+            if da % 2 == 1 && db % 2 == 1 {
+                negate = !negate;
+            }
+
+            let r = a.pseudo_rem(&b);
+            if r.is_zero() {
+                return Integer::from(0);
+            }
+            let divisor = &g * (&h).pow(delta);
+            a = b;
+            b = ZZPoly::from_raw(r.coeffs.into_iter().map(|c| c.div_exact(&divisor)).collect());
+
+            // h = g^delta / h^(delta - 1)
+            g = a.coeffs[a.length() - 1].clone();
+            h = if delta == 0 {
+                h
+            } else {
+                (&g).pow(delta).div_exact((&h).pow(delta - 1))
+            };
+
+            if b.length() == 1 {
+                let da = (a.length() - 1) as u64;
+                let h = (&b.coeffs[0]).pow(da).div_exact((&h).pow(da - 1));
+                let res = t * h;
+                return if negate { -res } else { res };
+            }
+        }
+    }
+
+    /// Return the discriminant of the polynomial, normalized so that the
+    /// discriminant of `x^2 + bx + c` is `b^2 - 4c`, i.e.
+    /// `(-1)^(n(n-1)/2) res(p, p') / lc(p)` for `p` of degree `n`. Constant
+    /// polynomials have discriminant zero and linear polynomials have
+    /// discriminant one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Integer;
+    ///
+    /// // x^2 + 3x + 1
+    /// let p = ZZPoly::from(vec![1, 3, 1]);
+    /// assert_eq!(p.discriminant(), Integer::from(5));
+    /// ```
+    pub fn discriminant(&self) -> Integer {
+        if self.length() < 2 {
+            return Integer::from(0);
+        }
+        let n = self.length() - 1;
+        let lead = &self.coeffs[n];
+        let res = self.resultant(&self.derivative()).div_exact(lead);
+        if (n * (n - 1) / 2) % 2 == 1 { -res } else { res }
+    }
+}
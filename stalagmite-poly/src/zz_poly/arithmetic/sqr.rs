@@ -27,15 +27,26 @@
 //! This allows us to compute each coefficient more efficiently than general multiplication.
 
 use malachite::Integer;
+use malachite::base::num::arithmetic::traits::Square;
+use malachite::base::num::logic::traits::SignificantBits;
 use crate::zz_poly::ZZPoly;
-use crate::zz_poly::arithmetic::mul_classical::classical_mul;
 use crate::zz_poly::arithmetic::mul_karatsuba::karatsuba_mul;
+use crate::zz_poly::arithmetic::mul_ks::{pack, packing_bits, unpack};
+use crate::zz_poly::arithmetic::thresholds::{
+    KARATSUBA_SQR_BITS_THRESHOLD,
+    KARATSUBA_SQR_THRESHOLD,
+    KS_SQR_THRESHOLD,
+    TINY_SQR_THRESHOLD,
+};
 
-/// Threshold below which we use the tiny squaring algorithm.
-const TINY_SQR_THRESHOLD: usize = 8;
+/// Length below which Karatsuba squaring recurses to classical squaring when
+/// the coefficients are large.
+const KARATSUBA_SQR_LARGE_COEFF_CUTOFF: usize = 4;
 
-/// Threshold below which we use classical squaring instead of Karatsuba.
-const KARATSUBA_SQR_THRESHOLD: usize = 16;
+/// Get the maximum number of bits of any coefficient.
+pub(crate) fn max_coefficient_bits(poly: &[Integer]) -> u64 {
+    poly.iter().map(|coeff| coeff.significant_bits()).max().unwrap_or(0)
+}
 
 /// Tiny squaring algorithm for small polynomials.
 /// 
@@ -116,13 +127,30 @@ pub fn tiny_sqr(poly: &[Integer], len: usize) -> Vec<Integer> {
 /// ]);
 /// ```
 pub fn classical_sqr(poly: &[Integer], len: usize) -> Vec<Integer> {
-    if len <= TINY_SQR_THRESHOLD {
+    if len <= TINY_SQR_THRESHOLD.get() {
         return tiny_sqr(poly, len);
     }
     
-    // For now, use classical multiplication for simplicity
-    // This can be optimized further with specialized squaring logic
-    classical_mul(poly, len, poly, len)
+    // Coefficient k is 2·Σᵢ<ⱼ aᵢaⱼ + a_{k/2}², so each cross term is
+    // multiplied once and the sum doubled with a shift.
+    let result_len = 2 * len - 1;
+    let mut result = Vec::with_capacity(result_len);
+    
+    for k in 0..result_len {
+        let mut coeff = Integer::from(0);
+        let mut i = k.saturating_sub(len - 1);
+        while 2 * i < k {
+            coeff += &poly[i] * &poly[k - i];
+            i += 1;
+        }
+        coeff <<= 1u64;
+        if k % 2 == 0 {
+            coeff += (&poly[k / 2]).square();
+        }
+        result.push(coeff);
+    }
+    
+    result
 }
 
 /// Karatsuba-based squaring algorithm.
@@ -148,7 +176,12 @@ pub fn classical_sqr(poly: &[Integer], len: usize) -> Vec<Integer> {
 /// assert_eq!(result, expected);
 /// ```
 pub fn karatsuba_sqr(poly: &[Integer], len: usize) -> Vec<Integer> {
-    if len < KARATSUBA_SQR_THRESHOLD {
+    karatsuba_sqr_with_cutoff(poly, len, KARATSUBA_SQR_THRESHOLD.get())
+}
+
+// Karatsuba squaring that recurses down to polynomials of length `cutoff`.
+fn karatsuba_sqr_with_cutoff(poly: &[Integer], len: usize, cutoff: usize) -> Vec<Integer> {
+    if len < cutoff {
         return classical_sqr(poly, len);
     }
     
@@ -162,11 +195,11 @@ pub fn karatsuba_sqr(poly: &[Integer], len: usize) -> Vec<Integer> {
     let b = &poly[0..split];
     
     // Compute b²
-    let b_sqr = karatsuba_sqr(b, b.len());
+    let b_sqr = karatsuba_sqr_with_cutoff(b, b.len(), cutoff);
     
     // Compute a² (if a is non-empty)
     let a_sqr = if !a.is_empty() {
-        karatsuba_sqr(a, a.len())
+        karatsuba_sqr_with_cutoff(a, a.len(), cutoff)
     } else {
         Vec::new()
     };
@@ -210,10 +243,42 @@ pub fn karatsuba_sqr(poly: &[Integer], len: usize) -> Vec<Integer> {
     result
 }
 
+/// Kronecker substitution squaring.
+/// 
+/// Evaluates the polynomial at a sufficiently large power of two, squares the
+/// resulting integer and extracts the coefficients. Only one evaluation is
+/// needed, and squaring an integer is cheaper than a general product.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use stalagmite_poly::zz_poly::arithmetic::sqr::{ks_sqr, classical_sqr};
+/// use malachite::Integer;
+/// 
+/// let poly: Vec<Integer> = (-10..=10).map(Integer::from).collect();
+/// let result = ks_sqr(&poly, poly.len());
+/// assert_eq!(result, classical_sqr(&poly, poly.len()));
+/// ```
+pub fn ks_sqr(poly: &[Integer], len: usize) -> Vec<Integer> {
+    if len < KS_SQR_THRESHOLD.get() {
+        return classical_sqr(poly, len);
+    }
+    
+    let bits = packing_bits(poly, len, poly, len);
+    let value = pack(&poly[..len], bits);
+    
+    let mut result = Vec::with_capacity(2 * len - 1);
+    unpack(value.square(), bits, 2 * len - 1, &mut result);
+    result
+}
+
 /// Automatic squaring algorithm selection.
 /// 
 /// Selects the most appropriate squaring algorithm based on polynomial length
-/// and coefficient characteristics, similar to flint's approach.
+/// and coefficient characteristics, similar to flint's approach. Short
+/// polynomials with large coefficients use Karatsuba squaring, since the
+/// coefficient products dominate the cost, and long polynomials use Kronecker
+/// substitution.
 /// 
 /// # Examples
 /// 
@@ -234,12 +299,18 @@ pub fn auto_sqr(poly: &[Integer], len: usize) -> Vec<Integer> {
         return Vec::new();
     }
     
-    if len <= TINY_SQR_THRESHOLD {
-        tiny_sqr(poly, len)
-    } else if len < KARATSUBA_SQR_THRESHOLD {
-        classical_sqr(poly, len)
+    if len <= TINY_SQR_THRESHOLD.get() {
+        return tiny_sqr(poly, len);
+    }
+    
+    if len < KS_SQR_THRESHOLD.get() {
+        if max_coefficient_bits(&poly[..len]) > KARATSUBA_SQR_BITS_THRESHOLD.get() as u64 {
+            karatsuba_sqr_with_cutoff(poly, len, KARATSUBA_SQR_LARGE_COEFF_CUTOFF)
+        } else {
+            classical_sqr(poly, len)
+        }
     } else {
-        karatsuba_sqr(poly, len)
+        ks_sqr(poly, len)
     }
 }
 
@@ -287,6 +358,7 @@ pub fn sqr(poly: &ZZPoly) -> ZZPoly {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::zz_poly::arithmetic::mul_classical::classical_mul;
     
     #[test]
     fn test_tiny_sqr_basic() {
@@ -323,6 +395,24 @@ mod tests {
         assert_eq!(sqr_result, mul_result);
     }
     
+    #[test]
+    fn test_sqr_large_coefficients() {
+        // Short polynomials with large mixed-sign coefficients take the
+        // Karatsuba path in auto_sqr.
+        for len in [9usize, 12, 17, 30] {
+            let poly: Vec<Integer> = (0..len)
+                .map(|i| {
+                    let c = (Integer::from(i as i64 + 3) << 1200u64) + Integer::from(i as i64);
+                    if i % 3 == 1 { -c } else { c }
+                })
+                .collect();
+            let expected = classical_mul(&poly, len, &poly, len);
+            assert_eq!(auto_sqr(&poly, len), expected);
+            assert_eq!(classical_sqr(&poly, len), expected);
+            assert_eq!(ks_sqr(&poly, len), expected);
+        }
+    }
+    
     #[test]
     fn test_auto_sqr_selection() {
         // Test tiny threshold
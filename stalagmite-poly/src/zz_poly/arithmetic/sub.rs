@@ -18,6 +18,7 @@
 use malachite::Integer;
 use malachite::base::num::arithmetic::traits::NegAssign;
 use crate::zz_poly::ZZPoly;
+use crate::zz_poly::arithmetic::par;
 use std::ops::{
     Sub,
     SubAssign,
@@ -144,12 +145,13 @@ impl Sub<&ZZPoly> for &ZZPoly {
             return -rhs;
         }
 
-        let n = self.length().max(rhs.length());
-        let mut result = Vec::with_capacity(n);
-        for i in 0..n {
-            let a = if i < self.length() { &self.coeffs[i] } else { &Integer::from(0) };
-            let b = if i < rhs.length() { &rhs.coeffs[i] } else { &Integer::from(0) };
-            result.push(a - b);
+        let mut result = self.coeffs.clone();
+        if self.length() >= rhs.length() {
+            par::sub_assign(&mut result, &rhs.coeffs);
+        } else {
+            let n = self.length();
+            par::sub_assign(&mut result, &rhs.coeffs[..n]);
+            result.extend(rhs.coeffs[n..].iter().map(|c| -c));
         }
         ZZPoly::from_raw(result)
     }
@@ -190,13 +192,9 @@ impl SubAssign<ZZPoly> for ZZPoly {
             if self.length() < rhs.length() {
                 rhs.neg_assign();
                 swap(self, &mut rhs);
-                for i in 0..rhs.length() {
-                    self.coeffs[i] += &rhs.coeffs[i];
-                }
+                par::add_assign(&mut self.coeffs, &rhs.coeffs);
             } else {
-                for i in 0..rhs.length() {
-                    self.coeffs[i] -= &rhs.coeffs[i];
-                }
+                par::sub_assign(&mut self.coeffs, &rhs.coeffs);
             }
         }
         self.normalize();
@@ -235,17 +233,12 @@ impl SubAssign<&ZZPoly> for ZZPoly {
         } else {
             if self.length() < rhs.length() {
                 // subtract the common coefficients
-                for i in 0..self.length() {
-                    self.coeffs[i] -= &rhs.coeffs[i];
-                }
+                let n = self.length();
+                par::sub_assign(&mut self.coeffs, &rhs.coeffs[..n]);
                 // push the remaining coefficients from rhs
-                for i in self.length()..rhs.length() {
-                    self.coeffs.push(-rhs.coeffs[i].clone());
-                }
+                self.coeffs.extend(rhs.coeffs[n..].iter().map(|c| -c));
             } else {
-                for i in 0..rhs.length() {
-                    self.coeffs[i] -= &rhs.coeffs[i];
-                }
+                par::sub_assign(&mut self.coeffs, &rhs.coeffs);
             }
         }
         self.normalize();
@@ -0,0 +1,141 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime-tunable algorithm selection thresholds.
+//!
+//! The cutoffs used to choose between multiplication and squaring algorithms
+//! are stored in atomics so that a benchmark harness can sweep them without
+//! recompiling. Each threshold starts at its tuned default, and changing one
+//! only affects performance, never the result. Thresholds that bound a
+//! recursion have a minimum value, and smaller settings are read as that
+//! minimum so the recursion always reaches its base case.
+//!
+//! Thresholds are global to the process, so concurrent callers observe each
+//! other's changes.
+//!
+//! # Examples
+//!
+//! ```
+//! use stalagmite_poly::zz_poly::arithmetic::thresholds::KARATSUBA_THRESHOLD;
+//!
+//! assert_eq!(KARATSUBA_THRESHOLD.get(), KARATSUBA_THRESHOLD.default_value());
+//! KARATSUBA_THRESHOLD.set(32);
+//! assert_eq!(KARATSUBA_THRESHOLD.get(), 32);
+//! KARATSUBA_THRESHOLD.reset();
+//! ```
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A tunable threshold with a compile-time default.
+#[derive(Debug)]
+pub struct Threshold {
+    value: AtomicUsize,
+    default: usize,
+    min: usize,
+}
+
+impl Threshold {
+    const fn new(default: usize) -> Self {
+        Self::with_min(default, 0)
+    }
+
+    const fn with_min(default: usize, min: usize) -> Self {
+        Threshold {
+            value: AtomicUsize::new(default),
+            default,
+            min,
+        }
+    }
+
+    /// The current value of the threshold, raised to its minimum if it was
+    /// set below it.
+    #[inline]
+    pub fn get(&self) -> usize {
+        self.value.load(Ordering::Relaxed).max(self.min)
+    }
+
+    /// Set the threshold to `value`.
+    #[inline]
+    pub fn set(&self, value: usize) {
+        self.value.store(value, Ordering::Relaxed);
+    }
+
+    /// Restore the threshold to its default value.
+    #[inline]
+    pub fn reset(&self) {
+        self.set(self.default);
+    }
+
+    /// The default value of the threshold.
+    #[inline]
+    pub fn default_value(&self) -> usize {
+        self.default
+    }
+
+    /// The smallest value [`get`](Self::get) returns.
+    #[inline]
+    pub fn min_value(&self) -> usize {
+        self.min
+    }
+}
+
+/// Length below which Karatsuba multiplication recurses to classical
+/// multiplication. Values below 2 are read as 2.
+pub static KARATSUBA_THRESHOLD: Threshold = Threshold::with_min(8, 2);
+
+/// Length below which Kronecker substitution falls back to classical
+/// multiplication.
+pub static KS_THRESHOLD: Threshold = Threshold::new(16);
+
+/// Minimum length of both factors for using multi-modular multiplication in
+/// place of Karatsuba when the coefficients are large.
+pub static MULTIMODULAR_THRESHOLD: Threshold = Threshold::new(1024);
+
+/// Length below which truncated multiplication uses the classical algorithm
+/// instead of Karatsuba. Values below 2 are read as 2.
+pub static KARATSUBA_MULLOW_THRESHOLD: Threshold = Threshold::with_min(16, 2);
+
+/// Length up to which squaring uses the tiny squaring algorithm.
+pub static TINY_SQR_THRESHOLD: Threshold = Threshold::new(8);
+
+/// Length below which Karatsuba squaring recurses to classical squaring.
+/// Values below 2 are read as 2.
+pub static KARATSUBA_SQR_THRESHOLD: Threshold = Threshold::with_min(16, 2);
+
+/// Coefficient size (in bits) above which short polynomials are squared with
+/// Karatsuba rather than classical squaring.
+pub static KARATSUBA_SQR_BITS_THRESHOLD: Threshold = Threshold::new(1000);
+
+/// Length below which squaring uses classical or Karatsuba squaring instead
+/// of Kronecker substitution.
+pub static KS_SQR_THRESHOLD: Threshold = Threshold::new(16);
+
+/// Restore every threshold to its default value.
+pub fn reset_all() {
+    for threshold in [
+        &KARATSUBA_THRESHOLD,
+        &KS_THRESHOLD,
+        &MULTIMODULAR_THRESHOLD,
+        &KARATSUBA_MULLOW_THRESHOLD,
+        &TINY_SQR_THRESHOLD,
+        &KARATSUBA_SQR_THRESHOLD,
+        &KARATSUBA_SQR_BITS_THRESHOLD,
+        &KS_SQR_THRESHOLD,
+    ] {
+        threshold.reset();
+    }
+}
@@ -15,7 +15,9 @@
 // You should have received a copy of the GNU General Public License
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
 
+use std::str::FromStr;
 use malachite::Integer;
+use stalagmite_base::StalagmiteError;
 use crate::zz_poly::ZZPoly;
 
 // From vectors
@@ -64,4 +66,110 @@ where
         p.normalize();
         p
     }
-}
\ No newline at end of file
+}
+/// Split a polynomial string in the `Display` format (i.e. `3*x^2 - x + 1`)
+/// into its terms. Each term is returned as its signed coefficient string
+/// together with its exponent. Coefficients are not parsed, so this can be
+/// shared by the integer and rational parsers.
+pub(crate) fn parse_terms(s: &str) -> Result<Vec<(String, usize)>, StalagmiteError> {
+    let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    if s.is_empty() {
+        return Err(StalagmiteError::ParseError("empty string".to_string()));
+    }
+
+    // Split on '+' and '-', keeping the sign with the term that follows it.
+    let mut raw_terms = Vec::new();
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        if (c == '+' || c == '-') && i > 0 {
+            raw_terms.push(&s[start..i]);
+            start = i;
+        }
+    }
+    raw_terms.push(&s[start..]);
+
+    let mut terms = Vec::with_capacity(raw_terms.len());
+    for term in raw_terms {
+        let (neg, body) = if let Some(body) = term.strip_prefix('-') {
+            (true, body)
+        } else if let Some(body) = term.strip_prefix('+') {
+            (false, body)
+        } else {
+            (false, term)
+        };
+
+        if body.is_empty() {
+            return Err(StalagmiteError::ParseError(format!("missing term in '{}'", s)));
+        }
+
+        let (coeff, exp) = if body.contains('x') {
+            let (coeff, monomial) = match body.split_once('*') {
+                Some((coeff, monomial)) => (coeff, monomial),
+                None => ("1", body),
+            };
+            let exp = match monomial.strip_prefix('x') {
+                Some("") => 1,
+                Some(exp) => match exp.strip_prefix('^') {
+                    Some(exp) => exp.parse::<usize>().map_err(|_| {
+                        StalagmiteError::ParseError(format!("invalid exponent in term '{}'", term))
+                    })?,
+                    None => return Err(StalagmiteError::ParseError(
+                        format!("invalid monomial in term '{}'", term))),
+                },
+                None => return Err(StalagmiteError::ParseError(
+                    format!("invalid monomial in term '{}'", term))),
+            };
+            (coeff, exp)
+        } else {
+            (body, 0)
+        };
+
+        if coeff.is_empty() || coeff.starts_with('+') || coeff.starts_with('-') {
+            return Err(StalagmiteError::ParseError(
+                format!("invalid coefficient in term '{}'", term)));
+        }
+
+        if neg {
+            terms.push((format!("-{}", coeff), exp));
+        } else {
+            terms.push((coeff.to_string(), exp));
+        }
+    }
+    Ok(terms)
+}
+
+impl FromStr for ZZPoly {
+    type Err = StalagmiteError;
+
+    /// Parse a polynomial from a string in the format produced by `Display`,
+    /// e.g. `3*x^2 - x + 1`. Repeated exponents are summed.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut coeffs: Vec<Integer> = Vec::new();
+        for (coeff, exp) in parse_terms(s)? {
+            let coeff = Integer::from_str(&coeff).map_err(|_| {
+                StalagmiteError::ParseError(format!("invalid integer coefficient '{}'", coeff))
+            })?;
+            if coeffs.len() <= exp {
+                coeffs.resize(exp + 1, Integer::from(0));
+            }
+            coeffs[exp] += coeff;
+        }
+        Ok(ZZPoly::from_raw(coeffs))
+    }
+}
+
+// Serialize as the list of coefficients. Deserialized data is re-normalized so
+// trailing zeros from external sources are removed.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ZZPoly {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.coeffs.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ZZPoly {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<Integer>::deserialize(deserializer).map(ZZPoly::from_raw)
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::{CoprimeWith, Pow, UnsignedAbs};
+use malachite::rational::Rational;
+use stalagmite_base::factor::Factor;
+use crate::zz_poly::ZZPoly;
+
+// Return the positive divisors of the nonzero integer n.
+fn divisors(n: &Integer) -> Vec<Natural> {
+    let mut n: Natural = n.unsigned_abs();
+    n.factor().divisors()
+}
+
+impl ZZPoly {
+    /// Return the squarefree factorization of the primitive part of the
+    /// polynomial as pairs `(factor, multiplicity)` with increasing
+    /// multiplicity, using Yun's algorithm. Each factor is primitive and
+    /// squarefree with a positive leading coefficient. The content and sign are
+    /// dropped, so constant polynomials give an empty factorization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    ///
+    /// // x^3 - x^2 - x + 1 = (x + 1)(x - 1)^2
+    /// let p = ZZPoly::from(vec![1, -1, -1, 1]);
+    /// assert_eq!(
+    ///     p.squarefree_factorization(),
+    ///     vec![(ZZPoly::from(vec![1, 1]), 1), (ZZPoly::from(vec![-1, 1]), 2)]
+    /// );
+    /// ```
+    pub fn squarefree_factorization(&self) -> Vec<(ZZPoly, usize)> {
+        let mut factors = Vec::new();
+        if self.length() <= 1 {
+            return factors;
+        }
+
+        let f = self.primitive_part();
+        let df = f.derivative();
+        let a = f.gcd(&df);
+        let mut b = f.div_exact(&a);
+        let mut d = df.div_exact(&a) - b.derivative();
+
+        // With b_i the product of the factors of multiplicity at least i,
+        // gcd(b_i, d_i) is the product of the factors of multiplicity i.
+        let mut i = 1;
+        loop {
+            let a = b.gcd(&d);
+            b = b.div_exact(&a);
+            d = d.div_exact(&a) - b.derivative();
+            if a.length() > 1 {
+                factors.push((a, i));
+            }
+            if b.length() <= 1 {
+                break;
+            }
+            i += 1;
+        }
+        factors
+    }
+
+    /// Return true if the polynomial has no repeated factors of positive
+    /// degree, that is if it is coprime to its derivative. The content is
+    /// ignored, so `4x + 4` is squarefree. Nonzero constants are squarefree and
+    /// the zero polynomial is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    ///
+    /// assert!(ZZPoly::from(vec![-1, 0, 1]).is_squarefree());   // x^2 - 1
+    /// assert!(!ZZPoly::from(vec![1, -2, 1]).is_squarefree());  // (x - 1)^2
+    /// ```
+    pub fn is_squarefree(&self) -> bool {
+        if self.is_zero() {
+            return false;
+        }
+        self.gcd(&self.derivative()).degree() == 0
+    }
+
+    // Strip the factor x^k, returning the remaining polynomial and whether
+    // k > 0.
+    fn strip_x_power(&self) -> (ZZPoly, bool) {
+        let k = self.coeffs.iter().take_while(|c| **c == 0).count();
+        (ZZPoly::from_raw(self.coeffs[k..].to_vec()), k > 0)
+    }
+
+    /// Return the distinct integer roots of the polynomial in ascending order.
+    /// The zero polynomial, which vanishes everywhere, returns no roots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Integer;
+    ///
+    /// // (x - 2)(x + 3)(2x - 1)
+    /// let p = ZZPoly::from(vec![6, -13, 1, 2]);
+    /// assert_eq!(p.integer_roots(), vec![Integer::from(-3), Integer::from(2)]);
+    /// ```
+    pub fn integer_roots(&self) -> Vec<Integer> {
+        if self.is_zero() {
+            return Vec::new();
+        }
+
+        // Any integer root divides the constant term once the factor x^k is
+        // removed.
+        let (f, zero_root) = self.strip_x_power();
+        let mut roots = Vec::new();
+        if zero_root {
+            roots.push(Integer::from(0));
+        }
+        for d in divisors(&f.coeffs[0]) {
+            let d = Integer::from(d);
+            if f.evaluate(&d) == 0 {
+                roots.push(d.clone());
+            }
+            let d = -d;
+            if f.evaluate(&d) == 0 {
+                roots.push(d);
+            }
+        }
+        roots.sort();
+        roots
+    }
+
+    /// Return the distinct rational roots of the polynomial in ascending
+    /// order, using the rational root theorem. The zero polynomial, which
+    /// vanishes everywhere, returns no roots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::rational::Rational;
+    ///
+    /// // 2x^2 - 3x + 1 = (x - 1)(2x - 1)
+    /// let p = ZZPoly::from(vec![1, -3, 2]);
+    /// assert_eq!(p.rational_roots(), vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+    /// ```
+    pub fn rational_roots(&self) -> Vec<Rational> {
+        if self.is_zero() {
+            return Vec::new();
+        }
+
+        // A root p/q in lowest terms has p dividing the constant term and q
+        // dividing the leading coefficient once the factor x^k is removed.
+        let (f, zero_root) = self.strip_x_power();
+        let mut roots = Vec::new();
+        if zero_root {
+            roots.push(Rational::from(0));
+        }
+
+        let n = (f.length() - 1) as u64;
+        let numerators = divisors(&f.coeffs[0]);
+        let denominators = divisors(&f.coeffs[f.length() - 1]);
+        for q in denominators.iter() {
+            // q^n f(p/q) = sum a_i p^i q^(n - i)
+            let q_powers: Vec<Integer> = (0..=n).map(|i| Integer::from(q.pow(i))).collect();
+            for p in numerators.iter().filter(|p| (*p).coprime_with(q)) {
+                for p in [Integer::from(p), -Integer::from(p)] {
+                    let mut value = Integer::from(0);
+                    let mut p_power = Integer::from(1);
+                    for (i, coeff) in f.coeffs.iter().enumerate() {
+                        value += coeff * &p_power * &q_powers[n as usize - i];
+                        p_power *= &p;
+                    }
+                    if value == 0 {
+                        roots.push(Rational::from_integers(p, Integer::from(q)));
+                    }
+                }
+            }
+        }
+        roots.sort();
+        roots
+    }
+}
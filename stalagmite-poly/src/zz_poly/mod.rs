@@ -18,9 +18,18 @@
 pub mod arithmetic;
 pub mod comparison;
 pub mod conversion;
+pub mod factor;
 
 use std::fmt;
-use malachite::Integer;
+use std::ops::{Index, IndexMut};
+use std::slice::SliceIndex;
+use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::{DivExact, Gcd, NegAssign};
+use malachite::base::num::basic::traits::Zero;
+use malachite::rational::Rational;
+use stalagmite_base::integer::ZZRing;
+use stalagmite_base::traits::{Element, Parent, PolynomialRing, Ring, RingElement};
+use crate::qq_poly::QQPoly;
 
 pub use arithmetic::*;
 
@@ -35,6 +44,20 @@ pub struct IntegerPolynomial {
 pub type ZZPolyRing = IntegerPolynomialRing;
 pub type ZZPoly = IntegerPolynomial;
 
+/// Summary statistics of a `ZZPoly`, as returned by `ZZPoly::stats`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct PolyStats {
+    /// The number of coefficients.
+    pub length: usize,
+    /// The degree, or `None` for the zero polynomial.
+    pub degree_opt: Option<usize>,
+    /// The number of nonzero coefficients.
+    pub num_nonzero_terms: usize,
+    /// The largest number of significant bits of the absolute value of any
+    /// coefficient, which is zero for the zero polynomial.
+    pub max_coefficient_bits: u64,
+}
+
 impl Default for ZZPoly {
     #[inline]
     fn default() -> Self {
@@ -42,6 +65,56 @@ impl Default for ZZPoly {
     }
 }
 
+impl ZZPolyRing {
+    /// Initialize the ring of integer polynomials.
+    #[inline]
+    pub fn init() -> Self {
+        IntegerPolynomialRing
+    }
+
+    /// Create the element of the ring represented by `input`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_base::traits::Element;
+    /// use stalagmite_poly::zz_poly::{ZZPoly, ZZPolyRing};
+    ///
+    /// let ring = ZZPolyRing::init();
+    /// let p = ring.new(vec![1, 2, 3]);
+    /// assert_eq!(p, ZZPoly::from(vec![1, 2, 3]));
+    /// assert_eq!(p.parent(), ring);
+    /// ```
+    #[inline]
+    pub fn new<T: Into<ZZPoly>>(&self, input: T) -> ZZPoly {
+        input.into()
+    }
+}
+
+impl Parent for ZZPolyRing {
+    type Element = ZZPoly;
+}
+
+impl Element for ZZPoly {
+    type Parent = ZZPolyRing;
+    #[inline]
+    fn parent(&self) -> Self::Parent {
+        IntegerPolynomialRing
+    }
+}
+
+impl Ring for ZZPolyRing {
+    type Element = ZZPoly;
+}
+
+impl RingElement for ZZPoly {
+    type Parent = ZZPolyRing;
+}
+
+impl PolynomialRing<ZZRing> for ZZPolyRing {}
+
+impl stalagmite_base::traits::Polynomial<ZZRing> for ZZPoly {}
+
 // Impl Deref but NOT DerefMut.
 impl std::ops::Deref for ZZPoly {
     type Target = Vec<Integer>;
@@ -51,28 +124,89 @@ impl std::ops::Deref for ZZPoly {
     }
 }
 
+/// Index the coefficients of the polynomial, where index `i` is the
+/// coefficient of `x^i`. Ranges give slices of coefficients.
+///
+/// # Panics
+///
+/// Panics if the index is out of bounds, i.e. `i >= length()`. Use
+/// `ZZPoly::coeff` or `ZZPoly::get_coeff` for checked access.
+impl<I: SliceIndex<[Integer]>> Index<I> for ZZPoly {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.coeffs[index]
+    }
+}
+
+/// Mutably index the coefficients of the polynomial. As with `coeff_mut`, the
+/// caller must call `normalize` afterward if the leading coefficient may have
+/// become zero.
+///
+/// # Panics
+///
+/// Panics if the index is out of bounds, i.e. `i >= length()`.
+impl<I: SliceIndex<[Integer]>> IndexMut<I> for ZZPoly {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.coeffs[index]
+    }
+}
+
 impl fmt::Display for ZZPoly {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_with_var("x"))
+    }
+}
+
+impl ZZPoly {
+    #[inline]
+    pub fn new<T: Into<ZZPoly>>(input: T) -> Self {
+        input.into()
+    }
 
+    /// Return the string representation of the polynomial using `var` as the
+    /// variable name. `Display` uses `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    ///
+    /// let p = ZZPoly::from(vec![-1, 0, 2]);
+    /// assert_eq!(p.to_string_with_var("t"), "2*t^2 - 1");
+    /// assert_eq!(p.to_string(), "2*x^2 - 1");
+    /// ```
+    pub fn to_string_with_var(&self, var: &str) -> String {
         let len = self.length();
         if len == 0 {
-            return write!(f, "0");
+            return "0".to_string();
         } else if len == 1 {
-            return write!(f, "{}", self.coeffs[0]);
+            return self.coeffs[0].to_string();
         }
 
+        // Write x^i, or x if i is 1.
+        let monomial = |i: usize| {
+            if i == 1 {
+                var.to_string()
+            } else {
+                format!("{}^{}", var, i)
+            }
+        };
+
         let mut s = String::new();
-        let mut iter = self.iter().enumerate().filter(|(_, coeff)| **coeff != 0).rev();
-        
+        let mut iter = self.terms().rev();
+
         // Dont print sign of highest order term if it is positive.
-        // We can assume i > 1 and coeff != 0.
+        // We can assume i > 0 and coeff != 0.
         if let Some((i, coeff)) = iter.next() {
             if *coeff == 1 {
-                s.push_str(&format!("x^{}", i));
+                s.push_str(&monomial(i));
             } else if *coeff == -1 {
-                s.push_str(&format!("-x^{}", i));
+                s.push_str(&format!("-{}", monomial(i)));
             } else {
-                s.push_str(&format!("{}*x^{}", coeff, i));
+                s.push_str(&format!("{}*{}", coeff, monomial(i)));
             }
         }
 
@@ -81,28 +215,14 @@ impl fmt::Display for ZZPoly {
             if i == 0 {
                 if *coeff == 1 { s.push_str(" + 1"); }
                 else if *coeff == -1 { s.push_str(" - 1"); }
-                else if *coeff > 0 { s.push_str(&format!(" + {}", coeff)); } 
+                else if *coeff > 0 { s.push_str(&format!(" + {}", coeff)); }
                 else { s.push_str(&format!(" - {}", coeff.unsigned_abs_ref())); }
-            } else if i == 1 {
-                if *coeff == 1 { s.push_str(" + x"); }
-                else if *coeff == -1 { s.push_str(" - x"); }
-                else if *coeff > 0 { s.push_str(&format!(" + {}*x", coeff)); } 
-                else { s.push_str(&format!(" - {}*x", coeff.unsigned_abs_ref())); }
-            } else {
-                if *coeff == 1 { s.push_str(&format!(" + x^{}", i)); }
-                else if *coeff == -1 { s.push_str(&format!(" - x^{}", i)); }
-                else if *coeff > 0 { s.push_str(&format!(" + {}*x^{}", coeff, i));} 
-                else { s.push_str(&format!(" - {}*x^{}", coeff.unsigned_abs_ref(), i)); }
-            }
+            } else if *coeff == 1 { s.push_str(&format!(" + {}", monomial(i))); }
+            else if *coeff == -1 { s.push_str(&format!(" - {}", monomial(i))); }
+            else if *coeff > 0 { s.push_str(&format!(" + {}*{}", coeff, monomial(i))); }
+            else { s.push_str(&format!(" - {}*{}", coeff.unsigned_abs_ref(), monomial(i))); }
         }
-        write!(f, "{}", s)
-    }
-}
-
-impl ZZPoly {
-    #[inline]
-    pub fn new<T: Into<ZZPoly>>(input: T) -> Self {
-        input.into()
+        s
     }
     
     pub fn normalize(&mut self) {
@@ -165,6 +285,45 @@ impl ZZPoly {
         self.length() - 1
     }
 
+    /// Return the degree, or `None` for the zero polynomial. Unlike
+    /// [`degree`](Self::degree) this distinguishes zero from nonzero
+    /// constants.
+    #[inline]
+    pub fn degree_opt(&self) -> Option<usize> {
+        self.length().checked_sub(1)
+    }
+
+    /// Return the leading coefficient, or `None` for the zero polynomial.
+    #[inline]
+    pub fn leading_coefficient(&self) -> Option<&Integer> {
+        self.coeffs.last()
+    }
+
+    /// Return true if the leading coefficient is one. The zero polynomial is
+    /// not monic.
+    #[inline]
+    pub fn is_monic(&self) -> bool {
+        self.leading_coefficient().is_some_and(|c| *c == 1)
+    }
+
+    /// Return the constant term, which is zero for the zero polynomial.
+    #[inline]
+    pub fn constant_term(&self) -> Integer {
+        self.coeff(0)
+    }
+
+    /// Return the coefficient of `x^i`, which is zero if `i` is past the end.
+    pub fn coeff(&self, i: usize) -> Integer {
+        self.get_coeff(i).cloned().unwrap_or(Integer::ZERO)
+    }
+
+    /// Return a reference to the coefficient of `x^i`, or `None` if `i` is
+    /// past the end.
+    #[inline]
+    pub fn get_coeff(&self, i: usize) -> Option<&Integer> {
+        self.coeffs.get(i)
+    }
+
     // unsafe? user needs to be sure to normalize if needed.
     pub fn coeff_mut(&mut self, i: usize) -> &mut Integer {
         &mut self.coeffs[i]
@@ -175,7 +334,193 @@ impl ZZPoly {
         &mut self.coeffs
     }
 
-    pub fn set_coeff(mut self, i: usize, coeff: Integer) {
+    /// Return summary statistics of the polynomial. The length and the
+    /// coefficient sizes are what automatic algorithm selection for
+    /// multiplication is based on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    ///
+    /// let stats = ZZPoly::from(vec![0, 3, 0, 5]).stats();
+    /// assert_eq!(stats.degree_opt, Some(3));
+    /// assert_eq!(stats.num_nonzero_terms, 2);
+    /// assert_eq!(stats.max_coefficient_bits, 3);
+    /// ```
+    pub fn stats(&self) -> PolyStats {
+        PolyStats {
+            length: self.length(),
+            degree_opt: self.degree_opt(),
+            num_nonzero_terms: self.terms().count(),
+            max_coefficient_bits: sqr::max_coefficient_bits(&self.coeffs),
+        }
+    }
+
+    /// Return the coefficients as a slice, where index `i` is the coefficient
+    /// of `x^i`.
+    #[inline]
+    pub fn as_coeffs(&self) -> &[Integer] {
+        &self.coeffs
+    }
+
+    /// Consume the polynomial and return its coefficient vector. The vector
+    /// is normalized, so it is empty for the zero polynomial and otherwise
+    /// ends in a nonzero coefficient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Integer;
+    ///
+    /// let p = ZZPoly::from(vec![1, 0, 2]);
+    /// assert_eq!(p.into_coeffs(), vec![Integer::from(1), Integer::from(0), Integer::from(2)]);
+    /// ```
+    pub fn into_coeffs(mut self) -> Vec<Integer> {
+        self.normalize();
+        self.coeffs
+    }
+
+    /// Set the coefficient of `x^i`, extending the polynomial with zeros if `i`
+    /// is past the end and normalizing if the leading coefficient is zeroed.
+    pub fn set_coeff(&mut self, i: usize, coeff: Integer) {
+        if i >= self.coeffs.len() {
+            if coeff == 0 {
+                return;
+            }
+            self.coeffs.resize(i + 1, Integer::ZERO);
+        }
         self.coeffs[i] = coeff;
+        if i + 1 == self.coeffs.len() {
+            self.normalize();
+        }
+    }
+
+    /// Return an iterator over the nonzero terms of the polynomial as
+    /// `(exponent, coefficient)` pairs in ascending order of exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Integer;
+    ///
+    /// let p = ZZPoly::from(vec![0, 3, 0, 5]);
+    /// let terms: Vec<_> = p.terms().collect();
+    /// assert_eq!(terms, vec![(1, &Integer::from(3)), (3, &Integer::from(5))]);
+    /// ```
+    pub fn terms(&self) -> impl DoubleEndedIterator<Item = (usize, &Integer)> {
+        self.coeffs.iter().enumerate().filter(|(_, coeff)| **coeff != 0)
+    }
+
+    /// Return the nonzero terms as owned `(exponent, coefficient)` pairs in
+    /// ascending order of exponent. This is the sparse counterpart of the
+    /// dense coefficient vector; see [`from_coeff_pairs`](Self::from_coeff_pairs).
+    pub fn to_coeff_pairs(&self) -> Vec<(usize, Integer)> {
+        self.terms().map(|(i, coeff)| (i, coeff.clone())).collect()
+    }
+
+    /// Construct a polynomial from `(exponent, coefficient)` pairs in any
+    /// order. Missing exponents are zero and coefficients of repeated
+    /// exponents are summed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Integer;
+    ///
+    /// let pairs = vec![(3, Integer::from(5)), (0, Integer::from(1)), (3, Integer::from(2))];
+    /// assert_eq!(ZZPoly::from_coeff_pairs(pairs), ZZPoly::from(vec![1, 0, 0, 7]));
+    /// ```
+    pub fn from_coeff_pairs<I>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, Integer)>,
+    {
+        let mut coeffs = Vec::new();
+        for (i, coeff) in pairs {
+            if i >= coeffs.len() {
+                coeffs.resize(i + 1, Integer::ZERO);
+            }
+            coeffs[i] += coeff;
+        }
+        ZZPoly::from_raw(coeffs)
+    }
+
+    /// Return `x^(n-1) * p(1/x)`, i.e. the first `n` coefficients of the
+    /// polynomial (zero-padded if needed) in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    ///
+    /// let p = ZZPoly::from(vec![1, 2, 3]);
+    /// assert_eq!(p.reverse(3), ZZPoly::from(vec![3, 2, 1]));
+    /// assert_eq!(p.reverse(4), ZZPoly::from(vec![0, 3, 2, 1]));
+    /// assert_eq!(p.reverse(2), ZZPoly::from(vec![2, 1]));
+    /// ```
+    pub fn reverse(&self, n: usize) -> ZZPoly {
+        let coeffs = (0..n).rev().map(|i| self.coeff(i)).collect();
+        ZZPoly::from_raw(coeffs)
+    }
+
+    /// Return the content of the polynomial, i.e. the non-negative GCD of its
+    /// coefficients. The content of the zero polynomial is zero.
+    pub fn content(&self) -> Integer {
+        let mut g = Natural::ZERO;
+        for coeff in self.coeffs.iter() {
+            if g == 1 {
+                break;
+            }
+            g = g.gcd(coeff.unsigned_abs_ref());
+        }
+        Integer::from(g)
+    }
+
+    /// Return the primitive part of the polynomial, i.e. the polynomial divided
+    /// by its content, normalized to have a positive leading coefficient. The
+    /// primitive part of the zero polynomial is zero.
+    pub fn primitive_part(&self) -> ZZPoly {
+        if self.is_zero() {
+            return ZZPoly::zero();
+        }
+
+        let mut g = self.content();
+        if self.coeffs[self.length() - 1] < 0 {
+            g.neg_assign();
+        }
+        ZZPoly { coeffs: self.coeffs.iter().map(|c| c.div_exact(&g)).collect() }
+    }
+
+    /// Return the polynomial of minimal degree passing through the given
+    /// `(x, y)` points if it has integer coefficients, or `None` otherwise.
+    /// See `QQPoly::interpolate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_poly::zz_poly::ZZPoly;
+    /// use malachite::Integer;
+    ///
+    /// let points = [(0, 1), (1, 2), (2, 5)].map(|(x, y)| (Integer::from(x), Integer::from(y)));
+    /// assert_eq!(ZZPoly::try_from_points(&points), Some(ZZPoly::from(vec![1, 0, 1])));
+    ///
+    /// // x/2 through (0, 0) and (2, 1)
+    /// let points = [(0, 0), (2, 1)].map(|(x, y)| (Integer::from(x), Integer::from(y)));
+    /// assert_eq!(ZZPoly::try_from_points(&points), None);
+    /// ```
+    pub fn try_from_points(points: &[(Integer, Integer)]) -> Option<ZZPoly> {
+        let points: Vec<(Rational, Rational)> = points
+            .iter()
+            .map(|(x, y)| (Rational::from(x), Rational::from(y)))
+            .collect();
+        let p = QQPoly::interpolate(&points);
+        if *p.denominator() == 1 {
+            Some(p.numerator().clone())
+        } else {
+            None
+        }
     }
 }
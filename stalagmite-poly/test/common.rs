@@ -0,0 +1,56 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+//! Helpers shared by the integration tests.
+
+use stalagmite_poly::zz_poly::ZZPoly;
+use malachite::Integer;
+
+/// Linear congruential generator for reproducible pseudorandom inputs.
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Lcg(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        self.0
+    }
+
+    /// A value in `-bound..=bound`.
+    pub fn next_signed(&mut self, bound: i64) -> i64 {
+        ((self.next_u64() >> 33) as i64 % (2 * bound + 1)) - bound
+    }
+}
+
+/// Polynomial of length `len` with signed coefficients of up to `bits` bits.
+pub fn random_poly(seed: u64, len: usize, bits: u64) -> ZZPoly {
+    let mut rng = Lcg::new(seed);
+    let coeffs: Vec<Integer> = (0..len)
+        .map(|_| {
+            let mut c = Integer::from(0);
+            for _ in 0..bits.div_ceil(64) {
+                c = (c << 64u64) + Integer::from(rng.next_u64());
+            }
+            c >>= bits.div_ceil(64) * 64 - bits;
+            if rng.next_u64() & 1 == 1 { -c } else { c }
+        })
+        .collect();
+    ZZPoly::from(coeffs)
+}
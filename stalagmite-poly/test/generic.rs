@@ -15,9 +15,11 @@
 // You should have received a copy of the GNU General Public License
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
 
-use stalagmite_poly::generic::{GenericPolyRing, GenericPoly, PolyCtx};
+use stalagmite_poly::generic::{GenericPolyRing, GenericPoly, PolyCtx, Polynomial};
+use stalagmite_poly::qq_poly::{QQPoly, QQPolyRing};
+use stalagmite_poly::zz_poly::{ZZPoly, ZZPolyRing};
 use stalagmite_base::integer::{IntegerRing, Integer};
-use stalagmite_base::traits::Element;
+use stalagmite_base::traits::{AdditiveIdentity, Element, MultiplicativeIdentity, Ring, RingElement};
 
 #[cfg(test)]
 mod tests {
@@ -57,4 +59,92 @@ mod tests {
         // Test that parent() returns a valid parent
         assert!(true);
     }
-}
\ No newline at end of file
+
+    fn degree_of<P: Polynomial>(p: &P) -> usize {
+        p.degree()
+    }
+
+    #[test]
+    fn test_polynomial_degree() {
+        let zz = ZZPoly::from(vec![1, 2, 3]);
+        let qq = QQPoly::from((vec![1, 0, 0, 5], malachite::Natural::from(2u32)));
+        let zn = ZZPoly::from(vec![1, 2, 9, 3]).reduce_mod(&malachite::Natural::from(3u32));
+        assert_eq!(degree_of(&zz), 2);
+        assert_eq!(degree_of(&qq), 3);
+        assert_eq!(degree_of(&zn), 1);
+        assert_eq!(degree_of(&ZZPoly::zero()), 0);
+    }
+
+    #[test]
+    fn test_polynomial_coefficients() {
+        use malachite::rational::Rational;
+
+        let qq = QQPoly::from((vec![1, 0, 3], malachite::Natural::from(2u32)));
+        assert_eq!(Polynomial::coeff(&qq, 2), Rational::from_signeds(3, 2));
+        assert_eq!(Polynomial::coeff(&qq, 5), Rational::from(0));
+        assert_eq!(Polynomial::leading_coefficient(&qq), Some(Rational::from_signeds(3, 2)));
+        // (1 + 3x^2)/2 at x = 1/3
+        assert_eq!(Polynomial::evaluate(&qq, &Rational::from_signeds(1, 3)), Rational::from_signeds(2, 3));
+
+        let zn = ZZPoly::from(vec![1, 2, 3]).reduce_mod(&malachite::Natural::from(7u32));
+        let x = zn.ring().new(malachite::Natural::from(2u32));
+        assert_eq!(*Polynomial::evaluate(&zn, &x).value(), 3u32);
+        assert_eq!(*Polynomial::coeff(&zn, 4).value(), 0u32);
+        assert_eq!(Polynomial::leading_coefficient(&ZZPoly::zero()), None);
+    }
+
+    // Check the identities of T through the traits only.
+    fn check_identities<T>(x: T)
+    where
+        T: AdditiveIdentity + MultiplicativeIdentity + Clone + PartialEq + std::fmt::Debug
+            + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+    {
+        let zero = T::zero();
+        let one = T::one();
+        assert!(zero.is_zero());
+        assert!(!zero.is_one());
+        assert!(one.is_one());
+        assert!(!one.is_zero());
+        assert_eq!(x.clone() + T::zero(), x);
+        assert_eq!(x.clone() * T::one(), x);
+        assert!((x * T::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_zero_one_traits() {
+        use malachite::rational::Rational;
+
+        check_identities(ZZPoly::from(vec![3, -1, 4]));
+        check_identities(QQPoly::from((vec![1, 0, 5], malachite::Natural::from(6u32))));
+        check_identities(Integer::from(-12));
+        check_identities(Rational::from_signeds(-7, 3));
+
+        let sum: ZZPoly = Vec::<ZZPoly>::new().into_iter().sum();
+        assert_eq!(sum, <ZZPoly as AdditiveIdentity>::zero());
+    }
+
+    // The parent of a ring element, through the traits only.
+    fn ring_of<E: RingElement>(x: &E) -> <E as Element>::Parent
+    where
+        <E as Element>::Parent: Ring,
+    {
+        x.parent()
+    }
+
+    #[test]
+    fn test_poly_ring_parent() {
+        let zz_ring = ZZPolyRing::init();
+        let p = zz_ring.new(vec![1, -2, 3]);
+        assert_eq!(p, ZZPoly::from(vec![1, -2, 3]));
+        assert_eq!(p.parent(), zz_ring);
+        assert_eq!(ring_of(&ZZPoly::zero()), zz_ring);
+        assert_eq!(zz_ring.new(ZZPoly::one()), ZZPoly::one());
+
+        let qq_ring = QQPolyRing::init();
+        let q = qq_ring.new((vec![2, 4], malachite::Natural::from(6u32)));
+        assert_eq!(q, QQPoly::from((vec![1, 2], malachite::Natural::from(3u32))));
+        assert_eq!(q.parent(), qq_ring);
+        assert_eq!(ring_of(&q), qq_ring);
+        assert_eq!(qq_ring.new(p), QQPoly::from(vec![1, -2, 3]));
+    }
+}
@@ -15,8 +15,11 @@
 // You should have received a copy of the GNU General Public License
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod common;
 pub mod generic;
 pub mod poly;
+pub mod qq_poly;
+pub mod zn_poly;
 pub mod zz_poly;
 
 // Integration tests that test interactions between modules
@@ -0,0 +1,486 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use std::str::FromStr;
+use stalagmite_poly::qq_poly::QQPoly;
+use stalagmite_poly::zz_poly::ZZPoly;
+use stalagmite_base::StalagmiteError;
+use malachite::{Integer, Natural};
+use malachite::rational::Rational;
+use malachite::base::num::arithmetic::traits::Pow;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        // (2 + 4x)/6 -> (1 + 2x)/3
+        let p = QQPoly::from_raw(ZZPoly::from(vec![2, 4]), Natural::from(6u32));
+        assert_eq!(p.numerator(), &ZZPoly::from(vec![1, 2]));
+        assert_eq!(p.denominator(), &Natural::from(3u32));
+
+        // negative coefficients keep their sign
+        let p = QQPoly::from_raw(ZZPoly::from(vec![-4, 0, 8]), Natural::from(12u32));
+        assert_eq!(p.numerator(), &ZZPoly::from(vec![-1, 0, 2]));
+        assert_eq!(p.denominator(), &Natural::from(3u32));
+
+        // already reduced
+        let p = QQPoly::from_raw(ZZPoly::from(vec![3, 5]), Natural::from(7u32));
+        assert_eq!(p.numerator(), &ZZPoly::from(vec![3, 5]));
+        assert_eq!(p.denominator(), &Natural::from(7u32));
+
+        let p = QQPoly::from_raw(ZZPoly::zero(), Natural::from(7u32));
+        assert!(p.is_zero());
+        assert_eq!(p.denominator(), &Natural::from(1u32));
+    }
+
+    #[test]
+    fn test_make_monic() {
+        // 2 + 4x -> 1/2 + x
+        let poly = QQPoly::from(vec![2, 4]);
+        let monic = poly.make_monic();
+        assert_eq!(monic, QQPoly::from((vec![1, 2], Natural::from(2u32))));
+
+        // (3 - 6x)/5 -> -1/2 + x
+        let poly = QQPoly::from((vec![3, -6], Natural::from(5u32)));
+        assert_eq!(poly.make_monic(), QQPoly::from((vec![-1, 2], Natural::from(2u32))));
+
+        // already monic
+        let poly = QQPoly::from(vec![7, 0, 1]);
+        assert_eq!(poly.make_monic(), poly);
+
+        assert_eq!(QQPoly::zero().make_monic(), QQPoly::zero());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(QQPoly::from_str("1/2*x + 3").unwrap(), QQPoly::from((vec![6, 1], 2u32)));
+        assert_eq!(QQPoly::from_str("-2/3*x^2 + 1/2").unwrap(), QQPoly::from((vec![3, 0, -4], 6u32)));
+        assert_eq!(QQPoly::from_str("3*x^2 - x + 1").unwrap(), QQPoly::from(vec![1, -1, 3]));
+        assert_eq!(QQPoly::from_str("(x + 1)/3").unwrap(), QQPoly::from((vec![1, 1], 3u32)));
+        assert_eq!(QQPoly::from_str("x + 1/3").unwrap(), QQPoly::from((vec![1, 3], 3u32)));
+        assert_eq!(QQPoly::from_str("-1/2").unwrap(), QQPoly::from((vec![-1], 2u32)));
+        assert_eq!(QQPoly::from_str("0").unwrap(), QQPoly::zero());
+        assert_eq!(QQPoly::from_str("x").unwrap(), QQPoly::r#gen());
+    }
+
+    #[test]
+    fn test_from_str_round_trip() {
+        let polys = vec![
+            QQPoly::zero(),
+            QQPoly::one(),
+            QQPoly::r#gen(),
+            QQPoly::from((vec![-1], 2u32)),
+            QQPoly::from((vec![1, 2], 3u32)),
+            QQPoly::from((vec![0, -5, 0, 7], 12u32)),
+            QQPoly::from(vec![4, -1, 3]),
+        ];
+        for poly in polys {
+            assert_eq!(poly.to_string().parse::<QQPoly>().unwrap(), poly);
+        }
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        for s in ["", "x/", "x/3", "(x + 1", "1/2/3*x", "a/2*x", "x^"] {
+            assert!(matches!(QQPoly::from_str(s), Err(StalagmiteError::ParseError(_))), "{}", s);
+        }
+        assert_eq!(QQPoly::from_str("(x)/0"), Err(StalagmiteError::DivisionByZero));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let polys = vec![
+            QQPoly::zero(),
+            QQPoly::from(vec![1, -1, 3]),
+            QQPoly::from((vec![0, -5, 0, 7], 12u32)),
+        ];
+        for poly in polys {
+            let json = serde_json::to_string(&poly).unwrap();
+            assert_eq!(serde_json::from_str::<QQPoly>(&json).unwrap(), poly);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_normalizes() {
+        // (2 + 4x + 0x^2)/6 written by hand, not in lowest terms.
+        let numerator = serde_json::to_string(&vec![
+            malachite::Integer::from(2), malachite::Integer::from(4), malachite::Integer::from(0)
+        ]).unwrap();
+        let denominator = serde_json::to_string(&Natural::from(6u32)).unwrap();
+        let json = format!("{{\"numerator\":{},\"denominator\":{}}}", numerator, denominator);
+        let poly: QQPoly = serde_json::from_str(&json).unwrap();
+        assert_eq!(poly, QQPoly::from((vec![1, 2], 3u32)));
+
+        let denominator = serde_json::to_string(&Natural::from(0u32)).unwrap();
+        let json = format!("{{\"numerator\":{},\"denominator\":{}}}", numerator, denominator);
+        assert!(serde_json::from_str::<QQPoly>(&json).is_err());
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let q = |n: i64, d: i64| Rational::from_signeds(n, d);
+
+        let points = [(q(0, 1), q(0, 1)), (q(1, 1), q(1, 1)), (q(2, 1), q(4, 1))];
+        assert_eq!(QQPoly::interpolate(&points), QQPoly::from(vec![0, 0, 1]));
+
+        // x(x - 1)/2
+        let points = [(q(0, 1), q(0, 1)), (q(1, 1), q(0, 1)), (q(2, 1), q(1, 1))];
+        assert_eq!(QQPoly::interpolate(&points), QQPoly::from((vec![0, -1, 1], Natural::from(2u32))));
+
+        // constant and linear cases, with a repeated point
+        assert_eq!(QQPoly::interpolate(&[(q(3, 1), q(-2, 3))]), QQPoly::from((vec![-2], Natural::from(3u32))));
+        let points = [(q(1, 2), q(1, 1)), (q(1, 2), q(1, 1)), (q(3, 2), q(2, 1))];
+        assert_eq!(QQPoly::interpolate(&points), QQPoly::from((vec![1, 2], Natural::from(2u32))));
+
+        assert_eq!(QQPoly::interpolate(&[]), QQPoly::zero());
+    }
+
+    #[test]
+    fn test_interpolate_round_trip() {
+        // 1/3 - 2x + 5/7 x^3 evaluated at several rational points
+        let f = |x: &Rational| Rational::from_signeds(1, 3) - Rational::from(2) * x
+            + Rational::from_signeds(5, 7) * x * x * x;
+        let points: Vec<(Rational, Rational)> = [-2i64, -1, 0, 3, 5]
+            .into_iter()
+            .map(|x| {
+                let x = Rational::from_signeds(x, 2);
+                let y = f(&x);
+                (x, y)
+            })
+            .collect();
+        let expected = QQPoly::from((vec![7, -42, 0, 15], Natural::from(21u32)));
+        assert_eq!(QQPoly::interpolate(&points), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_interpolate_inconsistent() {
+        let points = [(Rational::from(1), Rational::from(1)), (Rational::from(1), Rational::from(2))];
+        QQPoly::interpolate(&points);
+    }
+
+    #[test]
+    fn test_inv_series() {
+        // 1/(1 - x) = 1 + x + x^2 + x^3 + x^4 + O(x^5)
+        let p = QQPoly::from(vec![1, -1]);
+        assert_eq!(p.inv_series(5).unwrap(), QQPoly::from(vec![1, 1, 1, 1, 1]));
+        assert_eq!(p.inv_series(1).unwrap(), QQPoly::one());
+        assert_eq!(p.inv_series(0).unwrap(), QQPoly::zero());
+
+        // (2/3 - x + 5/2 x^2) * inv truncated to n is 1
+        let p = QQPoly::from((vec![4, -6, 15], Natural::from(6u32)));
+        for n in [1, 2, 3, 7, 10] {
+            let inv = p.inv_series(n).unwrap();
+            assert!(inv.length() <= n);
+            let prod = &p * &inv;
+            let (c0, d) = prod.coeff(0);
+            assert_eq!(c0, Integer::from(d));
+            assert!((1..n).all(|i| prod.coeff(i).0 == 0));
+        }
+    }
+
+    #[test]
+    fn test_inv_series_zero_constant() {
+        assert!(QQPoly::from(vec![0, 1, 2]).inv_series(5).is_none());
+        assert!(QQPoly::zero().inv_series(5).is_none());
+    }
+
+    #[test]
+    fn test_exp_log_series() {
+        // exp(log(1 + x)) = 1 + x up to degree 5
+        let p = QQPoly::from(vec![1, 1]);
+        let log = p.log_series(6).unwrap();
+        assert_eq!(log.exp_series(6).unwrap(), p);
+
+        // log(exp(f)) = f mod x^n when f(0) = 0
+        let f = QQPoly::from((vec![0, 3, -12, 0, 5], Natural::from(6u32)));
+        for n in [1, 2, 3, 5, 9] {
+            let exp = f.exp_series(n).unwrap();
+            assert!(exp.length() <= n);
+            let expected: Vec<Rational> = (0..n).map(|i| {
+                let (c, d) = f.coeff(i);
+                Rational::from_integers(c, Integer::from(d))
+            }).collect();
+            assert_eq!(exp.log_series(n).unwrap(), QQPoly::from(expected));
+        }
+
+        assert_eq!(QQPoly::zero().exp_series(4).unwrap(), QQPoly::one());
+        assert_eq!(QQPoly::one().log_series(4).unwrap(), QQPoly::zero());
+        assert_eq!(p.log_series(0).unwrap(), QQPoly::zero());
+        assert_eq!(f.exp_series(0).unwrap(), QQPoly::zero());
+    }
+
+    #[test]
+    fn test_exp_log_series_invalid_constant() {
+        assert!(QQPoly::from(vec![1, 1]).exp_series(5).is_none());
+        assert!(QQPoly::from(vec![2, 1]).log_series(5).is_none());
+        assert!(QQPoly::from((vec![3, 1], Natural::from(2u32))).log_series(5).is_none());
+        assert!(QQPoly::zero().log_series(5).is_none());
+    }
+
+    #[test]
+    fn test_pow() {
+        // ((1 + x)/2)^2 = (1 + 2x + x^2)/4
+        let p = QQPoly::from((vec![1, 1], Natural::from(2u32)));
+        let expected = QQPoly::from((vec![1, 2, 1], Natural::from(4u32)));
+        assert_eq!(p.pow(2), expected);
+        assert_eq!(*p.pow(2).denominator(), Natural::from(4u32));
+
+        let q = QQPoly::from((vec![3, -4, 0, 6], Natural::from(10u32)));
+        let mut expected = QQPoly::one();
+        for exp in 0..12u64 {
+            assert_eq!(q.pow(exp), expected, "exponent {}", exp);
+            expected *= &q;
+        }
+
+        assert_eq!(p.pow(0), QQPoly::one());
+        assert_eq!(QQPoly::zero().pow(0), QQPoly::one());
+        assert!(QQPoly::zero().pow(3).is_zero());
+    }
+
+    #[test]
+    fn test_equals() {
+        let p = QQPoly::from((vec![2, 4, -6], Natural::from(6u32)));
+        let q = QQPoly::from((vec![10, 20, -30], Natural::from(30u32)));
+        let r = QQPoly::from((vec![1, 2, -3], Natural::from(3u32)));
+        assert!(p.equals(&q));
+        assert!(p.equals(&r));
+        assert_eq!(p, q);
+        assert_eq!(p, r);
+        assert_eq!(*p.denominator(), Natural::from(3u32));
+
+        assert!(!p.equals(&QQPoly::from(vec![1, 2, -3])));
+        assert!(!p.equals(&QQPoly::from((vec![1, 2, 3], Natural::from(3u32)))));
+        assert!(!p.equals(&QQPoly::from((vec![1, 2], Natural::from(5u32)))));
+
+        let zero = QQPoly::from((vec![0, 0], Natural::from(7u32)));
+        assert!(zero.equals(&QQPoly::zero()));
+        assert!(QQPoly::default().equals(&QQPoly::zero()));
+        assert_eq!(zero, QQPoly::default());
+    }
+
+    #[test]
+    fn test_to_string_with_var() {
+        let p = QQPoly::from((vec![1, 0, 3], Natural::from(2u32)));
+        assert_eq!(p.to_string_with_var("t"), "(3*t^2 + 1)/2");
+        assert_eq!(p.to_string_with_var("x"), p.to_string());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(QQPoly::from(vec![1, 2]).to_string(), "2*x + 1");
+        assert_eq!(QQPoly::from((vec![1, 2], Natural::from(3u32))).to_string(), "(2*x + 1)/3");
+        assert_eq!(QQPoly::from((vec![-1], Natural::from(3u32))).to_string(), "(-1)/3");
+        // the denominator cancels
+        assert_eq!(QQPoly::from((vec![3, 6], Natural::from(3u32))).to_string(), "2*x + 1");
+        assert_eq!(QQPoly::zero().to_string(), "0");
+        assert_eq!(QQPoly::one().to_string(), "1");
+    }
+
+    #[test]
+    fn test_terms() {
+        // (3x - 6x^2)/9 = x/3 - 2/3 x^2
+        let p = QQPoly::from((vec![0, 3, -6], Natural::from(9u32)));
+        let terms: Vec<_> = p.terms().collect();
+        assert_eq!(terms, vec![(1, Rational::from_signeds(1, 3)), (2, Rational::from_signeds(-2, 3))]);
+        assert_eq!(QQPoly::zero().terms().count(), 0);
+    }
+
+    #[test]
+    fn test_constant_term() {
+        assert_eq!(QQPoly::from(vec![5, 7]).constant_term(), Rational::from(5));
+        // (2 + x)/4
+        assert_eq!(QQPoly::from((vec![2, 1], Natural::from(4u32))).constant_term(), Rational::from_signeds(1, 2));
+        assert_eq!(QQPoly::from(vec![0, 7]).constant_term(), Rational::from(0));
+        assert_eq!(QQPoly::zero().constant_term(), Rational::from(0));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let q = |n: i64, d: i64| Rational::from_signeds(n, d);
+
+        // (1 + 2x)/3
+        let p = QQPoly::from((vec![1, 2], Natural::from(3u32)));
+        assert_eq!(p.evaluate(&q(1, 1)), q(1, 1));
+        assert_eq!(p.evaluate(&q(0, 1)), q(1, 3));
+        assert_eq!(p.evaluate(&q(-1, 2)), q(0, 1));
+        assert_eq!(p.evaluate(&q(5, 4)), q(7, 6));
+
+        // agrees with evaluating term by term
+        let p = QQPoly::from((vec![-4, 0, 7, 3], Natural::from(10u32)));
+        for x in [q(-3, 7), q(2, 9), q(11, 1), q(-5, 2)] {
+            let expected = p.terms().fold(q(0, 1), |acc, (i, c)| acc + c * (&x).pow(i as u64));
+            assert_eq!(p.evaluate(&x), expected);
+        }
+
+        assert_eq!(QQPoly::zero().evaluate(&q(3, 2)), q(0, 1));
+        assert_eq!(QQPoly::from((vec![5], Natural::from(2u32))).evaluate(&q(-7, 3)), q(5, 2));
+    }
+
+    #[test]
+    fn test_div() {
+        // (x^2 - 1)/(x - 1) = x + 1
+        let a = QQPoly::from(vec![-1, 0, 1]);
+        let b = QQPoly::from(vec![-1, 1]);
+        let q = &a / &b;
+        assert_eq!(q, QQPoly::from(vec![1, 1]));
+        assert_eq!(*q.denominator(), 1u32);
+
+        // rational coefficients: (x/2 + 1/3)(3x - 1/5) / (3x - 1/5)
+        let f = QQPoly::from((vec![2, 3], Natural::from(6u32)));
+        let g = QQPoly::from((vec![-1, 15], Natural::from(5u32)));
+        let mut p = &f * &g;
+        assert_eq!(p.clone() / g.clone(), f);
+        p /= &f;
+        assert_eq!(p, g);
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let a = QQPoly::from((vec![3, -1, 0, 7, 2], Natural::from(5u32)));
+        let b = QQPoly::from((vec![-4, 0, 3], Natural::from(7u32)));
+        let (q, r) = a.div_rem(&b);
+        assert!(r.length() < b.length());
+        assert_eq!(&q * &b + &r, a);
+
+        // negative leading coefficient
+        let b = QQPoly::from(vec![1, 1, -2]);
+        let (q, r) = a.div_rem(&b);
+        assert!(r.length() < b.length());
+        assert_eq!(&q * &b + &r, a);
+
+        // lower degree dividend
+        let (q, r) = b.div_rem(&a);
+        assert!(q.is_zero());
+        assert_eq!(r, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_div_by_zero() {
+        QQPoly::from(vec![1, 1]).div_rem(&QQPoly::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_inexact() {
+        let _ = QQPoly::from(vec![1, 0, 1]) / QQPoly::from(vec![1, 1]);
+    }
+
+    #[test]
+    fn test_mul_rational_sign() {
+        // (1 - 2x)/3 * -3/4 = (-1 + 2x)/4
+        let p = QQPoly::from((vec![1, -2], Natural::from(3u32)));
+        let c = Rational::from_signeds(-3, 4);
+        let expected = QQPoly::from((vec![-1, 2], Natural::from(4u32)));
+        assert_eq!(p.clone() * &c, expected);
+        assert_eq!(p * c, expected);
+    }
+
+    #[test]
+    fn test_to_primitive_zz() {
+        let p = QQPoly::from((vec![1, 2], Natural::from(2u32)));
+        assert_eq!(p.to_primitive_zz(), (ZZPoly::from(vec![1, 2]), Rational::from_signeds(1, 2)));
+
+        // -4/3 + 2/3 x^2 = 2/3 * (-2 + x^2)
+        let p = QQPoly::from((vec![-4, 0, 2], Natural::from(3u32)));
+        let (zz, c) = p.to_primitive_zz();
+        assert_eq!(zz, ZZPoly::from(vec![-2, 0, 1]));
+        assert_eq!(c, Rational::from_signeds(2, 3));
+
+        let p = QQPoly::from((vec![6, 0, -9], Natural::from(5u32)));
+        let (zz, c) = p.to_primitive_zz();
+        assert_eq!(zz, ZZPoly::from(vec![-2, 0, 3]));
+        assert_eq!(c, Rational::from_signeds(-3, 5));
+        assert_eq!(QQPoly::from_raw(zz, Natural::from(1u32)) * c, p);
+
+        assert_eq!(QQPoly::zero().to_primitive_zz(), (ZZPoly::zero(), Rational::from(0)));
+    }
+
+    #[test]
+    fn test_from_rationals() {
+        let q = |n: i64, d: i64| Rational::from_signeds(n, d);
+
+        let p = QQPoly::from(vec![q(1, 2), q(1, 3)]);
+        assert_eq!(p.numerator(), &ZZPoly::from(vec![3, 2]));
+        assert_eq!(*p.denominator(), 6u32);
+
+        // the LCM, not the product, of the denominators is used
+        let p = QQPoly::from(vec![q(1, 4), q(-1, 6), q(5, 1)]);
+        assert_eq!(p.numerator(), &ZZPoly::from(vec![3, -2, 60]));
+        assert_eq!(*p.denominator(), 12u32);
+
+        // the result is normalized
+        let p = QQPoly::from(vec![q(2, 4), q(3, 6), q(0, 1)]);
+        assert_eq!(p.numerator(), &ZZPoly::from(vec![1, 1]));
+        assert_eq!(*p.denominator(), 2u32);
+        assert_eq!(QQPoly::from(vec![q(4, 2), q(-6, 3)]), QQPoly::from(vec![2, -2]));
+
+        assert!(QQPoly::from(Vec::<Rational>::new()).is_zero());
+        assert!(QQPoly::from(vec![q(0, 1), q(0, 5)]).is_zero());
+    }
+
+    #[test]
+    fn test_zz_poly_add_sub() {
+        // (1 + x)/2 and 3 - x
+        let q = QQPoly::from((vec![1, 1], Natural::from(2u32)));
+        let z = ZZPoly::from(vec![3, -1]);
+        let sum = QQPoly::from((vec![7, -1], Natural::from(2u32)));
+        assert_eq!(&q + &z, sum);
+        assert_eq!(&z + &q, sum);
+        assert_eq!(q.clone() + z.clone(), sum);
+        assert_eq!(z.clone() + q.clone(), sum);
+
+        assert_eq!(&q - &z, QQPoly::from((vec![-5, 3], Natural::from(2u32))));
+        assert_eq!(&z - &q, QQPoly::from((vec![5, -3], Natural::from(2u32))));
+
+        // the result is normalized: (1 - x)/2 + x = (1 + x)/2
+        let q = QQPoly::from((vec![1, -1], Natural::from(2u32)));
+        let r = &q + &ZZPoly::from(vec![0, 1]);
+        assert_eq!(r.numerator(), &ZZPoly::from(vec![1, 1]));
+        assert_eq!(*r.denominator(), 2u32);
+        assert!((&q - &q + ZZPoly::zero()).is_zero());
+        assert_eq!(*(&q - &q + ZZPoly::zero()).denominator(), 1u32);
+
+        let mut a = QQPoly::from((vec![1, 1], Natural::from(2u32)));
+        a += &z;
+        a -= z;
+        assert_eq!(a, QQPoly::from((vec![1, 1], Natural::from(2u32))));
+    }
+
+    #[test]
+    fn test_zz_poly_mul() {
+        // (1 + x)/2 * (2 - 2x) = 1 - x^2
+        let q = QQPoly::from((vec![1, 1], Natural::from(2u32)));
+        let z = ZZPoly::from(vec![2, -2]);
+        let product = QQPoly::from(vec![1, 0, -1]);
+        assert_eq!(&q * &z, product);
+        assert_eq!(&z * &q, product);
+        assert_eq!(q.clone() * z.clone(), product);
+        assert_eq!(z.clone() * q.clone(), product);
+        assert_eq!(*(&q * &z).denominator(), 1u32);
+
+        let mut a = q.clone();
+        a *= &ZZPoly::from(vec![3]);
+        assert_eq!(a, QQPoly::from((vec![3, 3], Natural::from(2u32))));
+        assert!((&q * &ZZPoly::zero()).is_zero());
+    }
+}
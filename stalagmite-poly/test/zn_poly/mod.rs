@@ -0,0 +1,205 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::Natural;
+use stalagmite_poly::zn_poly::ZnPoly;
+use stalagmite_poly::zz_poly::ZZPoly;
+use stalagmite_zn::ZnRing;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zn(coeffs: Vec<i32>, n: u32) -> ZnPoly {
+        ZZPoly::from(coeffs).reduce_mod(&Natural::from(n))
+    }
+
+    #[test]
+    fn test_reduce_mod() {
+        let p = zn(vec![5, -1, 6], 3);
+        assert_eq!(p.length(), 2);
+        assert_eq!(*p[0].value(), 2u32);
+        assert_eq!(*p[1].value(), 2u32);
+        assert_eq!(p.modulus(), &Natural::from(3u32));
+
+        // reduces to zero
+        assert!(zn(vec![3, 6, -9], 3).is_zero());
+    }
+
+    #[test]
+    fn test_new() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let p = ZnPoly::new(vec![ring.new(Natural::from(3u32)), ring.new(Natural::from(0u32))], &ring);
+        assert_eq!(p, zn(vec![3], 7));
+        assert_eq!(p.degree(), 0);
+        assert!(ZnPoly::zero(&ring).is_zero());
+        assert_eq!(ZnPoly::one(&ring), zn(vec![1], 7));
+        assert_eq!(ZnPoly::r#gen(&ring), zn(vec![0, 1], 7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_wrong_ring() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let other = ZnRing::init(Natural::from(5u32));
+        ZnPoly::new(vec![other.new(Natural::from(3u32))], &ring);
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let a = zn(vec![1, 2, 3], 5);
+        let b = zn(vec![4, 4, 2], 5);
+        assert_eq!(&a + &b, zn(vec![0, 1], 5));
+        assert_eq!(a.clone() + b.clone(), zn(vec![0, 1], 5));
+        assert_eq!(&a - &a, ZnPoly::zero(a.ring()));
+        assert_eq!(&a - &b, zn(vec![-3, -2, 1], 5));
+        assert_eq!(&zn(vec![1], 5) - &b, zn(vec![-3, -4, -2], 5));
+
+        let mut c = a.clone();
+        c += &b;
+        c -= b;
+        assert_eq!(c, a);
+        assert_eq!(-&a + &a, zn(vec![], 5));
+    }
+
+    #[test]
+    fn test_mul() {
+        // (x + 1)^2 = x^2 + 1 mod 2
+        let p = zn(vec![1, 1], 2);
+        assert_eq!(&p * &p, zn(vec![1, 0, 1], 2));
+
+        // matches multiplication over ZZ followed by reduction
+        let a = ZZPoly::from(vec![3, -7, 11, 2, 9, 1, 0, 4]);
+        let b = ZZPoly::from(vec![-5, 8, 1, 13]);
+        let n = Natural::from(1000003u32);
+        assert_eq!(a.reduce_mod(&n) * b.reduce_mod(&n), (&a * &b).reduce_mod(&n));
+
+        // leading coefficients can vanish modulo composite n
+        let p = zn(vec![1, 2], 4);
+        assert_eq!(&p * &p, zn(vec![1, 4], 4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mismatched_rings() {
+        let _ = zn(vec![1, 1], 2) + zn(vec![1, 1], 3);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(zn(vec![1, -1, 3], 5).to_string(), "3*x^2 + 4*x + 1");
+        assert_eq!(zn(vec![5], 5).to_string(), "0");
+    }
+
+    #[test]
+    fn test_rem() {
+        // x^3 mod (2x^2 + 1) = 3x over Z/7
+        let a = zn(vec![0, 0, 0, 1], 7);
+        let b = zn(vec![1, 0, 2], 7);
+        assert_eq!(&a % &b, zn(vec![0, 3], 7));
+
+        // lower degree dividend is unchanged
+        assert_eq!(&b % &a, b);
+        // constant divisor
+        assert!((&a % &zn(vec![3], 7)).is_zero());
+
+        // (q*b + r) mod b = r when deg r < deg b
+        let q = zn(vec![1, 2, 3], 7);
+        let r = zn(vec![4, 5], 7);
+        let b = zn(vec![2, 0, 1, 5], 7);
+        assert_eq!((&(&q * &b) + &r) % &b, r);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rem_non_invertible_lead() {
+        let _ = zn(vec![0, 0, 1], 4) % zn(vec![1, 2], 4);
+    }
+
+    #[test]
+    fn test_pow_mod_frobenius() {
+        // (x + a)^p = x^p + a mod (p, f) for any f
+        for p in [2u32, 3, 5, 7, 11, 13] {
+            let f = zn(vec![3, 1, 0, 2, 1], p);
+            for a in 0..p as i32 {
+                let lhs = zn(vec![a, 1], p).pow_mod(&Natural::from(p), &f);
+                let rhs = &(&zn(vec![0, 1], p).pow_mod(&Natural::from(p), &f) + &zn(vec![a], p)) % &f;
+                assert_eq!(lhs, rhs, "p = {}, a = {}", p, a);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_mod_irreducible() {
+        // x^(p^d) = x mod (p, f) for f irreducible of degree d
+        let cases = [(2u32, vec![1, 1, 1]), (3, vec![1, 0, 1]), (5, vec![2, 0, 1]), (7, vec![1, 1, 0, 1])];
+        for (p, f) in cases {
+            let d = f.len() as u32 - 1;
+            let f = zn(f, p);
+            let x = zn(vec![0, 1], p);
+            assert_eq!(x.pow_mod(&Natural::from(p.pow(d)), &f), x, "p = {}", p);
+        }
+    }
+
+    #[test]
+    fn test_pow_mod_small_exponents() {
+        let f = zn(vec![1, 0, 0, 1], 11);
+        let a = zn(vec![2, 3, 4], 11);
+        assert_eq!(a.pow_mod(&Natural::from(0u32), &f), zn(vec![1], 11));
+        assert_eq!(a.pow_mod(&Natural::from(1u32), &f), &a % &f);
+        assert_eq!(a.pow_mod(&Natural::from(3u32), &f), &(&(&a * &a) * &a) % &f);
+    }
+
+    #[test]
+    fn test_lift() {
+        assert_eq!(zn(vec![2, 1], 3).lift(), ZZPoly::from(vec![2, 1]));
+        assert_eq!(zn(vec![2, 1], 3).lift_symmetric(), ZZPoly::from(vec![-1, 1]));
+        assert_eq!(zn(vec![-3, 5, -2, 4], 8).lift(), ZZPoly::from(vec![5, 5, 6, 4]));
+        assert_eq!(zn(vec![-3, 5, -2, 4], 8).lift_symmetric(), ZZPoly::from(vec![-3, -3, -2, 4]));
+        assert!(zn(vec![], 5).lift_symmetric().is_zero());
+
+        // lifting is inverse to reduction for small coefficients
+        let p = ZZPoly::from(vec![3, -1, 0, -3, 2]);
+        assert_eq!(p.reduce_mod(&Natural::from(7u32)).lift_symmetric(), p);
+    }
+
+    #[test]
+    fn test_crt_reconstruct() {
+        // the image mod 7 is shorter
+        let p = ZZPoly::from(vec![3, -10, 0, 14]);
+        let images = [zn(vec![3, -10, 0, 14], 5), zn(vec![3, -10, 0, 14], 7)];
+        assert_eq!(images[0].length(), 4);
+        assert_eq!(images[1].length(), 2);
+        assert_eq!(ZZPoly::crt_reconstruct(&images), p);
+
+        // coefficients too large for a single modulus
+        let p = ZZPoly::from(vec![-1000, 999, 0, -1, 12345]);
+        let moduli = [11u32, 13, 17, 19, 23];
+        let images: Vec<_> = moduli.iter().map(|&m| p.reduce_mod(&Natural::from(m))).collect();
+        assert_eq!(ZZPoly::crt_reconstruct(&images), p);
+        assert_eq!(ZZPoly::crt_reconstruct(&images[..1]), images[0].lift_symmetric());
+
+        assert!(ZZPoly::crt_reconstruct(&[]).is_zero());
+        assert!(ZZPoly::crt_reconstruct(&[zn(vec![5], 5), zn(vec![7], 7)]).is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid modulus")]
+    fn test_crt_reconstruct_not_coprime() {
+        ZZPoly::crt_reconstruct(&[zn(vec![1, 2], 6), zn(vec![1, 2], 9)]);
+    }
+}
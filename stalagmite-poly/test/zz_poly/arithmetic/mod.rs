@@ -15,4 +15,6 @@
 // You should have received a copy of the GNU General Public License
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
 
-pub mod add;
\ No newline at end of file
+pub mod add;
+pub mod mul;
+pub mod primitive;
@@ -0,0 +1,181 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use stalagmite_poly::zz_poly::ZZPoly;
+use stalagmite_poly::zz_poly::arithmetic::mul::MulAlgorithm;
+use stalagmite_poly::zz_poly::arithmetic::mul_classical::mul_classical;
+use stalagmite_poly::zz_poly::arithmetic::mul_karatsuba::mul_karatsuba;
+use stalagmite_poly::zz_poly::arithmetic::mul_ks::mul_ks;
+use stalagmite_poly::zz_poly::arithmetic::mul_multimodular::mul_multimodular;
+use stalagmite_poly::zz_poly::arithmetic::mul_ss::mul_ss;
+use stalagmite_poly::zz_poly::arithmetic::mullow::mullow;
+use stalagmite_poly::zz_poly::arithmetic::thresholds;
+use malachite::Integer;
+use malachite::base::num::arithmetic::traits::Pow;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::random_poly;
+
+    type MulFn = fn(&ZZPoly, &ZZPoly) -> ZZPoly;
+
+    const WRAPPERS: [(&str, MulFn); 5] = [
+        ("classical", mul_classical),
+        ("karatsuba", mul_karatsuba),
+        ("ks", mul_ks),
+        ("ss", mul_ss),
+        ("multimodular", mul_multimodular),
+    ];
+
+    #[test]
+    fn test_mul_wrappers_agree() {
+        let mut seed = 1;
+        for &(len1, len2) in &[(1, 1), (2, 3), (7, 7), (16, 5), (33, 40), (100, 64)] {
+            for &bits in &[1, 20, 64, 150] {
+                let p = random_poly(seed, len1, bits);
+                let q = random_poly(seed + 1, len2, bits);
+                seed += 2;
+                let expected = &p * &q;
+                for (name, mul) in WRAPPERS {
+                    assert_eq!(mul(&p, &q), expected, "{} with lengths {} and {}", name, len1, len2);
+                    assert_eq!(mul(&q, &p), expected, "{} with lengths {} and {}", name, len2, len1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_wrappers_zero() {
+        let p = random_poly(7, 10, 32);
+        for (name, mul) in WRAPPERS {
+            assert!(mul(&p, &ZZPoly::zero()).is_zero(), "{}", name);
+            assert!(mul(&ZZPoly::zero(), &p).is_zero(), "{}", name);
+            assert!(mul(&ZZPoly::zero(), &ZZPoly::zero()).is_zero(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_mul_multimodular_large() {
+        // long enough with large enough coefficients that several primes
+        // are needed for the reconstruction
+        let p = random_poly(1, 1024, 900);
+        let q = random_poly(2, 1030, 900);
+        assert_eq!(mul_multimodular(&p, &q), mul_ks(&p, &q));
+        for (len1, len2, bits) in [(20, 17, 300), (40, 40, 1000)] {
+            let p = random_poly(len1 as u64, len1, bits);
+            let q = random_poly(len2 as u64 + 100, len2, bits);
+            assert_eq!(mul_multimodular(&p, &q), mul_classical(&p, &q));
+        }
+    }
+
+    #[test]
+    fn test_mul_with() {
+        let algos = [
+            MulAlgorithm::Classical,
+            MulAlgorithm::Karatsuba,
+            MulAlgorithm::KS,
+            MulAlgorithm::SS,
+            MulAlgorithm::Multimodular,
+            MulAlgorithm::Auto,
+        ];
+        let p = random_poly(11, 37, 100);
+        let q = random_poly(12, 20, 70);
+        let expected = mul_classical(&p, &q);
+        for algo in algos {
+            assert_eq!(p.mul_with(&q, algo), expected, "{:?}", algo);
+            assert!(p.mul_with(&ZZPoly::zero(), algo).is_zero(), "{:?}", algo);
+        }
+        assert_eq!(MulAlgorithm::default(), MulAlgorithm::Auto);
+    }
+
+    #[test]
+    fn test_scale_assign() {
+        let p = random_poly(21, 30, 90);
+        for c in [-7, -1, 0, 1, 12345] {
+            let c = Integer::from(c);
+            let mut q = p.clone();
+            q.scale_assign(&c);
+            assert_eq!(q, &p * &c);
+        }
+        let mut z = ZZPoly::zero();
+        z.scale_assign(&Integer::from(3));
+        assert!(z.is_zero());
+    }
+
+    #[test]
+    fn test_scale_pow2_assign() {
+        let p = random_poly(22, 30, 90);
+        for shift in [0u64, 1, 5, 63, 64, 200] {
+            let mut q = p.clone();
+            q.scale_pow2_assign(shift);
+            assert_eq!(q, &p * Integer::from(2).pow(shift));
+        }
+        let mut z = ZZPoly::zero();
+        z.scale_pow2_assign(10);
+        assert!(z.is_zero());
+    }
+
+    // Thresholds are global, so every test that changes them lives here to
+    // avoid racing with itself.
+    #[test]
+    fn test_thresholds() {
+        let p = random_poly(31, 64, 2000);
+        let q = random_poly(32, 64, 2000);
+        let expected = mul_classical(&p, &q);
+        assert_eq!(p.mul_algorithm(&q), MulAlgorithm::Karatsuba);
+
+        thresholds::MULTIMODULAR_THRESHOLD.set(32);
+        assert_eq!(p.mul_algorithm(&q), MulAlgorithm::Multimodular);
+        assert_eq!(p.mul_with(&q, MulAlgorithm::Auto), expected);
+
+        thresholds::MULTIMODULAR_THRESHOLD.reset();
+        assert_eq!(p.mul_algorithm(&q), MulAlgorithm::Karatsuba);
+
+        // Base case cutoffs change the recursion but not the product
+        thresholds::KARATSUBA_THRESHOLD.set(2);
+        thresholds::KS_THRESHOLD.set(2);
+        thresholds::KS_SQR_THRESHOLD.set(2);
+        thresholds::TINY_SQR_THRESHOLD.set(1);
+        thresholds::KARATSUBA_SQR_THRESHOLD.set(2);
+        let r = random_poly(33, 20, 40);
+        assert_eq!(p.mul_with(&q, MulAlgorithm::Karatsuba), expected);
+        assert_eq!(r.mul_with(&r, MulAlgorithm::KS), mul_classical(&r, &r));
+        assert_eq!(&r * &r, mul_classical(&r, &r));
+        assert_eq!(&p * &p, mul_classical(&p, &p));
+
+        // Cutoffs that would stop the recursion from terminating are read as
+        // the smallest cutoff that does
+        for cutoff in [0, 1] {
+            thresholds::KARATSUBA_THRESHOLD.set(cutoff);
+            thresholds::KARATSUBA_SQR_THRESHOLD.set(cutoff);
+            thresholds::KARATSUBA_MULLOW_THRESHOLD.set(cutoff);
+            assert_eq!(thresholds::KARATSUBA_THRESHOLD.get(), 2);
+            assert_eq!(p.mul_with(&q, MulAlgorithm::Karatsuba), expected);
+            assert_eq!(&r * &r, mul_classical(&r, &r));
+            assert_eq!(&p * &p, mul_classical(&p, &p));
+            let low = ZZPoly::from_raw((0..40).map(|i| expected.coeff(i)).collect());
+            assert_eq!(mullow(&p, &q, 40), low);
+        }
+
+        thresholds::reset_all();
+        assert_eq!(
+            thresholds::KARATSUBA_THRESHOLD.get(),
+            thresholds::KARATSUBA_THRESHOLD.default_value()
+        );
+    }
+}
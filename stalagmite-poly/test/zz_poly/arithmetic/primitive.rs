@@ -0,0 +1,72 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use stalagmite_poly::zz_poly::ZZPoly;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitive_ops_i32() {
+        let p = ZZPoly::from(vec![1, -2, 3]);
+        let n = -4i32;
+
+        assert_eq!(&p + n, ZZPoly::from(vec![-3, -2, 3]));
+        assert_eq!(p.clone() + &n, ZZPoly::from(vec![-3, -2, 3]));
+        assert_eq!(n + &p, ZZPoly::from(vec![-3, -2, 3]));
+        assert_eq!(&n + p.clone(), ZZPoly::from(vec![-3, -2, 3]));
+
+        assert_eq!(&p - n, ZZPoly::from(vec![5, -2, 3]));
+        assert_eq!(p.clone() - &n, ZZPoly::from(vec![5, -2, 3]));
+        assert_eq!(n - &p, ZZPoly::from(vec![-5, 2, -3]));
+        assert_eq!(&n - p.clone(), ZZPoly::from(vec![-5, 2, -3]));
+
+        assert_eq!(&p * n, ZZPoly::from(vec![-4, 8, -12]));
+        assert_eq!(p.clone() * &n, ZZPoly::from(vec![-4, 8, -12]));
+        assert_eq!(n * &p, ZZPoly::from(vec![-4, 8, -12]));
+        assert_eq!(&n * p.clone(), ZZPoly::from(vec![-4, 8, -12]));
+        assert!((&p * 0i32).is_zero());
+
+        let mut q = p.clone();
+        q += n;
+        q -= &n;
+        q *= n;
+        q *= &-1i32;
+        assert_eq!(q, ZZPoly::from(vec![4, -8, 12]));
+    }
+
+    #[test]
+    fn test_primitive_ops_u64() {
+        let p = ZZPoly::from(vec![1, -2, 3]);
+        let n = u64::MAX;
+        let big = malachite::Integer::from(n);
+
+        assert_eq!(&p + n, &p + &big);
+        assert_eq!(n + p.clone(), &p + &big);
+        assert_eq!(&p - n, &p - &big);
+        assert_eq!(&n - &p, &big - &p);
+        assert_eq!(&p * &n, &p * &big);
+        assert_eq!(n * p.clone(), &p * &big);
+
+        let mut q = p.clone();
+        q += 2u64;
+        q -= 1u64;
+        q *= 3u64;
+        assert_eq!(q, ZZPoly::from(vec![6, -6, 9]));
+    }
+}
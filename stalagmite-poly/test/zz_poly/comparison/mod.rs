@@ -14,3 +14,36 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::HashSet;
+use stalagmite_poly::zz_poly::ZZPoly;
+use malachite::Integer;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(ZZPoly::from(vec![1, 2, 3]), ZZPoly::from(vec![1, 2, 3]));
+        assert_ne!(ZZPoly::from(vec![1, 2, 3]), ZZPoly::from(vec![1, 2]));
+        // Equality is structural on normalized coefficients.
+        assert_eq!(ZZPoly::from(vec![1, 2, 0, 0]), ZZPoly::from(vec![1, 2]));
+        assert_eq!(ZZPoly::from(vec![0, 0]), ZZPoly::zero());
+    }
+
+    #[test]
+    fn test_hash_set() {
+        let mut set = HashSet::new();
+        set.insert(ZZPoly::from(vec![1, 2, 3]));
+        set.insert(ZZPoly::from(vec![1, 2, 3, 0]));
+        set.insert(ZZPoly::from(vec![Integer::from(1), Integer::from(2), Integer::from(3)]));
+        set.insert(ZZPoly::from(vec![1, 2]));
+        set.insert(ZZPoly::zero());
+        set.insert(ZZPoly::from(vec![0]));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&ZZPoly::from(vec![1, 2, 3])));
+        assert!(set.contains(&ZZPoly::from(vec![1, 2])));
+        assert!(set.contains(&ZZPoly::zero()));
+    }
+}
@@ -15,8 +15,11 @@
 // You should have received a copy of the GNU General Public License
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
 
+use std::str::FromStr;
 use stalagmite_poly::zz_poly::ZZPoly;
+use stalagmite_base::StalagmiteError;
 use malachite::Integer;
+use malachite::base::num::arithmetic::traits::Pow;
 
 #[cfg(test)]
 mod tests {
@@ -204,4 +207,64 @@ mod tests {
         assert_eq!(from_vec, from_array);
         assert_eq!(from_array, from_array_ref);
     }
+
+    // Test FromStr implementation
+    #[test]
+    fn test_from_str() {
+        assert_eq!(ZZPoly::from_str("3*x^2 - x + 1").unwrap(), ZZPoly::from(vec![1, -1, 3]));
+        assert_eq!(ZZPoly::from_str("-x^3 + 2*x").unwrap(), ZZPoly::from(vec![0, 2, 0, -1]));
+        assert_eq!(ZZPoly::from_str("0").unwrap(), ZZPoly::zero());
+        assert_eq!(ZZPoly::from_str("-42").unwrap(), ZZPoly::from(vec![-42]));
+        assert_eq!(ZZPoly::from_str("x").unwrap(), ZZPoly::r#gen());
+        assert_eq!(ZZPoly::from_str("x - x").unwrap(), ZZPoly::zero());
+        assert_eq!(ZZPoly::from_str("  1 +x^2 ").unwrap(), ZZPoly::from(vec![1, 0, 1]));
+    }
+
+    #[test]
+    fn test_from_str_round_trip() {
+        let polys = vec![
+            ZZPoly::zero(),
+            ZZPoly::one(),
+            ZZPoly::r#gen(),
+            ZZPoly::from(vec![-1]),
+            ZZPoly::from(vec![0, -1]),
+            ZZPoly::from(vec![5, -3]),
+            ZZPoly::from(vec![1, -1, 3]),
+            ZZPoly::from(vec![-7, 0, 0, 1, -1, 12]),
+            ZZPoly::from(vec![Integer::from(10).pow(30), Integer::from(-1), Integer::from(0), Integer::from(-1)]),
+        ];
+        for poly in polys {
+            assert_eq!(poly.to_string().parse::<ZZPoly>().unwrap(), poly);
+        }
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        for s in ["", "x^", "x^-1", "1 +", "2*", "2*y", "1/2", "3x", "+ - 1"] {
+            assert!(matches!(ZZPoly::from_str(s), Err(StalagmiteError::ParseError(_))), "{}", s);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let polys = vec![
+            ZZPoly::zero(),
+            ZZPoly::from(vec![1, -1, 3]),
+            ZZPoly::from(vec![Integer::from(10).pow(40), Integer::from(-7)]),
+        ];
+        for poly in polys {
+            let json = serde_json::to_string(&poly).unwrap();
+            assert_eq!(serde_json::from_str::<ZZPoly>(&json).unwrap(), poly);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_normalizes() {
+        let json = serde_json::to_string(&vec![Integer::from(1), Integer::from(2), Integer::from(0)]).unwrap();
+        let poly: ZZPoly = serde_json::from_str(&json).unwrap();
+        assert_eq!(poly, ZZPoly::from(vec![1, 2]));
+        assert_eq!(poly.length(), 2);
+    }
 }
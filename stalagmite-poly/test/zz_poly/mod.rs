@@ -19,12 +19,15 @@ pub mod arithmetic;
 pub mod comparison;
 pub mod conversion;
 
+use stalagmite_poly::qq_poly::QQPoly;
 use stalagmite_poly::zz_poly::ZZPoly;
-use malachite::Integer;
+use malachite::{Integer, Natural};
+use malachite::rational::Rational;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::Lcg;
 
     #[test]
     fn test_default() {
@@ -122,4 +125,593 @@ mod tests {
         let higher_degree_poly = ZZPoly::from(vec![0, 1, 1]);
         assert!(!higher_degree_poly.is_gen());
     }
+
+    #[test]
+    fn test_content() {
+        assert_eq!(ZZPoly::from(vec![2, 4, 6]).content(), 2);
+        assert_eq!(ZZPoly::from(vec![-2, 4, -6]).content(), 2);
+        assert_eq!(ZZPoly::from(vec![-7]).content(), 7);
+        assert_eq!(ZZPoly::from(vec![3, 5]).content(), 1);
+        assert_eq!(ZZPoly::zero().content(), 0);
+    }
+
+    #[test]
+    fn test_primitive_part() {
+        assert_eq!(ZZPoly::from(vec![2, 4, 6]).primitive_part(), ZZPoly::from(vec![1, 2, 3]));
+        assert_eq!(ZZPoly::from(vec![2, 4, -6]).primitive_part(), ZZPoly::from(vec![-1, -2, 3]));
+        assert_eq!(ZZPoly::from(vec![-7]).primitive_part(), ZZPoly::one());
+        assert_eq!(ZZPoly::zero().primitive_part(), ZZPoly::zero());
+
+        let poly = ZZPoly::from(vec![6, -9, 12]);
+        assert_eq!(ZZPoly::from(vec![poly.content()]) * poly.primitive_part(), poly);
+    }
+
+    #[test]
+    fn test_leading_coefficient() {
+        assert_eq!(ZZPoly::from(vec![1, 2, 3]).leading_coefficient(), Some(&Integer::from(3)));
+        assert_eq!(ZZPoly::from(vec![1, 0, -5, 0]).leading_coefficient(), Some(&Integer::from(-5)));
+        assert_eq!(ZZPoly::zero().leading_coefficient(), None);
+    }
+
+    #[test]
+    fn test_is_monic() {
+        assert!(ZZPoly::from(vec![5, 0, 1]).is_monic());
+        assert!(ZZPoly::one().is_monic());
+        assert!(ZZPoly::r#gen().is_monic());
+        assert!(!ZZPoly::from(vec![1, -1]).is_monic());
+        assert!(!ZZPoly::from(vec![1, 2]).is_monic());
+        assert!(!ZZPoly::zero().is_monic());
+    }
+
+    #[test]
+    fn test_try_from_points() {
+        let points = |pts: &[(i32, i32)]| -> Vec<(Integer, Integer)> {
+            pts.iter().map(|&(x, y)| (Integer::from(x), Integer::from(y))).collect()
+        };
+
+        assert_eq!(ZZPoly::try_from_points(&points(&[(0, 0), (1, 1), (2, 4)])), Some(ZZPoly::from(vec![0, 0, 1])));
+        // 2x^3 - x + 5
+        assert_eq!(
+            ZZPoly::try_from_points(&points(&[(-1, 4), (0, 5), (1, 6), (2, 19)])),
+            Some(ZZPoly::from(vec![5, -1, 0, 2]))
+        );
+        // x(x - 1)/2 takes integer values but has rational coefficients
+        assert_eq!(ZZPoly::try_from_points(&points(&[(0, 0), (1, 0), (2, 1)])), None);
+        assert_eq!(ZZPoly::try_from_points(&[]), Some(ZZPoly::zero()));
+    }
+
+    #[test]
+    fn test_derivative() {
+        assert_eq!(ZZPoly::from(vec![3, -2, 0, 4]).derivative(), ZZPoly::from(vec![-2, 0, 12]));
+        assert!(ZZPoly::from(vec![7]).derivative().is_zero());
+        assert!(ZZPoly::zero().derivative().is_zero());
+    }
+
+    #[test]
+    fn test_pseudo_divrem() {
+        let a = ZZPoly::from(vec![5, -1, 0, 3, 2]);
+        let b = ZZPoly::from(vec![1, 0, 3]);
+        let (q, r) = a.pseudo_divrem(&b);
+        assert!(r.length() < b.length());
+        // lc(b)^(4 - 2 + 1) * a = q * b + r
+        assert_eq!(&a * Integer::from(27), &q * &b + &r);
+
+        let (q, r) = b.pseudo_divrem(&a);
+        assert!(q.is_zero());
+        assert_eq!(r, b);
+    }
+
+    #[test]
+    fn test_div_exact() {
+        let a = ZZPoly::from(vec![1, 2, 3]);
+        let b = ZZPoly::from(vec![-4, 0, 5, 6]);
+        assert_eq!((&a * &b).div_exact(&b), a);
+        assert!(ZZPoly::zero().div_exact(&b).is_zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_exact_inexact() {
+        ZZPoly::from(vec![1, 0, 1]).div_exact(&ZZPoly::from(vec![1, 1]));
+    }
+
+    #[test]
+    fn test_divides() {
+        let a = ZZPoly::from(vec![-1, 0, 1]);
+        assert!(ZZPoly::from(vec![-1, 1]).divides(&a));
+        assert!(!ZZPoly::from(vec![2, 1]).divides(&a));
+        assert!(a.divides(&a));
+        assert!(!a.divides(&ZZPoly::from(vec![-1, 1])));
+
+        // constants divide when they divide the content
+        assert!(ZZPoly::from(vec![-3]).divides(&ZZPoly::from(vec![3, 6, 9])));
+        assert!(!ZZPoly::from(vec![2]).divides(&ZZPoly::from(vec![3, 6, 9])));
+
+        // every polynomial divides zero
+        assert!(a.divides(&ZZPoly::zero()));
+        assert!(ZZPoly::one().divides(&ZZPoly::zero()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero polynomial")]
+    fn test_divides_zero_divisor() {
+        ZZPoly::zero().divides(&ZZPoly::one());
+    }
+
+    #[test]
+    fn test_gcd() {
+        // (x + 1)(2x - 3) and (x + 1)(x^2 + 5)
+        let g = ZZPoly::from(vec![1, 1]);
+        let a = &g * ZZPoly::from(vec![-3, 2]);
+        let b = &g * ZZPoly::from(vec![5, 0, 1]);
+        assert_eq!(a.gcd(&b), g);
+        assert_eq!(b.gcd(&a), g);
+
+        // contents combine and the sign is normalized
+        let a = ZZPoly::from(vec![-6, -6]);
+        let b = ZZPoly::from(vec![4, 8, 4]);
+        assert_eq!(a.gcd(&b), ZZPoly::from(vec![2, 2]));
+
+        assert_eq!(a.gcd(&ZZPoly::zero()), ZZPoly::from(vec![6, 6]));
+        assert_eq!(ZZPoly::from(vec![1, 1]).gcd(&ZZPoly::from(vec![-1, 1])), ZZPoly::one());
+        assert!(ZZPoly::zero().gcd(&ZZPoly::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_squarefree_factorization() {
+        let xm1 = ZZPoly::from(vec![-1, 1]);
+        let xp1 = ZZPoly::from(vec![1, 1]);
+
+        // (x - 1)^2 (x + 1)
+        let p = &xm1 * &xm1 * &xp1;
+        assert_eq!(p.squarefree_factorization(), vec![(xp1.clone(), 1), (xm1.clone(), 2)]);
+
+        // squarefree input
+        let p = ZZPoly::from(vec![-1, 0, 3]);
+        assert_eq!(p.squarefree_factorization(), vec![(p.clone(), 1)]);
+
+        // -3 x^3 (2x + 1)^2 (x^2 + 1)
+        let x = ZZPoly::r#gen();
+        let q = ZZPoly::from(vec![1, 2]);
+        let r = ZZPoly::from(vec![1, 0, 1]);
+        let p = ZZPoly::from(vec![-3]) * &x * &x * &x * &q * &q * &r;
+        assert_eq!(p.squarefree_factorization(), vec![(r, 1), (q, 2), (x, 3)]);
+
+        assert!(ZZPoly::from(vec![5]).squarefree_factorization().is_empty());
+        assert!(ZZPoly::zero().squarefree_factorization().is_empty());
+    }
+
+    #[test]
+    fn test_is_squarefree() {
+        assert!(ZZPoly::from(vec![-1, 0, 1]).is_squarefree());
+        assert!(!ZZPoly::from(vec![1, -2, 1]).is_squarefree());
+        assert!(ZZPoly::from(vec![4, 4]).is_squarefree());
+        assert!(ZZPoly::from(vec![5]).is_squarefree());
+        assert!(!ZZPoly::zero().is_squarefree());
+
+        // x^2 (x + 1) is not squarefree, (x^2 + 1)(x - 2) is
+        assert!(!ZZPoly::from(vec![0, 0, 1, 1]).is_squarefree());
+        assert!(ZZPoly::from(vec![-2, 1, -2, 1]).is_squarefree());
+        assert!(!(ZZPoly::from(vec![-2, 1, -2, 1]) * ZZPoly::from(vec![1, 0, 1])).is_squarefree());
+    }
+
+    #[test]
+    fn test_resultant() {
+        // res(f, g) = lc(f)^deg(g) * prod g(r) over the roots r of f
+        let f = ZZPoly::from(vec![2, -3, 1]); // (x - 1)(x - 2)
+        let g = ZZPoly::from(vec![1, 0, 1]);
+        assert_eq!(f.resultant(&g), Integer::from(10));
+        assert_eq!(g.resultant(&f), Integer::from(10));
+
+        // non-monic and with content
+        let f = ZZPoly::from(vec![-1, 2]);
+        assert_eq!(f.resultant(&g), Integer::from(5));
+        assert_eq!(g.resultant(&f), Integer::from(5));
+        let f3 = ZZPoly::from(vec![-3, 6]);
+        assert_eq!(f3.resultant(&g), Integer::from(45));
+
+        // odd degrees swap sign
+        let f = ZZPoly::from(vec![-2, 1]);
+        let g = ZZPoly::from(vec![1, 0, 0, 1]);
+        assert_eq!(f.resultant(&g), Integer::from(9));
+        assert_eq!(g.resultant(&f), Integer::from(-9));
+
+        // x - a and x - b
+        let a = ZZPoly::from(vec![-5, 1]);
+        let b = ZZPoly::from(vec![7, 1]);
+        assert_eq!(a.resultant(&b), Integer::from(12));
+
+        // common root
+        let a = ZZPoly::from(vec![-2, 1]) * ZZPoly::from(vec![1, 3, 1]);
+        let b = ZZPoly::from(vec![-2, 1]) * ZZPoly::from(vec![4, 0, 0, 1]);
+        assert_eq!(a.resultant(&b), Integer::from(0));
+    }
+
+    #[test]
+    fn test_resultant_degree_zero() {
+        let c = ZZPoly::from(vec![-3]);
+        let g = ZZPoly::from(vec![1, 0, 1]);
+        assert_eq!(c.resultant(&g), Integer::from(9));
+        assert_eq!(g.resultant(&c), Integer::from(9));
+        assert_eq!(c.resultant(&ZZPoly::from(vec![4])), Integer::from(1));
+        assert_eq!(ZZPoly::zero().resultant(&g), Integer::from(0));
+        assert_eq!(c.resultant(&ZZPoly::zero()), Integer::from(0));
+    }
+
+    #[test]
+    fn test_discriminant() {
+        for (b, c) in [(3, 2), (0, 1), (2, 1), (-5, 7), (4, -3)] {
+            let p = ZZPoly::from(vec![c, b, 1]);
+            assert_eq!(p.discriminant(), Integer::from(b * b - 4 * c), "b = {}, c = {}", b, c);
+        }
+        // ax^2 + bx + c
+        assert_eq!(ZZPoly::from(vec![1, 3, -2]).discriminant(), Integer::from(17));
+        // x^3 + px + q has discriminant -4p^3 - 27q^2
+        assert_eq!(ZZPoly::from(vec![1, -2, 0, 1]).discriminant(), Integer::from(5));
+        assert_eq!(ZZPoly::from(vec![3, 2]).discriminant(), Integer::from(1));
+        assert_eq!(ZZPoly::from(vec![3]).discriminant(), Integer::from(0));
+        assert_eq!(ZZPoly::zero().discriminant(), Integer::from(0));
+    }
+
+    #[test]
+    fn test_set_coeff() {
+        let mut p = ZZPoly::from(vec![1, 2, 3]);
+        p.set_coeff(1, Integer::from(-7));
+        assert_eq!(p, ZZPoly::from(vec![1, -7, 3]));
+
+        // setting past the end extends the polynomial
+        p.set_coeff(5, Integer::from(4));
+        assert_eq!(p.degree(), 5);
+        assert_eq!(p, ZZPoly::from(vec![1, -7, 3, 0, 0, 4]));
+
+        // zeroing the top coefficient shrinks it
+        p.set_coeff(5, Integer::from(0));
+        assert_eq!(p.degree(), 2);
+        assert_eq!(p, ZZPoly::from(vec![1, -7, 3]));
+
+        p.set_coeff(9, Integer::from(0));
+        assert_eq!(p, ZZPoly::from(vec![1, -7, 3]));
+
+        let mut z = ZZPoly::zero();
+        z.set_coeff(0, Integer::from(2));
+        assert_eq!(z, ZZPoly::from(vec![2]));
+        z.set_coeff(0, Integer::from(0));
+        assert!(z.is_zero());
+    }
+
+    #[test]
+    fn test_coeff() {
+        let p = ZZPoly::from(vec![4, 0, -3]);
+        assert_eq!(p.coeff(0), Integer::from(4));
+        assert_eq!(p.coeff(1), Integer::from(0));
+        assert_eq!(p.coeff(2), Integer::from(-3));
+        assert_eq!(p.coeff(3), Integer::from(0));
+        assert_eq!(p.coeff(100), Integer::from(0));
+        assert_eq!(ZZPoly::zero().coeff(0), Integer::from(0));
+
+        assert_eq!(p.get_coeff(2), Some(&Integer::from(-3)));
+        assert_eq!(p.get_coeff(3), None);
+        assert_eq!(ZZPoly::zero().get_coeff(0), None);
+    }
+
+    #[test]
+    fn test_constant_term() {
+        assert_eq!(ZZPoly::from(vec![5, 7]).constant_term(), Integer::from(5));
+        assert_eq!(ZZPoly::from(vec![0, 7]).constant_term(), Integer::from(0));
+        assert_eq!(ZZPoly::zero().constant_term(), Integer::from(0));
+    }
+
+    #[test]
+    fn test_coeff_pairs() {
+        let p = ZZPoly::from(vec![0, 3, 0, 0, -5, 0, 1]);
+        let pairs = p.to_coeff_pairs();
+        assert_eq!(pairs, vec![(1, Integer::from(3)), (4, Integer::from(-5)), (6, Integer::from(1))]);
+        assert_eq!(ZZPoly::from_coeff_pairs(pairs.clone()), p);
+        assert_eq!(ZZPoly::from_coeff_pairs(pairs.into_iter().rev()), p);
+
+        assert!(ZZPoly::zero().to_coeff_pairs().is_empty());
+        assert!(ZZPoly::from_coeff_pairs(vec![]).is_zero());
+
+        // repeated exponents are summed, and cancelling terms normalize away
+        let pairs = vec![(2, Integer::from(4)), (0, Integer::from(1)), (2, Integer::from(-4))];
+        assert_eq!(ZZPoly::from_coeff_pairs(pairs), ZZPoly::from(vec![1]));
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = ZZPoly::from(vec![0, 3, 0, 5]).stats();
+        assert_eq!(stats.length, 4);
+        assert_eq!(stats.degree_opt, Some(3));
+        assert_eq!(stats.num_nonzero_terms, 2);
+        assert_eq!(stats.max_coefficient_bits, 3);
+
+        let stats = ZZPoly::from(vec![Integer::from(-256), Integer::from(255)]).stats();
+        assert_eq!(stats.max_coefficient_bits, 9);
+
+        let stats = ZZPoly::zero().stats();
+        assert_eq!(stats.length, 0);
+        assert_eq!(stats.degree_opt, None);
+        assert_eq!(stats.num_nonzero_terms, 0);
+        assert_eq!(stats.max_coefficient_bits, 0);
+    }
+
+    #[test]
+    fn test_degree_opt() {
+        assert_eq!(ZZPoly::zero().degree_opt(), None);
+        assert_eq!(ZZPoly::from(vec![5]).degree_opt(), Some(0));
+        assert_eq!(ZZPoly::from(vec![1, 0, -2]).degree_opt(), Some(2));
+        assert_eq!(ZZPoly::zero().degree(), ZZPoly::from(vec![5]).degree());
+    }
+
+    #[test]
+    fn test_into_coeffs() {
+        let p = ZZPoly::from(vec![4, 0, -3]);
+        assert_eq!(p.as_coeffs(), &[Integer::from(4), Integer::from(0), Integer::from(-3)]);
+        assert_eq!(ZZPoly::from_raw(p.clone().into_coeffs()), p);
+        assert!(ZZPoly::zero().into_coeffs().is_empty());
+        assert_eq!(ZZPoly::from_raw(ZZPoly::zero().into_coeffs()), ZZPoly::zero());
+
+        // zeroing the top coefficient in place leaves trailing zeros, which
+        // into_coeffs strips
+        let mut q = ZZPoly::from(vec![1, 2, 3]);
+        q.coeffs_mut()[2] = Integer::from(0);
+        assert_eq!(q.into_coeffs(), vec![Integer::from(1), Integer::from(2)]);
+    }
+
+    #[test]
+    fn test_to_string_with_var() {
+        assert_eq!(ZZPoly::from(vec![0, 0, 1]).to_string_with_var("t"), "t^2");
+        assert_eq!(ZZPoly::from(vec![0, 1]).to_string_with_var("t"), "t");
+        assert_eq!(ZZPoly::from(vec![3, -1, 0, -2]).to_string_with_var("y"), "-2*y^3 - y + 3");
+        assert_eq!(ZZPoly::from(vec![-1, 1, 1]).to_string_with_var("z"), "z^2 + z - 1");
+        assert_eq!(ZZPoly::from(vec![-7]).to_string_with_var("t"), "-7");
+        assert_eq!(ZZPoly::zero().to_string_with_var("t"), "0");
+
+        let p = ZZPoly::from(vec![5, -3, 0, 1, -4]);
+        assert_eq!(p.to_string_with_var("x"), p.to_string());
+    }
+
+    #[test]
+    fn test_reverse() {
+        let p = ZZPoly::from(vec![1, 2, 3]);
+        assert_eq!(p.reverse(3), ZZPoly::from(vec![3, 2, 1]));
+        assert_eq!(p.reverse(4), ZZPoly::from(vec![0, 3, 2, 1]));
+        assert_eq!(p.reverse(1), ZZPoly::from(vec![1]));
+        assert!(p.reverse(0).is_zero());
+
+        // trailing zeros of the input become leading zeros and are dropped
+        let p = ZZPoly::from(vec![0, 0, 5, 7]);
+        assert_eq!(p.reverse(4), ZZPoly::from(vec![7, 5]));
+        assert_eq!(p.reverse(4).reverse(4), p);
+        assert!(ZZPoly::zero().reverse(3).is_zero());
+    }
+
+    #[test]
+    fn test_negate() {
+        let p = ZZPoly::from(vec![4, 0, -3, 1]);
+        let n = -&p;
+        assert_eq!(n, ZZPoly::from(vec![-4, 0, 3, -1]));
+        assert_eq!(n, ZZPoly::zero() - &p);
+        // p is still usable
+        assert_eq!(-p.clone(), n);
+
+        let mut q = p.clone();
+        let capacity = q.capacity();
+        q.negate_assign();
+        assert_eq!(q, n);
+        assert_eq!(q.capacity(), capacity);
+
+        let mut z = ZZPoly::zero();
+        z.negate_assign();
+        assert!(z.is_zero());
+        assert!((-&ZZPoly::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_index() {
+        let mut p = ZZPoly::from(vec![4, 0, -3]);
+        assert_eq!(p[0], Integer::from(4));
+        assert_eq!(p[2], Integer::from(-3));
+        assert_eq!(p[1..], [Integer::from(0), Integer::from(-3)]);
+
+        p[1] = Integer::from(6);
+        assert_eq!(p, ZZPoly::from(vec![4, 6, -3]));
+
+        // zeroing the leading coefficient requires normalizing
+        p[2] = Integer::from(0);
+        p.normalize();
+        assert_eq!(p, ZZPoly::from(vec![4, 6]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let p = ZZPoly::from(vec![4, 0, -3]);
+        let _ = &p[3];
+    }
+
+    #[test]
+    fn test_terms() {
+        let p = ZZPoly::from(vec![0, 3, 0, 5]);
+        let terms: Vec<(usize, Integer)> = p.terms().map(|(i, c)| (i, c.clone())).collect();
+        assert_eq!(terms, vec![(1, Integer::from(3)), (3, Integer::from(5))]);
+
+        let p = ZZPoly::from(vec![-1, 0, 0, 0, 2]);
+        assert_eq!(p.terms().map(|(i, _)| i).collect::<Vec<_>>(), vec![0, 4]);
+        assert_eq!(ZZPoly::zero().terms().count(), 0);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let p = ZZPoly::from(vec![-5, 0, 2, 1]);
+        for x in -4i32..=4 {
+            let expected = -5 + 2 * x * x + x * x * x;
+            assert_eq!(p.evaluate(&Integer::from(x)), Integer::from(expected));
+        }
+        assert_eq!(ZZPoly::from(vec![7]).evaluate(&Integer::from(100)), Integer::from(7));
+    }
+
+    #[test]
+    fn test_evaluate_multi() {
+        let mut rng = Lcg::new(0x2545F4914F6CDD1D);
+
+        for (poly_len, num_points, bound) in [(1, 40, 10), (5, 10, 100), (20, 100, 1000), (300, 64, 50), (50, 257, 1 << 40), (100, 33, 3)] {
+            let coeffs: Vec<Integer> = (0..poly_len).map(|_| Integer::from(rng.next_signed(1 << 30)) << 70u64).collect();
+            let p = ZZPoly::from(coeffs);
+            let points: Vec<Integer> = (0..num_points).map(|_| Integer::from(rng.next_signed(bound))).collect();
+
+            let expected: Vec<Integer> = points.iter().map(|x| p.evaluate(x)).collect();
+            assert_eq!(p.evaluate_multi(&points), expected);
+        }
+
+        assert!(ZZPoly::from(vec![1, 2]).evaluate_multi(&[]).is_empty());
+        let points: Vec<Integer> = (0..50).map(Integer::from).collect();
+        assert_eq!(ZZPoly::zero().evaluate_multi(&points), vec![Integer::from(0); 50]);
+    }
+
+    #[test]
+    fn test_rem_linear() {
+        let mut rng = Lcg::new(0x9E3779B97F4A7C15);
+
+        for poly_len in [0, 1, 2, 7, 40] {
+            let coeffs: Vec<Integer> = (0..poly_len).map(|_| Integer::from(rng.next_signed(1 << 30))).collect();
+            let p = ZZPoly::from(coeffs);
+            let roots: Vec<Integer> = (0..40).map(|_| Integer::from(rng.next_signed(1000))).collect();
+            for root in &roots {
+                let rem = p.rem_linear(root);
+                assert_eq!(rem, p.evaluate(root));
+                let divisor = ZZPoly::from(vec![-root, Integer::from(1)]);
+                assert_eq!(ZZPoly::from(vec![rem]), &p % &divisor);
+            }
+            let expected: Vec<Integer> = roots.iter().map(|r| p.rem_linear(r)).collect();
+            assert_eq!(p.rem_many(&roots), expected);
+        }
+        assert!(ZZPoly::from(vec![1, 2]).rem_many(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_integer_roots() {
+        // x^2 (x - 4)(x + 4)(3x - 2)
+        let p = ZZPoly::from(vec![0, 0, 32, -48, -2, 3]);
+        assert_eq!(p.integer_roots(), vec![Integer::from(-4), Integer::from(0), Integer::from(4)]);
+
+        // repeated roots are listed once
+        let p = ZZPoly::from(vec![-1, 1]) * ZZPoly::from(vec![-1, 1]);
+        assert_eq!(p.integer_roots(), vec![Integer::from(1)]);
+
+        assert!(ZZPoly::from(vec![1, 0, 1]).integer_roots().is_empty());
+        assert!(ZZPoly::from(vec![5]).integer_roots().is_empty());
+        assert!(ZZPoly::zero().integer_roots().is_empty());
+    }
+
+    #[test]
+    fn test_rational_roots() {
+        // 2x^2 - 3x + 1 = (x - 1)(2x - 1)
+        let p = ZZPoly::from(vec![1, -3, 2]);
+        assert_eq!(p.rational_roots(), vec![Rational::from_signeds(1, 2), Rational::from(1)]);
+
+        // x (3x + 2)(4x - 3)(x^2 + 2)
+        let p = ZZPoly::from(vec![0, 1]) * ZZPoly::from(vec![2, 3])
+            * ZZPoly::from(vec![-3, 4]) * ZZPoly::from(vec![2, 0, 1]);
+        assert_eq!(
+            p.rational_roots(),
+            vec![Rational::from_signeds(-2, 3), Rational::from(0), Rational::from_signeds(3, 4)]
+        );
+
+        // the integer roots are among the rational roots
+        let p = ZZPoly::from(vec![6, -13, 1, 2]);
+        let integer: Vec<Rational> = p.integer_roots().into_iter().map(Rational::from).collect();
+        let rational = p.rational_roots();
+        assert_eq!(rational.len(), 3);
+        assert!(integer.iter().all(|r| rational.contains(r)));
+
+        assert!(ZZPoly::from(vec![-2, 0, 1]).rational_roots().is_empty());
+        assert!(ZZPoly::zero().rational_roots().is_empty());
+    }
+
+    #[test]
+    fn test_div_scalar() {
+        let p = ZZPoly::from(vec![2, 4, 6]);
+        let q = p.div_scalar(&Integer::from(4));
+        assert_eq!(q, QQPoly::from((vec![1, 2, 3], Natural::from(2u32))));
+
+        let q = p.div_scalar(&Integer::from(-3));
+        assert_eq!(q, QQPoly::from((vec![-2, -4, -6], Natural::from(3u32))));
+        assert_eq!(p.div_scalar(&Integer::from(1)), QQPoly::from(vec![2, 4, 6]));
+        assert!(ZZPoly::zero().div_scalar(&Integer::from(5)).is_zero());
+    }
+
+    #[test]
+    fn test_exact_div_scalar() {
+        let p = ZZPoly::from(vec![-6, 0, 9]);
+        assert_eq!(p.exact_div_scalar(&Integer::from(3)), Some(ZZPoly::from(vec![-2, 0, 3])));
+        assert_eq!(p.exact_div_scalar(&Integer::from(-3)), Some(ZZPoly::from(vec![2, 0, -3])));
+        assert_eq!(p.exact_div_scalar(&Integer::from(2)), None);
+        assert_eq!(ZZPoly::zero().exact_div_scalar(&Integer::from(7)), Some(ZZPoly::zero()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_div_scalar_by_zero() {
+        ZZPoly::from(vec![1, 2]).div_scalar(&Integer::from(0));
+    }
+
+    #[test]
+    fn test_large_coefficientwise_ops() {
+        // Long enough to take the parallel path with the rayon feature.
+        let n: i64 = 12_000;
+        let a_coeffs: Vec<Integer> = (0..n).map(|i| Integer::from(i * 7919 % 10007) - Integer::from(5000)).collect();
+        let b_coeffs: Vec<Integer> = (0..n + 5).map(|i| Integer::from(i * 104729 % 65537)).collect();
+        let a = ZZPoly::from(a_coeffs.clone());
+        let b = ZZPoly::from(b_coeffs.clone());
+        let c = Integer::from(-123456789);
+
+        let coeffwise = |f: &dyn Fn(&Integer, &Integer) -> Integer| {
+            let zero = Integer::from(0);
+            let coeffs: Vec<Integer> = (0..n as usize + 5)
+                .map(|i| f(a_coeffs.get(i).unwrap_or(&zero), &b_coeffs[i]))
+                .collect();
+            ZZPoly::from(coeffs)
+        };
+        let sum = coeffwise(&|x, y| x + y);
+        let diff = coeffwise(&|x, y| x - y);
+
+        assert_eq!(&a + &b, sum);
+        assert_eq!(a.clone() + b.clone(), sum);
+        assert_eq!(&b - &a, -&diff);
+        assert_eq!(&a - &b, diff);
+        assert_eq!(a.clone() - b.clone(), diff);
+
+        let mut d = b.clone();
+        d -= &a;
+        assert_eq!(d, -&diff);
+
+        let scaled = ZZPoly::from(a_coeffs.iter().map(|x| x * &c).collect::<Vec<_>>());
+        assert_eq!(&a * &c, scaled);
+        assert_eq!(a.clone() * c.clone(), scaled);
+    }
+
+    #[test]
+    fn test_coeffs_mod() {
+        let m = Natural::from(3u32);
+        let p = ZZPoly::from(vec![5, 7, 3]);
+        assert_eq!(p.coeffs_mod(&m), ZZPoly::from(vec![2, 1, 0]));
+        assert_eq!(p.coeffs_mod(&m).degree(), 1);
+        assert_eq!(ZZPoly::from(vec![-1, -5, 6]).coeffs_mod(&m), ZZPoly::from(vec![2, 1]));
+        assert!(ZZPoly::from(vec![3, -6]).coeffs_mod(&m).is_zero());
+    }
+
+    #[test]
+    fn test_coeffs_mod_symmetric() {
+        let p = ZZPoly::from(vec![0, 1, 2, 3, -1, -2, 4]);
+        assert_eq!(p.coeffs_mod_symmetric(&Natural::from(4u32)), ZZPoly::from(vec![0, 1, 2, -1, -1, 2]));
+        assert_eq!(p.coeffs_mod_symmetric(&Natural::from(5u32)), ZZPoly::from(vec![0, 1, 2, -2, -1, -2, -1]));
+        assert!(ZZPoly::from(vec![5, 10]).coeffs_mod_symmetric(&Natural::from(5u32)).is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_coeffs_mod_zero_modulus() {
+        ZZPoly::from(vec![1, 2]).coeffs_mod(&Natural::from(0u32));
+    }
 }
@@ -3,12 +3,21 @@
 // By vector we mean a tuple of elements with addition and scalar multiplication.
 // There is no distinction between row or column vectors.
 
+pub mod qq_vector;
+
+pub use qq_vector::QQVector;
+
 use stalagmite_base::integer::ZZElem;
+use malachite::base::num::arithmetic::traits::{Gcd, Lcm, Square};
+use malachite::Natural;
 use malachite::base::num::basic::traits::{Zero, One};
 use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct ZZVector {
     entries: Vec<ZZElem>
 }
@@ -68,6 +77,230 @@ impl ZZVector {
         self.iter().all(|x| *x == 0)
     }
 
+    /// Return the dot product of two vectors of the same length. Panics if
+    /// the lengths differ.
+    pub fn dot(&self, other: &ZZVector) -> ZZElem {
+        check_lengths(self.len(), other.len());
+        self.iter()
+            .zip(other.iter())
+            .fold(ZZElem::ZERO, |acc, (a, b)| acc + a * b)
+    }
+
+    /// Return the squared Euclidean norm, the sum of the squares of the
+    /// entries. The empty vector has norm zero.
+    pub fn norm_squared(&self) -> ZZElem {
+        self.iter().fold(ZZElem::ZERO, |acc, x| acc + x.square())
+    }
+
+    /// Return the infinity norm, the largest absolute value of an entry. The
+    /// empty vector has norm zero.
+    pub fn norm_infinity(&self) -> ZZElem {
+        let max = self.iter().map(|x| x.unsigned_abs_ref()).max();
+        max.map_or(ZZElem::ZERO, ZZElem::from)
+    }
+
+    /// Return the vector with every entry multiplied by `c`.
+    pub fn scalar_mul(&self, c: &ZZElem) -> ZZVector {
+        ZZVector::from_raw(self.iter().map(|x| x * c).collect())
+    }
+
+    /// Return the elementwise (Hadamard) product of two vectors of the same
+    /// length. Panics if the lengths differ.
+    pub fn hadamard(&self, other: &ZZVector) -> ZZVector {
+        check_lengths(self.len(), other.len());
+        ZZVector::from_raw(self.iter().zip(other.iter()).map(|(a, b)| a * b).collect())
+    }
+
+    /// Return the vector obtained by applying `f` to every entry.
+    pub fn map<F>(&self, f: F) -> ZZVector
+    where
+        F: FnMut(&ZZElem) -> ZZElem
+    {
+        ZZVector::from_raw(self.iter().map(f).collect())
+    }
+
+    /// Return the nonnegative gcd of the entries. The gcd of the zero vector
+    /// and of the empty vector is zero.
+    pub fn gcd(&self) -> ZZElem {
+        let mut g = Natural::ZERO;
+        for x in self.iter() {
+            g = g.gcd(x.unsigned_abs_ref());
+            if g == 1 {
+                break;
+            }
+        }
+        ZZElem::from(g)
+    }
+
+    /// Return the nonnegative lcm of the entries. The lcm is zero if any
+    /// entry is zero, and the lcm of the empty vector is one.
+    pub fn lcm(&self) -> ZZElem {
+        let mut l = Natural::ONE;
+        for x in self.iter() {
+            if *x == 0 {
+                return ZZElem::ZERO;
+            }
+            l = l.lcm(x.unsigned_abs_ref());
+        }
+        ZZElem::from(l)
+    }
+}
+
+#[inline]
+fn check_lengths(a: usize, b: usize) {
+    if a != b {
+        panic!("Cannot perform operation on vectors of different lengths: {} and {}", a, b);
+    }
+}
+
+impl Add<&ZZVector> for &ZZVector {
+    type Output = ZZVector;
+    fn add(self, rhs: &ZZVector) -> ZZVector {
+        check_lengths(self.len(), rhs.len());
+        ZZVector::from_raw(self.iter().zip(rhs.iter()).map(|(a, b)| a + b).collect())
+    }
+}
+
+impl Add<&ZZVector> for ZZVector {
+    type Output = ZZVector;
+    #[inline]
+    fn add(mut self, rhs: &ZZVector) -> ZZVector {
+        self += rhs;
+        self
+    }
+}
+
+impl Add<ZZVector> for &ZZVector {
+    type Output = ZZVector;
+    #[inline]
+    fn add(self, mut rhs: ZZVector) -> ZZVector {
+        rhs += self;
+        rhs
+    }
+}
+
+impl Add for ZZVector {
+    type Output = ZZVector;
+    #[inline]
+    fn add(mut self, rhs: ZZVector) -> ZZVector {
+        self += &rhs;
+        self
+    }
+}
+
+impl AddAssign<&ZZVector> for ZZVector {
+    fn add_assign(&mut self, rhs: &ZZVector) {
+        check_lengths(self.len(), rhs.len());
+        for (a, b) in self.entries.iter_mut().zip(rhs.iter()) {
+            *a += b;
+        }
+    }
+}
+
+impl AddAssign for ZZVector {
+    #[inline]
+    fn add_assign(&mut self, rhs: ZZVector) {
+        *self += &rhs;
+    }
+}
+
+impl Sub<&ZZVector> for &ZZVector {
+    type Output = ZZVector;
+    fn sub(self, rhs: &ZZVector) -> ZZVector {
+        check_lengths(self.len(), rhs.len());
+        ZZVector::from_raw(self.iter().zip(rhs.iter()).map(|(a, b)| a - b).collect())
+    }
+}
+
+impl Sub<&ZZVector> for ZZVector {
+    type Output = ZZVector;
+    #[inline]
+    fn sub(mut self, rhs: &ZZVector) -> ZZVector {
+        self -= rhs;
+        self
+    }
+}
+
+impl Sub<ZZVector> for &ZZVector {
+    type Output = ZZVector;
+    #[inline]
+    fn sub(self, rhs: ZZVector) -> ZZVector {
+        self - &rhs
+    }
+}
+
+impl Sub for ZZVector {
+    type Output = ZZVector;
+    #[inline]
+    fn sub(mut self, rhs: ZZVector) -> ZZVector {
+        self -= &rhs;
+        self
+    }
+}
+
+impl SubAssign<&ZZVector> for ZZVector {
+    fn sub_assign(&mut self, rhs: &ZZVector) {
+        check_lengths(self.len(), rhs.len());
+        for (a, b) in self.entries.iter_mut().zip(rhs.iter()) {
+            *a -= b;
+        }
+    }
+}
+
+impl SubAssign for ZZVector {
+    #[inline]
+    fn sub_assign(&mut self, rhs: ZZVector) {
+        *self -= &rhs;
+    }
+}
+
+impl Mul<&ZZElem> for &ZZVector {
+    type Output = ZZVector;
+    #[inline]
+    fn mul(self, rhs: &ZZElem) -> ZZVector {
+        self.scalar_mul(rhs)
+    }
+}
+
+impl Mul<ZZElem> for &ZZVector {
+    type Output = ZZVector;
+    #[inline]
+    fn mul(self, rhs: ZZElem) -> ZZVector {
+        self.scalar_mul(&rhs)
+    }
+}
+
+impl Mul<&ZZElem> for ZZVector {
+    type Output = ZZVector;
+    #[inline]
+    fn mul(mut self, rhs: &ZZElem) -> ZZVector {
+        self *= rhs;
+        self
+    }
+}
+
+impl Mul<ZZElem> for ZZVector {
+    type Output = ZZVector;
+    #[inline]
+    fn mul(mut self, rhs: ZZElem) -> ZZVector {
+        self *= &rhs;
+        self
+    }
+}
+
+impl MulAssign<&ZZElem> for ZZVector {
+    fn mul_assign(&mut self, rhs: &ZZElem) {
+        for a in self.entries.iter_mut() {
+            *a *= rhs;
+        }
+    }
+}
+
+impl MulAssign<ZZElem> for ZZVector {
+    #[inline]
+    fn mul_assign(&mut self, rhs: ZZElem) {
+        *self *= &rhs;
+    }
 }
 
 impl<T> From<Vec<T>> for ZZVector
@@ -80,4 +313,111 @@ where
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot() {
+        let a = ZZVector::from(vec![1, 2, 3]);
+        let b = ZZVector::from(vec![4, 5, 6]);
+        assert_eq!(a.dot(&b), 32);
+        assert_eq!(ZZVector::zeros(3).dot(&ZZVector::zeros(3)), 0);
+        assert_eq!(ZZVector::from_raw(vec![]).dot(&ZZVector::from_raw(vec![])), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dot_length_mismatch() {
+        ZZVector::zeros(2).dot(&ZZVector::zeros(3));
+    }
+
+    #[test]
+    fn test_norms() {
+        assert_eq!(ZZVector::from(vec![3, 4]).norm_squared(), 25);
+        assert_eq!(ZZVector::from(vec![-5, 2, 3]).norm_infinity(), 5);
+        assert_eq!(ZZVector::from(vec![-5, 2, 3]).norm_squared(), 38);
+        assert_eq!(ZZVector::from(vec![1, -7]).norm_infinity(), 7);
+
+        let empty = ZZVector::from_raw(vec![]);
+        assert_eq!(empty.norm_squared(), 0);
+        assert_eq!(empty.norm_infinity(), 0);
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let a = ZZVector::from(vec![1, 2, 3]);
+        let b = ZZVector::from(vec![4, -5, 6]);
+        assert_eq!(&a + &b, ZZVector::from(vec![5, -3, 9]));
+        assert_eq!(a.clone() + b.clone(), ZZVector::from(vec![5, -3, 9]));
+        assert_eq!(&a - &b, ZZVector::from(vec![-3, 7, -3]));
+        assert_eq!(b.clone() - a.clone(), ZZVector::from(vec![3, -7, 3]));
+
+        let mut c = a.clone();
+        c += &b;
+        c -= b;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_length_mismatch() {
+        let _ = ZZVector::zeros(2) + ZZVector::zeros(3);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let a = ZZVector::from(vec![1, -2, 3]);
+        let c = ZZElem::from(-3);
+        assert_eq!(a.scalar_mul(&c), ZZVector::from(vec![-3, 6, -9]));
+        assert_eq!(&a * &c, ZZVector::from(vec![-3, 6, -9]));
+        assert_eq!(a.clone() * ZZElem::ZERO, ZZVector::zeros(3));
+
+        let mut b = a.clone();
+        b *= ZZElem::from(2);
+        assert_eq!(b, ZZVector::from(vec![2, -4, 6]));
+    }
 
+    #[test]
+    fn test_hadamard_map() {
+        let a = ZZVector::from(vec![1, 2, 3]);
+        let b = ZZVector::from(vec![4, 5, 6]);
+        assert_eq!(a.hadamard(&b), ZZVector::from(vec![4, 10, 18]));
+        assert_eq!(a.map(|x| x * x), ZZVector::from(vec![1, 4, 9]));
+        assert_eq!(a.map(|x| -x), ZZVector::from(vec![-1, -2, -3]));
+
+        let empty = ZZVector::from_raw(vec![]);
+        assert_eq!(empty.hadamard(&empty), empty);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hadamard_length_mismatch() {
+        ZZVector::zeros(2).hadamard(&ZZVector::zeros(3));
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(ZZVector::from(vec![6, 9, 15]).gcd(), 3);
+        assert_eq!(ZZVector::from(vec![-6, 9, -15]).gcd(), 3);
+        assert_eq!(ZZVector::zeros(3).gcd(), 0);
+        assert_eq!(ZZVector::from(vec![0, -4]).gcd(), 4);
+
+        assert_eq!(ZZVector::from(vec![2, 3, 4]).lcm(), 12);
+        assert_eq!(ZZVector::from(vec![-2, 3, -4]).lcm(), 12);
+        assert_eq!(ZZVector::from(vec![2, 0, 4]).lcm(), 0);
+        assert_eq!(ZZVector::from_raw(vec![]).lcm(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let v = ZZVector::from(vec![1, -2, 0, 3]);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(serde_json::from_str::<ZZVector>(&json).unwrap(), v);
+
+        let empty = ZZVector::from_raw(vec![]);
+        let json = serde_json::to_string(&empty).unwrap();
+        assert_eq!(serde_json::from_str::<ZZVector>(&json).unwrap(), empty);
+    }
+}
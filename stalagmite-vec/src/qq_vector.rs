@@ -0,0 +1,311 @@
+use stalagmite_base::rational::QQElem;
+use malachite::base::num::basic::traits::{Zero, One};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use crate::{ZZVector, check_lengths};
+
+/// A vector with rational entries. Entries are always in lowest terms, as
+/// guaranteed by `QQElem`.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct QQVector {
+    entries: Vec<QQElem>
+}
+
+// Impl Deref but NOT DerefMut.
+impl std::ops::Deref for QQVector {
+    type Target = Vec<QQElem>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.entries
+    }
+}
+
+impl fmt::Display for QQVector {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut iter = self.iter();
+        if let Some(first) = iter.next() {
+            write!(f, "({}", first)?;
+            for item in iter {
+                write!(f, " {}", item)?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+impl QQVector {
+    #[inline]
+    pub fn new<T: Into<QQVector>>(input: T) -> Self {
+        input.into()
+    }
+
+    #[inline]
+    pub fn from_raw(entries: Vec<QQElem>) -> Self {
+        QQVector { entries }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        QQVector::from_raw(Vec::with_capacity(capacity))
+    }
+
+    #[inline]
+    pub fn zeros(len: usize) -> Self {
+        QQVector::from_raw(vec![QQElem::ZERO; len])
+    }
+
+    #[inline]
+    pub fn ones(len: usize) -> Self {
+        QQVector::from_raw(vec![QQElem::ONE; len])
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.iter().all(|x| *x == 0)
+    }
+
+    /// Return the dot product of two vectors of the same length. Panics if
+    /// the lengths differ.
+    pub fn dot(&self, other: &QQVector) -> QQElem {
+        check_lengths(self.len(), other.len());
+        self.iter()
+            .zip(other.iter())
+            .fold(QQElem::ZERO, |acc, (a, b)| acc + a * b)
+    }
+
+    /// Return the vector with every entry multiplied by `c`.
+    pub fn scalar_mul(&self, c: &QQElem) -> QQVector {
+        QQVector::from_raw(self.iter().map(|x| x * c).collect())
+    }
+}
+
+impl<T> From<Vec<T>> for QQVector
+where
+    T: Into<QQElem>
+{
+    fn from(entries: Vec<T>) -> Self {
+        let entries = entries.into_iter().map(|x| x.into()).collect();
+        QQVector::from_raw(entries)
+    }
+}
+
+impl From<ZZVector> for QQVector {
+    fn from(v: ZZVector) -> Self {
+        QQVector::from_raw(v.iter().map(QQElem::from).collect())
+    }
+}
+
+impl From<&ZZVector> for QQVector {
+    fn from(v: &ZZVector) -> Self {
+        QQVector::from_raw(v.iter().map(QQElem::from).collect())
+    }
+}
+
+impl Add<&QQVector> for &QQVector {
+    type Output = QQVector;
+    fn add(self, rhs: &QQVector) -> QQVector {
+        check_lengths(self.len(), rhs.len());
+        QQVector::from_raw(self.iter().zip(rhs.iter()).map(|(a, b)| a + b).collect())
+    }
+}
+
+impl Add<&QQVector> for QQVector {
+    type Output = QQVector;
+    #[inline]
+    fn add(mut self, rhs: &QQVector) -> QQVector {
+        self += rhs;
+        self
+    }
+}
+
+impl Add<QQVector> for &QQVector {
+    type Output = QQVector;
+    #[inline]
+    fn add(self, mut rhs: QQVector) -> QQVector {
+        rhs += self;
+        rhs
+    }
+}
+
+impl Add for QQVector {
+    type Output = QQVector;
+    #[inline]
+    fn add(mut self, rhs: QQVector) -> QQVector {
+        self += &rhs;
+        self
+    }
+}
+
+impl AddAssign<&QQVector> for QQVector {
+    fn add_assign(&mut self, rhs: &QQVector) {
+        check_lengths(self.len(), rhs.len());
+        for (a, b) in self.entries.iter_mut().zip(rhs.iter()) {
+            *a += b;
+        }
+    }
+}
+
+impl AddAssign for QQVector {
+    #[inline]
+    fn add_assign(&mut self, rhs: QQVector) {
+        *self += &rhs;
+    }
+}
+
+impl Sub<&QQVector> for &QQVector {
+    type Output = QQVector;
+    fn sub(self, rhs: &QQVector) -> QQVector {
+        check_lengths(self.len(), rhs.len());
+        QQVector::from_raw(self.iter().zip(rhs.iter()).map(|(a, b)| a - b).collect())
+    }
+}
+
+impl Sub<&QQVector> for QQVector {
+    type Output = QQVector;
+    #[inline]
+    fn sub(mut self, rhs: &QQVector) -> QQVector {
+        self -= rhs;
+        self
+    }
+}
+
+impl Sub<QQVector> for &QQVector {
+    type Output = QQVector;
+    #[inline]
+    fn sub(self, rhs: QQVector) -> QQVector {
+        self - &rhs
+    }
+}
+
+impl Sub for QQVector {
+    type Output = QQVector;
+    #[inline]
+    fn sub(mut self, rhs: QQVector) -> QQVector {
+        self -= &rhs;
+        self
+    }
+}
+
+impl SubAssign<&QQVector> for QQVector {
+    fn sub_assign(&mut self, rhs: &QQVector) {
+        check_lengths(self.len(), rhs.len());
+        for (a, b) in self.entries.iter_mut().zip(rhs.iter()) {
+            *a -= b;
+        }
+    }
+}
+
+impl SubAssign for QQVector {
+    #[inline]
+    fn sub_assign(&mut self, rhs: QQVector) {
+        *self -= &rhs;
+    }
+}
+
+impl Mul<&QQElem> for &QQVector {
+    type Output = QQVector;
+    #[inline]
+    fn mul(self, rhs: &QQElem) -> QQVector {
+        self.scalar_mul(rhs)
+    }
+}
+
+impl Mul<QQElem> for &QQVector {
+    type Output = QQVector;
+    #[inline]
+    fn mul(self, rhs: QQElem) -> QQVector {
+        self.scalar_mul(&rhs)
+    }
+}
+
+impl Mul<&QQElem> for QQVector {
+    type Output = QQVector;
+    #[inline]
+    fn mul(mut self, rhs: &QQElem) -> QQVector {
+        self *= rhs;
+        self
+    }
+}
+
+impl Mul<QQElem> for QQVector {
+    type Output = QQVector;
+    #[inline]
+    fn mul(mut self, rhs: QQElem) -> QQVector {
+        self *= &rhs;
+        self
+    }
+}
+
+impl MulAssign<&QQElem> for QQVector {
+    fn mul_assign(&mut self, rhs: &QQElem) {
+        for a in self.entries.iter_mut() {
+            *a *= rhs;
+        }
+    }
+}
+
+impl MulAssign<QQElem> for QQVector {
+    #[inline]
+    fn mul_assign(&mut self, rhs: QQElem) {
+        *self *= &rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn q(n: i64, d: i64) -> QQElem {
+        QQElem::from_signeds(n, d)
+    }
+
+    #[test]
+    fn test_dot() {
+        let a = QQVector::from(vec![q(1, 2), q(1, 3)]);
+        let b = QQVector::from(vec![6, 6]);
+        assert_eq!(a.dot(&b), 5);
+        assert_eq!(a.dot(&a), q(13, 36));
+        assert_eq!(QQVector::from_raw(vec![]).dot(&QQVector::from_raw(vec![])), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dot_length_mismatch() {
+        QQVector::zeros(2).dot(&QQVector::zeros(3));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let a = QQVector::from(vec![q(1, 2), q(-1, 3)]);
+        let b = QQVector::from(vec![q(1, 2), q(1, 6)]);
+        assert_eq!(&a + &b, QQVector::from(vec![q(1, 1), q(-1, 6)]));
+        assert_eq!(&a - &b, QQVector::from(vec![q(0, 1), q(-1, 2)]));
+        assert!((&a - &a).is_zero());
+
+        let mut c = a.clone();
+        c += &b;
+        c -= b;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let a = QQVector::from(vec![q(1, 2), q(-2, 3), q(3, 1)]);
+        assert_eq!(a.scalar_mul(&q(3, 2)), QQVector::from(vec![q(3, 4), q(-1, 1), q(9, 2)]));
+        assert_eq!(&a * q(0, 1), QQVector::zeros(3));
+    }
+
+    #[test]
+    fn test_from_zz_vector() {
+        let v = ZZVector::from(vec![1, -2, 3]);
+        let w = QQVector::from(&v);
+        assert_eq!(w, QQVector::from(vec![1, -2, 3]));
+        assert_eq!(QQVector::from(v), w);
+        assert_eq!(QQVector::ones(2).to_string(), "(1 1)");
+        assert_eq!(QQVector::from(vec![q(1, 2), q(-3, 4)]).to_string(), "(1/2 -3/4)");
+    }
+}
@@ -32,7 +32,7 @@ use crate::{ZnElem, check_moduli};
 /// let a = zn.new(Natural::from(5u32));
 /// let b = zn.new(Natural::from(4u32));
 /// let result = a + b; // (5 + 4) mod 7 = 2
-/// assert_eq!(result.value(), Natural::from(2u32));
+/// assert_eq!(*result.value(), Natural::from(2u32));
 /// ```
 impl Add for ZnElem {
     type Output = ZnElem;
@@ -43,7 +43,7 @@ impl Add for ZnElem {
         //assert!(self.context() == rhs.context();)
         
         let sum = &self.value + &rhs.value;
-        let reduced = sum % &self.ctx.modulus;
+        let reduced = self.ctx.reduce(sum);
         
         ZnElem::from_ctx(reduced, self.ctx)
     }
@@ -56,7 +56,7 @@ impl AddAssign for ZnElem {
         check_moduli!(self, rhs);
         
         self.value += &rhs.value;
-        self.value %= &self.ctx.modulus;
+        self.ctx.reduce_assign(&mut self.value);
     }
 }
 
@@ -68,7 +68,7 @@ impl Add<&ZnElem> for ZnElem {
         check_moduli!(self, rhs);
         
         let sum = &self.value + &rhs.value;
-        let reduced = sum % &self.ctx.modulus;
+        let reduced = self.ctx.reduce(sum);
         
         ZnElem::from_ctx(reduced, self.ctx)
     }
@@ -82,7 +82,7 @@ impl Add<ZnElem> for &ZnElem {
         check_moduli!(self, rhs);
         
         let sum = &self.value + &rhs.value;
-        let reduced = sum % &self.ctx.modulus;
+        let reduced = self.ctx.reduce(sum);
         
         ZnElem::from_ctx(reduced, rhs.ctx)
     }
@@ -96,7 +96,7 @@ impl Add<&ZnElem> for &ZnElem {
         check_moduli!(self, rhs);
         
         let sum = &self.value + &rhs.value;
-        let reduced = sum % &self.ctx.modulus;
+        let reduced = self.ctx.reduce(sum);
         
         ZnElem::from_ctx(reduced, self.ctx.clone())
     }
@@ -108,6 +108,44 @@ impl AddAssign<&ZnElem> for ZnElem {
         check_moduli!(self, rhs);
         
         self.value += &rhs.value;
-        self.value %= &self.ctx.modulus;
+        self.ctx.reduce_assign(&mut self.value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ZnRing;
+    use malachite::Natural;
+
+    #[test]
+    fn test_add_wraparound() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let a = ring.new(Natural::from(5u32));
+        let b = ring.new(Natural::from(4u32));
+        let expected = Natural::from(2u32);
+
+        assert_eq!(*(&a + &b).value(), expected);
+        assert_eq!(*(a.clone() + &b).value(), expected);
+        assert_eq!(*(&a + b.clone()).value(), expected);
+        assert_eq!(*(a.clone() + b.clone()).value(), expected);
+
+        let mut c = a.clone();
+        c += &b;
+        assert_eq!(*c.value(), expected);
+        let mut c = a.clone();
+        c += b.clone();
+        assert_eq!(*c.value(), expected);
+
+        // a + (n - a) = 0
+        let c = ring.new(Natural::from(2u32));
+        assert_eq!(*(&a + &c).value(), Natural::from(0u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "different rings")]
+    fn test_add_mismatched_moduli() {
+        let a = ZnRing::init(Natural::from(7u32)).new(Natural::from(1u32));
+        let b = ZnRing::init(Natural::from(11u32)).new(Natural::from(1u32));
+        let _ = a + b;
     }
 }
@@ -0,0 +1,73 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::base::num::arithmetic::traits::Lcm;
+use stalagmite_base::integer::crt;
+use crate::ZnElem;
+
+impl ZnElem {
+    /// Combine this element with an element of another ring using the
+    /// Chinese remainder theorem, returning the element `x` modulo the least
+    /// common multiple of the two moduli (the product when coprime) that
+    /// reduces to both, or `None` if the two residues are inconsistent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_zn::ZnElem;
+    /// use malachite::Natural;
+    ///
+    /// let a = ZnElem::new(Natural::from(2u32), Natural::from(3u32));
+    /// let b = ZnElem::new(Natural::from(3u32), Natural::from(5u32));
+    /// let x = a.crt(&b).unwrap();
+    /// assert_eq!(*x.value(), Natural::from(8u32));
+    /// assert_eq!(*x.modulus(), Natural::from(15u32));
+    /// ```
+    pub fn crt(&self, other: &ZnElem) -> Option<ZnElem> {
+        let residues = [
+            (self.to_natural(), self.modulus().clone()),
+            (other.to_natural(), other.modulus().clone()),
+        ];
+        let x = crt(&residues)?;
+        Some(ZnElem::new(x, self.modulus().lcm(other.modulus())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use malachite::Natural;
+
+    fn elem(value: u32, modulus: u32) -> ZnElem {
+        ZnElem::new(Natural::from(value), Natural::from(modulus))
+    }
+
+    #[test]
+    fn test_crt_coprime() {
+        let x = elem(2, 3).crt(&elem(3, 5)).unwrap();
+        assert_eq!(x, elem(8, 15));
+
+        let x = x.crt(&elem(2, 7)).unwrap();
+        assert_eq!(x, elem(23, 105));
+    }
+
+    #[test]
+    fn test_crt_not_coprime() {
+        assert_eq!(elem(2, 4).crt(&elem(4, 6)).unwrap(), elem(10, 12));
+        assert!(elem(1, 4).crt(&elem(2, 6)).is_none());
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use std::ops::{Div, DivAssign};
+use crate::ZnElem;
+
+/// Divide one integer modulo n by another.
+///
+/// This operation multiplies by the modular inverse of the divisor, and panics
+/// if the divisor is not a unit modulo n.
+///
+/// # Examples
+///
+/// ```
+/// use stalagmite_zn::{ZnRing, ZnElem};
+/// use malachite::Natural;
+///
+/// let zn = ZnRing::init(Natural::from(7u32));
+/// let a = zn.new(Natural::from(1u32));
+/// let b = zn.new(Natural::from(3u32));
+/// let result = a / b; // 1 * 3^(-1) mod 7 = 5
+/// assert_eq!(*result.value(), Natural::from(5u32));
+/// ```
+impl Div for ZnElem {
+    type Output = ZnElem;
+
+    #[inline]
+    fn div(mut self, rhs: ZnElem) -> ZnElem {
+        self /= &rhs;
+        self
+    }
+}
+
+/// Divide by a reference to another integer modulo n.
+impl Div<&ZnElem> for ZnElem {
+    type Output = ZnElem;
+
+    #[inline]
+    fn div(mut self, rhs: &ZnElem) -> ZnElem {
+        self /= rhs;
+        self
+    }
+}
+
+/// Divide a reference to this integer modulo n by another one.
+impl Div<ZnElem> for &ZnElem {
+    type Output = ZnElem;
+
+    #[inline]
+    fn div(self, rhs: ZnElem) -> ZnElem {
+        self / &rhs
+    }
+}
+
+/// Divide two references to integers modulo n.
+impl Div<&ZnElem> for &ZnElem {
+    type Output = ZnElem;
+
+    fn div(self, rhs: &ZnElem) -> ZnElem {
+        check_moduli!(self, rhs);
+        let inverse = rhs.inverse().expect("Element has no modular inverse");
+        self * inverse
+    }
+}
+
+/// Divide this integer modulo n by another one, modifying this element in place.
+impl DivAssign for ZnElem {
+    #[inline]
+    fn div_assign(&mut self, rhs: ZnElem) {
+        *self /= &rhs;
+    }
+}
+
+/// Divide this integer modulo n by a reference to another one.
+impl DivAssign<&ZnElem> for ZnElem {
+    fn div_assign(&mut self, rhs: &ZnElem) {
+        check_moduli!(self, rhs);
+        let inverse = rhs.inverse().expect("Element has no modular inverse");
+        *self *= inverse;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ZnRing;
+    use malachite::Natural;
+
+    #[test]
+    fn test_div() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let a = ring.new(Natural::from(4u32));
+        let b = ring.new(Natural::from(3u32));
+        // 4 * 3^(-1) = 4 * 5 = 20 = 6 mod 7
+        let expected = Natural::from(6u32);
+
+        assert_eq!(*(&a / &b).value(), expected);
+        assert_eq!(*(a.clone() / &b).value(), expected);
+        assert_eq!(*(&a / b.clone()).value(), expected);
+        assert_eq!(*(a.clone() / b.clone()).value(), expected);
+
+        let mut c = a.clone();
+        c /= &b;
+        assert_eq!(*c.value(), expected);
+        let mut c = a.clone();
+        c /= b.clone();
+        assert_eq!(*c.value(), expected);
+
+        assert_eq!(&(&a / &b) * &b, a);
+    }
+
+    #[test]
+    fn test_div_composite_modulus() {
+        let ring = ZnRing::init(Natural::from(15u32));
+        let a = ring.new(Natural::from(8u32));
+        let b = ring.new(Natural::from(4u32));
+        // 4^(-1) = 4 mod 15, so 8 / 4 = 32 = 2 mod 15
+        assert_eq!(*(&a / &b).value(), Natural::from(2u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "Element has no modular inverse")]
+    fn test_div_non_unit() {
+        let ring = ZnRing::init(Natural::from(4u32));
+        let a = ring.new(Natural::from(1u32));
+        let b = ring.new(Natural::from(2u32));
+        let _ = a / b;
+    }
+}
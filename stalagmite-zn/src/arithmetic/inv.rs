@@ -15,8 +15,10 @@
 // You should have received a copy of the GNU General Public License
 // along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
 
-use malachite::base::num::arithmetic::traits::ModInverse;
-use crate::ZnElem;
+use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::{Mod, ModInverse};
+use stalagmite_base::integer::xgcd;
+use crate::{ZnElem, ZnRing};
 
 pub trait Inv {
     type Output;
@@ -24,13 +26,106 @@ pub trait Inv {
     fn inv(self) -> Self::Output;
 }
 
+impl ZnElem {
+    /// Return the multiplicative inverse of this element, or `None` if the
+    /// value is not a unit modulo n.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_zn::ZnRing;
+    /// use malachite::Natural;
+    ///
+    /// let zn = ZnRing::init(Natural::from(7u32));
+    /// let a = zn.new(Natural::from(3u32));
+    /// assert_eq!(*a.inverse().unwrap().value(), Natural::from(5u32));
+    ///
+    /// let zn = ZnRing::init(Natural::from(4u32));
+    /// assert!(zn.new(Natural::from(2u32)).inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<ZnElem> {
+        if *self.modulus() == 1u32 {
+            return Some(self.clone());
+        } else if *self.value() == 0u32 {
+            return None;
+        }
+        let modulus = Integer::from(self.modulus());
+        let (g, s, _) = xgcd(&Integer::from(self.to_natural()), &modulus);
+        if g != 1 {
+            return None;
+        }
+        let inverse = Natural::try_from(s.mod_op(modulus)).unwrap();
+        Some(ZnElem::from_ctx(self.ctx.to_repr(inverse), self.ctx.clone()))
+    }
+}
+
+impl ZnRing {
+    /// Invert every element of `elems` using a single modular inversion
+    /// (Montgomery's trick), returning `None` if any element is not a unit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element does not belong to this ring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_zn::ZnRing;
+    /// use malachite::Natural;
+    ///
+    /// let zn = ZnRing::init(Natural::from(7u32));
+    /// let elems = vec![zn.new(Natural::from(2u32)), zn.new(Natural::from(3u32))];
+    /// let invs = zn.batch_inverse(&elems).unwrap();
+    /// assert_eq!(*invs[0].value(), Natural::from(4u32));
+    /// assert_eq!(*invs[1].value(), Natural::from(5u32));
+    ///
+    /// let zn = ZnRing::init(Natural::from(6u32));
+    /// assert!(zn.batch_inverse(&[zn.new(Natural::from(5u32)), zn.new(Natural::from(2u32))]).is_none());
+    /// ```
+    pub fn batch_inverse(&self, elems: &[ZnElem]) -> Option<Vec<ZnElem>> {
+        for elem in elems {
+            check_moduli!(self, elem);
+        }
+        if *self.modulus() == 1u32 {
+            return Some(elems.to_vec());
+        } else if elems.is_empty() {
+            return Some(Vec::new());
+        }
+
+        // prefix[i] = elems[0] * ... * elems[i]
+        let ctx = self.context();
+        let mut prefix = Vec::with_capacity(elems.len());
+        prefix.push(elems[0].value.clone());
+        for (i, elem) in elems.iter().enumerate().skip(1) {
+            let next = ctx.mul(&prefix[i - 1], &elem.value);
+            prefix.push(next);
+        }
+
+        let total = ctx.to_standard(&prefix[elems.len() - 1]).into_owned();
+        if total == 0u32 {
+            return None;
+        }
+        let mut inv = ctx.to_repr(total.mod_inverse(self.modulus())?);
+
+        // Walking backwards, inv holds (elems[0] * ... * elems[i])^(-1).
+        let mut result = vec![None; elems.len()];
+        for i in (1..elems.len()).rev() {
+            result[i] = Some(ctx.mul(&inv, &prefix[i - 1]));
+            ctx.mul_assign(&mut inv, &elems[i].value);
+        }
+        result[0] = Some(inv);
+
+        Some(result.into_iter()
+            .map(|value| ZnElem::from_ctx(value.unwrap(), self.ctx.clone()))
+            .collect())
+    }
+}
+
 impl Inv for ZnElem {
     type Output = ZnElem;
 
     fn inv(self) -> Self::Output {
-        let inverse = self.value().mod_inverse(self.modulus())
-            .expect("Element has no modular inverse");
-        ZnElem::from_ctx(inverse, self.ctx.clone())
+        self.inverse().expect("Element has no modular inverse")
     }
 }
 
@@ -38,9 +133,7 @@ impl Inv for &ZnElem {
     type Output = ZnElem;
 
     fn inv(self) -> Self::Output {
-        let inverse = self.value().mod_inverse(self.modulus())
-            .expect("Element has no modular inverse");
-        ZnElem::from_ctx(inverse, self.ctx.clone())
+        self.inverse().expect("Element has no modular inverse")
     }
 }
 
@@ -80,6 +173,18 @@ mod tests {
         six.inv(); // This should panic
     }
 
+    #[test]
+    fn test_inverse_option() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let three = ring.new(Natural::from(3u32));
+        assert_eq!(*three.inverse().unwrap().value(), Natural::from(5u32));
+        assert!(ring.new(Natural::from(0u32)).inverse().is_none());
+
+        let ring = ZnRing::init(Natural::from(4u32));
+        assert!(ring.new(Natural::from(2u32)).inverse().is_none());
+        assert_eq!(*ring.new(Natural::from(3u32)).inverse().unwrap().value(), Natural::from(3u32));
+    }
+
     #[test]
     fn test_inverse_reference() {
         let ring = ZnRing::init(Natural::from(7u32));
@@ -88,4 +193,40 @@ mod tests {
         let inv_three = (&three).inv();
         assert_eq!(*inv_three.value(), Natural::from(5u32));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_batch_inverse_prime_modulus() {
+        for ring in [ZnRing::init(Natural::from(1000003u32)), ZnRing::init_montgomery(Natural::from(1000003u32))] {
+            let elems = ring.random_elements_from_seed(7, 50);
+            let elems: Vec<ZnElem> = elems.into_iter().filter(|a| *a.value() != 0u32).collect();
+            let invs = ring.batch_inverse(&elems).unwrap();
+            assert_eq!(invs.len(), elems.len());
+            for (a, inv) in elems.iter().zip(invs.iter()) {
+                assert_eq!(*inv, a.inverse().unwrap());
+            }
+
+            let single = [ring.new(Natural::from(2u32))];
+            assert_eq!(ring.batch_inverse(&single).unwrap()[0], single[0].inverse().unwrap());
+            assert!(ring.batch_inverse(&[]).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_batch_inverse_non_unit() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let elems = [ring.new(Natural::from(3u32)), ring.new(Natural::from(0u32))];
+        assert!(ring.batch_inverse(&elems).is_none());
+
+        let ring = ZnRing::init(Natural::from(15u32));
+        let elems = [ring.new(Natural::from(2u32)), ring.new(Natural::from(6u32)), ring.new(Natural::from(7u32))];
+        assert!(ring.batch_inverse(&elems).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "different rings")]
+    fn test_batch_inverse_wrong_ring() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let other = ZnRing::init(Natural::from(11u32));
+        ring.batch_inverse(&[other.new(Natural::from(3u32))]);
+    }
+}
@@ -19,4 +19,9 @@ pub mod add;
 pub mod sub;
 pub mod neg;
 pub mod mul;
-pub mod inv;
\ No newline at end of file
+pub mod inv;
+pub mod div;
+pub mod pow;
+pub mod sqrt;
+pub mod crt;
+pub mod order;
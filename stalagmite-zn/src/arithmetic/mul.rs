@@ -17,8 +17,7 @@
 
 use std::ops::{Mul, MulAssign};
 use std::rc::Rc;
-use crate::{ZnElem, IntegerMod};
-use malachite::base::num::arithmetic::traits::{ModMulPrecomputed, ModMulPrecomputedAssign};
+use crate::ZnElem;
 use stalagmite_base::traits::Element;
 
 /// Multiply two integers modulo n.
@@ -35,7 +34,7 @@ use stalagmite_base::traits::Element;
 /// let a = zn.new(Natural::from(3u32));
 /// let b = zn.new(Natural::from(5u32));
 /// let result = a * b; // (3 * 5) mod 7 = 1
-/// assert_eq!(result.value(), Natural::from(1u32));
+/// assert_eq!(*result.value(), Natural::from(1u32));
 /// ```
 impl Mul for ZnElem {
     type Output = ZnElem;
@@ -77,7 +76,7 @@ impl Mul<&ZnElem> for &ZnElem {
         check_moduli!(self, rhs);
         let ctx = Rc::clone(&self.ctx);
 
-        let res = (&self.value).mod_mul_precomputed(&rhs.value, ctx.modulus(), ctx.mod_mul_data());
+        let res = ctx.mul(&self.value, &rhs.value);
         ZnElem::from_ctx(res, ctx)
     }
 }
@@ -86,8 +85,7 @@ impl Mul<&ZnElem> for &ZnElem {
 impl MulAssign for ZnElem {
     fn mul_assign(&mut self, rhs: ZnElem) {
         check_moduli!(self, rhs);
-        let IntegerMod { value: rhs_value, ctx: rhs_ctx } = rhs;
-        self.value.mod_mul_precomputed_assign(rhs_value, rhs_ctx.modulus(), rhs_ctx.mod_mul_data());
+        self.ctx.mul_assign(&mut self.value, &rhs.value);
     }
 }
 
@@ -95,7 +93,50 @@ impl MulAssign for ZnElem {
 impl MulAssign<&ZnElem> for ZnElem {
     fn mul_assign(&mut self, rhs: &ZnElem) {
         check_moduli!(self, rhs);
-        let ctx = Rc::clone(&self.ctx);
-        self.value.mod_mul_precomputed_assign(&rhs.value, ctx.modulus(), ctx.mod_mul_data());
+        self.ctx.mul_assign(&mut self.value, &rhs.value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ZnRing;
+    use malachite::Natural;
+
+    #[test]
+    fn test_mul_wraparound() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let a = ring.new(Natural::from(3u32));
+        let b = ring.new(Natural::from(5u32));
+        let expected = Natural::from(1u32);
+
+        assert_eq!(*(&a * &b).value(), expected);
+        assert_eq!(*(a.clone() * &b).value(), expected);
+        assert_eq!(*(&a * b.clone()).value(), expected);
+        assert_eq!(*(a.clone() * b.clone()).value(), expected);
+
+        let mut c = a.clone();
+        c *= &b;
+        assert_eq!(*c.value(), expected);
+        let mut c = a.clone();
+        c *= b.clone();
+        assert_eq!(*c.value(), expected);
+    }
+
+    #[test]
+    fn test_mul_large_modulus() {
+        // 2^127 - 1 is prime.
+        let n = (Natural::from(1u32) << 127u32) - Natural::from(1u32);
+        let ring = ZnRing::init(n.clone());
+        let a = ring.new(&n - Natural::from(1u32));
+        // (-1) * (-1) = 1
+        assert_eq!(*(&a * &a).value(), Natural::from(1u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "different rings")]
+    fn test_mul_mismatched_moduli() {
+        let a = ZnRing::init(Natural::from(7u32)).new(Natural::from(1u32));
+        let b = ZnRing::init(Natural::from(11u32)).new(Natural::from(1u32));
+        let _ = a * b;
     }
 }
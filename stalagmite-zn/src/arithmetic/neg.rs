@@ -17,6 +17,7 @@
 
 use std::ops::Neg;
 use malachite::Natural;
+use malachite::base::num::arithmetic::traits::NegAssign;
 use malachite::base::num::basic::traits::Zero;
 use crate::ZnElem;
 
@@ -35,11 +36,11 @@ use crate::ZnElem;
 /// let zn = ZnRing::init(Natural::from(7u32));
 /// let a = zn.new(Natural::from(3u32));
 /// let result = -a; // -3 mod 7 = 4
-/// assert_eq!(result.value(), Natural::from(4u32));
+/// assert_eq!(*result.value(), Natural::from(4u32));
 ///
 /// let zero = zn.new(Natural::from(0u32));
 /// let neg_zero = -zero; // -0 mod 7 = 0
-/// assert_eq!(neg_zero.value(), Natural::from(0u32));
+/// assert_eq!(*neg_zero.value(), Natural::from(0u32));
 /// ```
 impl Neg for ZnElem {
     type Output = ZnElem;
@@ -70,4 +71,43 @@ impl Neg for &ZnElem {
             ZnElem::from_ctx(neg_value, self.ctx.clone())
         }
     }
-}
\ No newline at end of file
+}
+
+/// Negate an integer modulo n in place.
+impl NegAssign for ZnElem {
+    fn neg_assign(&mut self) {
+        if self.value != Natural::ZERO {
+            self.value = &self.ctx.modulus - &self.value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ZnRing;
+
+    #[test]
+    fn test_neg() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let a = ring.new(Natural::from(3u32));
+        assert_eq!(*(-&a).value(), Natural::from(4u32));
+        assert_eq!(*(-a.clone()).value(), Natural::from(4u32));
+        assert_eq!(-(-a.clone()), a);
+
+        let zero = ring.new(Natural::ZERO);
+        assert_eq!(*(-&zero).value(), Natural::ZERO);
+    }
+
+    #[test]
+    fn test_neg_assign() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let mut a = ring.new(Natural::from(6u32));
+        a.neg_assign();
+        assert_eq!(*a.value(), Natural::from(1u32));
+
+        let mut zero = ring.new(Natural::ZERO);
+        zero.neg_assign();
+        assert_eq!(*zero.value(), Natural::ZERO);
+    }
+}
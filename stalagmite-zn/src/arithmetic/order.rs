@@ -0,0 +1,205 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::Natural;
+use malachite::base::num::arithmetic::traits::{DivExact, Gcd};
+use malachite::base::num::basic::traits::One;
+use stalagmite_base::factor::Factor;
+use stalagmite_base::factored::FactoredNatural;
+use crate::{ZnElem, ZnRing};
+
+// Factorization of the Euler totient of n, from phi(p^e) = p^(e-1) * (p - 1).
+pub(crate) fn euler_phi_factored(n: &Natural) -> FactoredNatural {
+    let mut phi = FactoredNatural::new();
+    for (p, exp) in n.clone().factor().factors {
+        if exp > 1 {
+            phi *= FactoredNatural::from([(p.clone(), exp - 1)]);
+        }
+        let mut p_minus_one = p - Natural::ONE;
+        if p_minus_one > 1u32 {
+            phi *= p_minus_one.factor();
+        }
+    }
+    phi
+}
+
+impl ZnElem {
+    /// Return the multiplicative order of this element, the smallest `k > 0`
+    /// with `x^k = 1`, or `None` if the element is not a unit. This factors
+    /// the modulus and `phi(n)`, then removes prime factors from `phi(n)`
+    /// while the power stays one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_zn::ZnRing;
+    /// use malachite::Natural;
+    ///
+    /// let zn = ZnRing::init(Natural::from(7u32));
+    /// assert_eq!(zn.new(Natural::from(3u32)).multiplicative_order(), Some(Natural::from(6u32)));
+    /// assert_eq!(zn.new(Natural::from(2u32)).multiplicative_order(), Some(Natural::from(3u32)));
+    /// assert_eq!(zn.new(Natural::from(0u32)).multiplicative_order(), None);
+    /// ```
+    pub fn multiplicative_order(&self) -> Option<Natural> {
+        if self.to_natural().gcd(self.modulus()) != 1u32 {
+            return None;
+        }
+        let phi = euler_phi_factored(self.modulus());
+        Some(self.order_dividing(&phi))
+    }
+
+    // Return the order of a unit whose order divides the expanded `phi`.
+    pub(crate) fn order_dividing(&self, phi: &FactoredNatural) -> Natural {
+        let one = ZnElem::from_ctx(self.ctx.one(), self.ctx.clone());
+        let mut order = phi.expand();
+        for (p, exp) in phi.iter() {
+            for _ in 0..*exp {
+                let candidate = (&order).div_exact(p);
+                if self.pow(&candidate) != one {
+                    break;
+                }
+                order = candidate;
+            }
+        }
+        order
+    }
+}
+
+impl ZnRing {
+    /// Return a generator of the multiplicative group of units, or `None` if
+    /// the group is not cyclic. A primitive root exists exactly when the
+    /// modulus is 1, 2, 4, `p^k` or `2p^k` for an odd prime `p`. The smallest
+    /// primitive root is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_zn::ZnRing;
+    /// use malachite::Natural;
+    ///
+    /// let zn = ZnRing::init(Natural::from(7u32));
+    /// assert_eq!(*zn.primitive_root().unwrap().value(), Natural::from(3u32));
+    /// assert!(ZnRing::init(Natural::from(8u32)).primitive_root().is_none());
+    /// ```
+    pub fn primitive_root(&self) -> Option<ZnElem> {
+        let n = self.modulus();
+        if *n <= 4u32 {
+            // n - 1 generates the units for n <= 4, including the trivial
+            // group of Zn(1)
+            return Some(self.new(n - Natural::ONE));
+        }
+
+        let factors = n.clone().factor();
+        let odd_primes = factors.keys().filter(|p| **p != 2u32).count();
+        let two_exp = factors.get(&Natural::from(2u32)).copied().unwrap_or(0);
+        if odd_primes != 1 || two_exp > 1 {
+            return None;
+        }
+
+        let phi = euler_phi_factored(n);
+        let phi_value = phi.expand();
+        let mut g = Natural::from(2u32);
+        loop {
+            if (&g).gcd(n) == 1u32 {
+                let x = self.new(g.clone());
+                if x.order_dividing(&phi) == phi_value {
+                    return Some(x);
+                }
+            }
+            g += Natural::ONE;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ZnRing;
+
+    // Smallest k > 0 with x^k = 1, by repeated multiplication.
+    fn naive_order(x: &ZnElem) -> Natural {
+        let mut k = 1u64;
+        let mut y = x.clone();
+        while y.to_natural() != Natural::ONE % x.modulus() {
+            y *= x;
+            k += 1;
+        }
+        Natural::from(k)
+    }
+
+    #[test]
+    fn test_multiplicative_order_mod_7() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let order = |x: u32| ring.new(Natural::from(x)).multiplicative_order();
+        assert_eq!(order(1), Some(Natural::from(1u32)));
+        assert_eq!(order(2), Some(Natural::from(3u32)));
+        assert_eq!(order(3), Some(Natural::from(6u32)));
+        assert_eq!(order(6), Some(Natural::from(2u32)));
+        assert_eq!(order(0), None);
+    }
+
+    #[test]
+    fn test_multiplicative_order_matches_naive() {
+        for n in [2u32, 8, 9, 12, 25, 36, 97, 100, 1024] {
+            let ring = ZnRing::init(Natural::from(n));
+            for a in 0..n {
+                let x = ring.new(Natural::from(a));
+                match x.multiplicative_order() {
+                    Some(order) => assert_eq!(order, naive_order(&x), "{} mod {}", a, n),
+                    None => assert_ne!(Natural::from(a).gcd(Natural::from(n)), 1u32),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiplicative_order_large() {
+        // 2 has order 127 modulo the Mersenne prime 2^127 - 1
+        let p = (Natural::ONE << 127u64) - Natural::ONE;
+        let ring = ZnRing::init(p);
+        let two = ring.new(Natural::from(2u32));
+        assert_eq!(two.multiplicative_order(), Some(Natural::from(127u32)));
+    }
+
+    #[test]
+    fn test_primitive_root() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let g = ring.primitive_root().unwrap();
+        assert_eq!(g.multiplicative_order(), Some(Natural::from(6u32)));
+
+        for n in [1u32, 2, 3, 4, 5, 6, 9, 10, 18, 25, 27, 50, 97, 121, 242] {
+            let ring = ZnRing::init(Natural::from(n));
+            let g = ring.primitive_root().unwrap();
+            let phi = stalagmite_base::natural::euler_phi(&Natural::from(n));
+            assert_eq!(g.multiplicative_order(), Some(phi), "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_primitive_root_none() {
+        for n in [8u32, 12, 15, 16, 20, 21, 24, 100] {
+            assert!(ZnRing::init(Natural::from(n)).primitive_root().is_none(), "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_multiplicative_order_modulus_one() {
+        let ring = ZnRing::init(Natural::ONE);
+        let x = ring.new(Natural::from(0u32));
+        assert_eq!(x.multiplicative_order(), Some(Natural::ONE));
+    }
+}
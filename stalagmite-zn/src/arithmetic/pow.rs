@@ -0,0 +1,244 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::UnsignedAbs;
+use malachite::base::num::logic::traits::BitIterable;
+use crate::{ZnElem, ZnContext};
+
+// Left-to-right binary exponentiation using the multiplication of the context.
+fn pow_natural(base: &Natural, exp: &Natural, ctx: &ZnContext) -> Natural {
+    let mut res = ctx.one();
+    for bit in exp.bits().rev() {
+        res = ctx.mul(&res, &res);
+        if bit {
+            ctx.mul_assign(&mut res, base);
+        }
+    }
+    res
+}
+
+// Largest window accepted by `pow_windowed`, which needs 2^(window - 1)
+// precomputed powers.
+const MAX_POW_WINDOW: usize = 16;
+
+// Left-to-right sliding window exponentiation. Runs of up to `window` bits
+// ending in a set bit are handled with a single multiplication by one of the
+// precomputed odd powers base, base^3, ..., base^(2^window - 1).
+fn pow_natural_windowed(base: &Natural, exp: &Natural, ctx: &ZnContext, window: usize) -> Natural {
+    let bits: Vec<bool> = exp.bits().collect();
+    if bits.is_empty() {
+        return ctx.one();
+    }
+
+    let base_sqr = ctx.mul(base, base);
+    let mut odd_powers = Vec::with_capacity(1 << (window - 1));
+    odd_powers.push(base.clone());
+    for i in 1..(1 << (window - 1)) {
+        let next = ctx.mul(&odd_powers[i - 1], &base_sqr);
+        odd_powers.push(next);
+    }
+
+    let mut res = ctx.one();
+    let mut i = bits.len();
+    while i > 0 {
+        if !bits[i - 1] {
+            res = ctx.mul(&res, &res);
+            i -= 1;
+            continue;
+        }
+
+        // Bits j..i form the window, with bit j set so the window value is odd.
+        let mut j = i.saturating_sub(window);
+        while !bits[j] {
+            j += 1;
+        }
+        let mut value = 0usize;
+        for k in (j..i).rev() {
+            res = ctx.mul(&res, &res);
+            value = (value << 1) | bits[k] as usize;
+        }
+        ctx.mul_assign(&mut res, &odd_powers[value >> 1]);
+        i = j;
+    }
+    res
+}
+
+impl ZnElem {
+    /// Raise this element to a non-negative integer power using square and
+    /// multiply. Any element raised to the power zero is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_zn::ZnRing;
+    /// use malachite::Natural;
+    ///
+    /// let zn = ZnRing::init(Natural::from(7u32));
+    /// let a = zn.new(Natural::from(3u32));
+    /// let result = a.pow(&Natural::from(4u32)); // 3^4 = 81 = 4 mod 7
+    /// assert_eq!(*result.value(), Natural::from(4u32));
+    /// ```
+    pub fn pow(&self, exp: &Natural) -> ZnElem {
+        let res = pow_natural(&self.value, exp, &self.ctx);
+        ZnElem::from_ctx(res, self.ctx.clone())
+    }
+
+    /// Raise this element to a non-negative integer power using sliding
+    /// window exponentiation, processing up to `window` bits of the exponent
+    /// per multiplication. This is faster than [`ZnElem::pow`] for large
+    /// exponents, at the cost of precomputing `2^(window - 1)` odd powers.
+    /// The window is clamped to `1..=16`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_zn::ZnRing;
+    /// use malachite::Natural;
+    ///
+    /// let zn = ZnRing::init(Natural::from(1000003u32));
+    /// let a = zn.new(Natural::from(12345u32));
+    /// let exp = Natural::from(987654321u32);
+    /// assert_eq!(a.pow_windowed(&exp, 4), a.pow(&exp));
+    /// ```
+    pub fn pow_windowed(&self, exp: &Natural, window: usize) -> ZnElem {
+        let window = window.clamp(1, MAX_POW_WINDOW);
+        let res = pow_natural_windowed(&self.value, exp, &self.ctx, window);
+        ZnElem::from_ctx(res, self.ctx.clone())
+    }
+
+    /// Raise this element to a `u64` power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_zn::ZnRing;
+    /// use malachite::Natural;
+    ///
+    /// let zn = ZnRing::init(Natural::from(7u32));
+    /// let a = zn.new(Natural::from(3u32));
+    /// assert_eq!(*a.pow_u64(6).value(), Natural::from(1u32));
+    /// ```
+    #[inline]
+    pub fn pow_u64(&self, exp: u64) -> ZnElem {
+        self.pow(&Natural::from(exp))
+    }
+
+    /// Raise this element to a signed integer power. For negative exponents the
+    /// element is inverted first, returning `None` if it is not a unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_zn::ZnRing;
+    /// use malachite::{Integer, Natural};
+    ///
+    /// let zn = ZnRing::init(Natural::from(7u32));
+    /// let a = zn.new(Natural::from(3u32));
+    /// // 3^(-1) = 5 mod 7, so 3^(-2) = 25 = 4 mod 7
+    /// assert_eq!(*a.pow_signed(&Integer::from(-2)).unwrap().value(), Natural::from(4u32));
+    ///
+    /// let zn = ZnRing::init(Natural::from(4u32));
+    /// assert!(zn.new(Natural::from(2u32)).pow_signed(&Integer::from(-1)).is_none());
+    /// ```
+    pub fn pow_signed(&self, exp: &Integer) -> Option<ZnElem> {
+        if *exp < 0 {
+            self.inverse().map(|inv| inv.pow(&exp.unsigned_abs()))
+        } else {
+            Some(self.pow(&exp.unsigned_abs()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ZnRing;
+
+    #[test]
+    fn test_pow_zero() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        assert_eq!(*ring.new(Natural::from(3u32)).pow_u64(0).value(), Natural::from(1u32));
+        assert_eq!(*ring.new(Natural::from(0u32)).pow_u64(0).value(), Natural::from(1u32));
+        assert_eq!(*ring.new(Natural::from(0u32)).pow_u64(5).value(), Natural::from(0u32));
+
+        // One is reduced modulo n.
+        let ring = ZnRing::init(Natural::from(1u32));
+        assert_eq!(*ring.new(Natural::from(0u32)).pow_u64(0).value(), Natural::from(0u32));
+    }
+
+    #[test]
+    fn test_pow_matches_repeated_mul() {
+        let ring = ZnRing::init(Natural::from(1000u32));
+        let a = ring.new(Natural::from(123u32));
+        let mut expected = ring.new(Natural::from(1u32));
+        for k in 0..20u64 {
+            assert_eq!(a.pow_u64(k), expected);
+            expected *= &a;
+        }
+    }
+
+    #[test]
+    fn test_fermat_little_theorem() {
+        for p in [2u64, 3, 5, 7, 101, 65537, 1000000007] {
+            let ring = ZnRing::init(Natural::from(p));
+            for a in [1u64, 2, 3, 12345] {
+                let a = ring.new(Natural::from(a % p));
+                if *a.value() == 0u32 {
+                    continue;
+                }
+                assert_eq!(*a.pow_u64(p - 1).value(), Natural::from(1u32));
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_signed() {
+        let ring = ZnRing::init(Natural::from(11u32));
+        let a = ring.new(Natural::from(2u32));
+        assert_eq!(a.pow_signed(&Integer::from(3)).unwrap(), a.pow_u64(3));
+        let inv = a.pow_signed(&Integer::from(-3)).unwrap();
+        assert_eq!(*(&inv * &a.pow_u64(3)).value(), Natural::from(1u32));
+
+        let ring = ZnRing::init(Natural::from(12u32));
+        let b = ring.new(Natural::from(4u32));
+        assert!(b.pow_signed(&Integer::from(-2)).is_none());
+        assert_eq!(*b.pow_signed(&Integer::from(2)).unwrap().value(), Natural::from(4u32));
+    }
+
+    #[test]
+    fn test_pow_windowed_matches_pow() {
+        let moduli = [
+            Natural::from(2u32),
+            Natural::from(1024u32),
+            Natural::from(1000u32),
+            Natural::from(1000000007u32),
+            (Natural::from(1u32) << 127u64) - Natural::from(1u32),
+        ];
+        for n in moduli {
+            for ring in [ZnRing::init(n.clone()), ZnRing::init_montgomery(n.clone())] {
+                let a = ring.new(Natural::from(123456789u64) % &n);
+                for e in [0u64, 1, 2, 3, 7, 8, 255, 256, 65537, 0xdeadbeef, u64::MAX] {
+                    let exp = Natural::from(e);
+                    for window in [0usize, 1, 2, 3, 4, 5, 8] {
+                        assert_eq!(a.pow_windowed(&exp, window), a.pow(&exp), "{} {} {}", n, e, window);
+                    }
+                }
+            }
+        }
+    }
+}
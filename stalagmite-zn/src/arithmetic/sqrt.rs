@@ -0,0 +1,181 @@
+// Copyright (C) 2025 William Youmans
+//
+// This file is part of Stalagmite.
+//
+// Stalagmite is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published
+// by the Free Software Foundation, either version 3 of the License,
+// or (at your option) any later version.
+//
+// Stalagmite is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Stalagmite. If not, see <https://www.gnu.org/licenses/>.
+
+use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::{Mod, Parity};
+use malachite::base::num::basic::traits::One;
+use stalagmite_base::integer::legendre;
+use stalagmite_base::is_prime::is_prime;
+use crate::ZnElem;
+
+impl ZnElem {
+    /// Return a square root of this element if the modulus is prime and the
+    /// element is a quadratic residue, or `None` otherwise. The root of zero
+    /// is zero for any modulus; every other element of a ring with composite
+    /// modulus gives `None`. Uses Tonelli-Shanks, with a single
+    /// exponentiation when `p = 3 mod 4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_zn::ZnRing;
+    /// use malachite::Natural;
+    ///
+    /// let zn = ZnRing::init(Natural::from(7u32));
+    /// let r = zn.new(Natural::from(2u32)).sqrt().unwrap();
+    /// assert_eq!(*(&r * &r).value(), Natural::from(2u32));
+    /// assert!(zn.new(Natural::from(3u32)).sqrt().is_none());
+    /// ```
+    pub fn sqrt(&self) -> Option<ZnElem> {
+        let p = self.modulus();
+        let a = self.to_natural();
+        if a == 0u32 || *p == 2u32 {
+            return Some(self.clone());
+        }
+        // Tonelli-Shanks may not terminate for composite moduli
+        if p.even() || !is_prime(p.clone()) || legendre(&Integer::from(&a), p) != 1 {
+            return None;
+        }
+
+        let one = ZnElem::from_ctx(self.ctx.one(), self.ctx.clone());
+        let p_minus_one = p - Natural::ONE;
+
+        let root = if (p % Natural::from(4u32)) == 3u32 {
+            self.pow(&((p + Natural::ONE) >> 2))
+        } else {
+            // p - 1 = q * 2^s with q odd
+            let s = p_minus_one.trailing_zeros().unwrap();
+            let q = &p_minus_one >> s;
+
+            // find a quadratic non-residue z
+            let mut z = Integer::from(2);
+            while legendre(&z, p) != -1 {
+                z += Integer::ONE;
+            }
+            let z = self.ctx.to_repr(Natural::try_from(z.mod_op(Integer::from(p))).unwrap());
+            let z = ZnElem::from_ctx(z, self.ctx.clone());
+
+            let mut m = s;
+            let mut c = z.pow(&q);
+            let mut t = self.pow(&q);
+            let mut r = self.pow(&((q + Natural::ONE) >> 1));
+            while t != one {
+                // least i with t^(2^i) = 1
+                let mut i = 0;
+                let mut t2 = t.clone();
+                while t2 != one {
+                    t2 = &t2 * &t2;
+                    i += 1;
+                    if i == m {
+                        return None;
+                    }
+                }
+                let mut b = c;
+                for _ in 0..(m - i - 1) {
+                    b = &b * &b;
+                }
+                r *= &b;
+                c = &b * &b;
+                t *= &c;
+                m = i;
+            }
+            r
+        };
+
+        if &root * &root == *self { Some(root) } else { None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ZnRing;
+
+    fn check_sqrt(ring: &ZnRing, a: u64) -> bool {
+        let a = ring.new(Natural::from(a));
+        match a.sqrt() {
+            Some(r) => {
+                assert_eq!(&r * &r, a);
+                true
+            }
+            None => false,
+        }
+    }
+
+    #[test]
+    fn test_sqrt_mod_7() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let r = ring.new(Natural::from(2u32)).sqrt().unwrap();
+        assert!(*r.value() == 3u32 || *r.value() == 4u32);
+        assert!(ring.new(Natural::from(3u32)).sqrt().is_none());
+        assert_eq!(*ring.new(Natural::from(0u32)).sqrt().unwrap().value(), 0u32);
+    }
+
+    #[test]
+    fn test_sqrt_composite() {
+        // no Jacobi symbol mod 9 is -1, and the order of 5 mod 21 is 6
+        for n in [9u64, 21, 25, 49, 15, 91] {
+            let ring = ZnRing::init(Natural::from(n));
+            for a in 1..n {
+                assert!(ring.new(Natural::from(a)).sqrt().is_none(), "{} mod {}", a, n);
+            }
+            assert_eq!(*ring.new(Natural::from(0u32)).sqrt().unwrap().value(), 0u32);
+        }
+        assert!(ZnRing::init(Natural::from(9u32)).new(Natural::from(1u32)).sqrt().is_none());
+        assert!(ZnRing::init(Natural::from(21u32)).new(Natural::from(5u32)).sqrt().is_none());
+    }
+
+    #[test]
+    fn test_sqrt_residues() {
+        // 13 = 1 mod 4 and 41 = 1 mod 8 use Tonelli-Shanks
+        for p in [3u64, 5, 7, 13, 17, 41, 97, 257] {
+            let ring = ZnRing::init(Natural::from(p));
+            let mut residues = 0;
+            for a in 1..p {
+                if check_sqrt(&ring, a) {
+                    residues += 1;
+                }
+            }
+            assert_eq!(residues, (p - 1) / 2, "{}", p);
+        }
+    }
+
+    #[test]
+    fn test_sqrt_large_prime() {
+        // p - 1 is divisible by a large power of two for p = 3 * 2^30 + 1
+        let p = Natural::from(3u64 * (1 << 30) + 1);
+        let ring = ZnRing::init(p);
+        for a in [2u64, 5, 10, 123456789] {
+            let x = ring.new(Natural::from(a));
+            let sq = &x * &x;
+            let r = sq.sqrt().unwrap();
+            assert!(r == x || r == -&x);
+        }
+
+        let ring = ZnRing::init_montgomery((Natural::ONE << 127u64) - Natural::ONE);
+        let x = ring.new(Natural::from(987654321u64));
+        let r = (&x * &x).sqrt().unwrap();
+        assert!(r == x || r == -&x);
+    }
+
+    #[test]
+    fn test_sqrt_two() {
+        let ring = ZnRing::init(Natural::from(2u32));
+        assert!(check_sqrt(&ring, 0));
+        assert!(check_sqrt(&ring, 1));
+    }
+}
@@ -33,7 +33,7 @@ use crate::{ZnElem, check_moduli};
 /// let a = zn.new(Natural::from(2u32));
 /// let b = zn.new(Natural::from(5u32));
 /// let result = a - b; // (2 - 5) mod 7 = 4
-/// assert_eq!(result.value(), Natural::from(4u32));
+/// assert_eq!(*result.value(), Natural::from(4u32));
 /// ```
 impl Sub for ZnElem {
     type Output = ZnElem;
@@ -134,4 +134,41 @@ impl SubAssign<&ZnElem> for ZnElem {
             self.value = &self.ctx.modulus - (&rhs.value - &self.value);
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ZnRing;
+    use malachite::Natural;
+
+    #[test]
+    fn test_sub_wraparound() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let a = ring.new(Natural::from(2u32));
+        let b = ring.new(Natural::from(5u32));
+        let expected = Natural::from(4u32);
+
+        assert_eq!(*(&a - &b).value(), expected);
+        assert_eq!(*(a.clone() - &b).value(), expected);
+        assert_eq!(*(&a - b.clone()).value(), expected);
+        assert_eq!(*(a.clone() - b.clone()).value(), expected);
+
+        let mut c = a.clone();
+        c -= &b;
+        assert_eq!(*c.value(), expected);
+        let mut c = a.clone();
+        c -= b.clone();
+        assert_eq!(*c.value(), expected);
+
+        assert_eq!(*(&b - &a).value(), Natural::from(3u32));
+        assert_eq!(*(&a - &a).value(), Natural::from(0u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "different rings")]
+    fn test_sub_mismatched_moduli() {
+        let a = ZnRing::init(Natural::from(7u32)).new(Natural::from(1u32));
+        let b = ZnRing::init(Natural::from(11u32)).new(Natural::from(1u32));
+        let _ = &a - &b;
+    }
+}
@@ -1,11 +1,24 @@
-use malachite::Natural;
-use malachite::base::num::arithmetic::traits::ModMulPrecomputed;
+use malachite::{Integer, Natural};
+use malachite::base::num::arithmetic::traits::{
+    IsPowerOf2, Mod, ModInverse, ModMulPrecomputed, ModMulPrecomputedAssign,
+    ModPowerOf2, ModPowerOf2Assign, ModPowerOf2Mul, ModPowerOf2MulAssign,
+    Parity, PowerOf2,
+};
+use malachite::base::num::basic::traits::One;
+use malachite::base::num::logic::traits::SignificantBits;
 use malachite::natural::arithmetic::mod_mul::ModMulData;
 use malachite::natural::random::get_random_natural_less_than;
-use malachite::base::num::random::{RandomPrimitiveInts, HasRandomPrimitiveInts};
+use malachite::base::num::random::{RandomPrimitiveInts, HasRandomPrimitiveInts, random_primitive_ints};
+use malachite::base::random::{Seed, EXAMPLE_SEED};
 
+use std::borrow::Cow;
+use std::str::FromStr;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
+use stalagmite_base::StalagmiteError;
+use stalagmite_base::LIMB_BITS;
 use stalagmite_base::traits::{
     Parent, Element,
     Ring, RingElement,
@@ -14,7 +27,7 @@ use stalagmite_base::traits::{
 #[macro_export]
 macro_rules! check_moduli {
     ($a:expr, $b:expr) => {
-        if $a.modulus() != $b.modulus() {
+        if $a.context() != $b.context() {
             panic!(
                 "Cannot perform operation on elements from different rings: Zn({}) and Zn({})", 
                 $a.modulus(), $b.modulus()
@@ -23,16 +36,47 @@ macro_rules! check_moduli {
     };
 }
 
-#[derive(Debug, Clone, Hash)]
+// How elements of the ring are represented and reduced. A modulus 2^k only
+// needs the low k bits to be kept, so no division or `ModMulData` is required.
+// In Montgomery form a value x is stored as xR mod n for R = 2^bits.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub(crate) enum ZnRepr {
+    Standard,
+    PowerOf2(u64),
+    Montgomery(MontgomeryData),
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub(crate) struct MontgomeryData {
+    // R = 2^bits > n
+    bits: u64,
+    // -n^(-1) mod R
+    n_prime: Natural,
+    // R^2 mod n, used to convert into Montgomery form
+    r2: Natural,
+}
+
+#[derive(Debug, Clone)]
 pub(crate) struct IntegerModContext {
     modulus: Natural,
-    mod_mul_data: ModMulData,
+    // Only computed for the standard representation; powers of two and
+    // Montgomery form multiply without it.
+    mod_mul_data: Option<ModMulData>,
+    repr: ZnRepr,
 }
 
 impl PartialEq for IntegerModContext {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        self.modulus == other.modulus
+        self.modulus == other.modulus && self.repr == other.repr
+    }
+}
+
+impl Hash for IntegerModContext {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.modulus.hash(state);
+        self.repr.hash(state);
     }
 }
 
@@ -74,19 +118,134 @@ impl std::fmt::Display for ZnRing {
 impl std::fmt::Display for ZnElem {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value)
+        write!(f, "{}", self.value())
+    }
+}
+
+impl PartialOrd for ZnElem {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Elements of the same ring are ordered by their values in `0..n`, whatever
+/// the internal representation. Panics if the elements belong to different
+/// rings.
+impl Ord for ZnElem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        check_moduli!(self, other);
+        match self.ctx.repr {
+            ZnRepr::Montgomery(_) => self.value().cmp(&other.value()),
+            _ => self.value.cmp(&other.value),
+        }
     }
 }
 
 impl ZnContext {
     pub fn new(modulus: Natural) -> Self {
-        let mod_mul_data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(&modulus);
-        Self { modulus, mod_mul_data }
+        if modulus.is_power_of_2() {
+            let repr = ZnRepr::PowerOf2(modulus.significant_bits() - 1);
+            return Self { modulus, mod_mul_data: None, repr };
+        }
+        let mod_mul_data = Some(ModMulPrecomputed::<Natural>::precompute_mod_mul_data(&modulus));
+        Self { modulus, mod_mul_data, repr: ZnRepr::Standard }
+    }
+
+    /// Create a context storing elements in Montgomery form. The modulus must
+    /// be odd.
+    pub(crate) fn new_montgomery(modulus: Natural) -> Self {
+        assert!(modulus.odd(), "Montgomery representation requires an odd modulus");
+        let bits = modulus.limb_count() * LIMB_BITS as u64;
+        let r = Natural::power_of_2(bits);
+        let n_inv = (&modulus).mod_inverse(&r).unwrap();
+        let n_prime = (&r - n_inv).mod_power_of_2(bits);
+        let r2 = Natural::power_of_2(2 * bits) % &modulus;
+        let repr = ZnRepr::Montgomery(MontgomeryData { bits, n_prime, r2 });
+        Self { modulus, mod_mul_data: None, repr }
+    }
+
+    // Montgomery reduction: given t < nR return tR^(-1) mod n.
+    fn redc(&self, t: Natural, data: &MontgomeryData) -> Natural {
+        let m = (&t).mod_power_of_2(data.bits).mod_power_of_2_mul(&data.n_prime, data.bits);
+        let mut u = (t + m * &self.modulus) >> data.bits;
+        if u >= self.modulus {
+            u -= &self.modulus;
+        }
+        u
+    }
+
+    /// Convert a standard value into the internal representation.
+    pub(crate) fn to_repr(&self, value: Natural) -> Natural {
+        match &self.repr {
+            ZnRepr::Montgomery(data) => self.redc((value % &self.modulus) * &data.r2, data),
+            _ => value,
+        }
+    }
+
+    /// Convert an internal value into the standard representation.
+    pub(crate) fn to_standard<'a>(&self, value: &'a Natural) -> Cow<'a, Natural> {
+        match &self.repr {
+            ZnRepr::Montgomery(data) => Cow::Owned(self.redc(value.clone(), data)),
+            _ => Cow::Borrowed(value),
+        }
     }
 
+    /// The internal representation of one.
     #[inline]
-    pub fn mod_mul_data(&self) -> &ModMulData {
-        &self.mod_mul_data
+    pub(crate) fn one(&self) -> Natural {
+        self.to_repr(self.reduce(Natural::ONE))
+    }
+
+    /// Reduce a value modulo n.
+    #[inline]
+    pub(crate) fn reduce(&self, value: Natural) -> Natural {
+        match self.repr {
+            ZnRepr::PowerOf2(k) => value.mod_power_of_2(k),
+            _ => value % &self.modulus,
+        }
+    }
+
+    /// Reduce a value modulo n in place.
+    #[inline]
+    pub(crate) fn reduce_assign(&self, value: &mut Natural) {
+        match self.repr {
+            ZnRepr::PowerOf2(k) => value.mod_power_of_2_assign(k),
+            _ => *value %= &self.modulus,
+        }
+    }
+
+    /// Multiply two reduced values modulo n.
+    #[inline]
+    pub(crate) fn mul(&self, a: &Natural, b: &Natural) -> Natural {
+        match &self.repr {
+            ZnRepr::PowerOf2(k) => a.mod_power_of_2_mul(b, *k),
+            ZnRepr::Standard => a.mod_mul_precomputed(b, &self.modulus, self.standard_mod_mul_data()),
+            ZnRepr::Montgomery(data) => self.redc(a * b, data),
+        }
+    }
+
+    /// Multiply a reduced value by another one modulo n in place.
+    #[inline]
+    pub(crate) fn mul_assign(&self, a: &mut Natural, b: &Natural) {
+        match &self.repr {
+            ZnRepr::PowerOf2(k) => a.mod_power_of_2_mul_assign(b, *k),
+            ZnRepr::Standard => a.mod_mul_precomputed_assign(b, &self.modulus, self.standard_mod_mul_data()),
+            ZnRepr::Montgomery(data) => *a = self.redc(&*a * b, data),
+        }
+    }
+
+    // The standard representation always stores its `ModMulData`.
+    #[inline]
+    fn standard_mod_mul_data(&self) -> &ModMulData {
+        self.mod_mul_data.as_ref().unwrap()
+    }
+
+    /// Precomputed data for multiplying modulo n, or `None` if the modulus is
+    /// a power of two or elements are stored in Montgomery form.
+    #[inline]
+    pub fn mod_mul_data(&self) -> Option<&ModMulData> {
+        self.mod_mul_data.as_ref()
     }
 
     #[inline]
@@ -96,14 +255,63 @@ impl ZnContext {
 }
 
 impl ZnRing {
+    /// Initialize the ring Z/nZ.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero; see [`ZnRing::try_init`].
     #[inline]
     pub fn init(modulus: Natural) -> Self {
-        Self { ctx: Rc::new(IntegerModContext::new(modulus)) }
+        Self::try_init(modulus).unwrap_or_else(|e| panic!("{}", e))
     }
 
+    /// Initialize the ring Z/nZ, returning `StalagmiteError::InvalidModulus`
+    /// if the modulus is zero. A modulus of one gives the zero ring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_zn::ZnRing;
+    /// use malachite::Natural;
+    ///
+    /// assert!(ZnRing::try_init(Natural::from(0u32)).is_err());
+    /// assert_eq!(ZnRing::try_init(Natural::from(7u32)).unwrap().modulus(), &Natural::from(7u32));
+    /// ```
+    pub fn try_init(modulus: Natural) -> Result<Self, StalagmiteError> {
+        if modulus == 0u32 {
+            return Err(StalagmiteError::InvalidModulus(modulus));
+        }
+        Ok(Self { ctx: Rc::new(IntegerModContext::new(modulus)) })
+    }
+
+    /// Initialize the ring with elements stored in Montgomery form, which
+    /// avoids divisions when multiplying. Arithmetic results are identical to
+    /// the standard representation. Montgomery form requires an odd modulus,
+    /// so for even moduli this is the same as [`ZnRing::init`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_zn::ZnRing;
+    /// use malachite::Natural;
+    ///
+    /// let zn = ZnRing::init_montgomery(Natural::from(7u32));
+    /// let a = zn.new(Natural::from(3u32));
+    /// let b = zn.new(Natural::from(5u32));
+    /// assert_eq!(*(a * b).value(), Natural::from(1u32));
+    /// ```
+    pub fn init_montgomery(modulus: Natural) -> Self {
+        if modulus.even() {
+            return Self::init(modulus);
+        }
+        Self { ctx: Rc::new(IntegerModContext::new_montgomery(modulus)) }
+    }
+
+    /// Create the element of the ring represented by `value`, which is
+    /// reduced modulo n.
     #[inline]
     pub fn new(&self, value: Natural) -> ZnElem {
-        ZnElem::from_ctx(value, self.ctx.clone())
+        ZnElem::from_ctx(self.ctx.to_repr(self.ctx.reduce(value)), self.ctx.clone())
     }
 
     #[inline]
@@ -121,8 +329,10 @@ impl ZnRing {
         self.ctx.modulus()
     }
 
+    /// Precomputed data for multiplying modulo n, or `None` if the modulus is
+    /// a power of two or elements are stored in Montgomery form.
     #[inline]
-    pub fn mod_mul_data(&self) -> &ModMulData {
+    pub fn mod_mul_data(&self) -> Option<&ModMulData> {
         self.ctx.mod_mul_data()
     }
 
@@ -134,12 +344,63 @@ impl ZnRing {
     pub fn random_elements(&self, rng: &mut RandomPrimitiveInts<u64>, count: usize) -> Vec<ZnElem> {
         (0..count).map(|_| self.random_element(rng)).collect()
     }
+
+    // Derive a malachite seed from a u64 by forking the example seed.
+    fn seed_from_u64(seed: u64) -> Seed {
+        EXAMPLE_SEED.fork(&seed.to_string())
+    }
+
+    /// Generate a uniformly random element using an RNG constructed from
+    /// `seed`. The same seed always gives the same element.
+    pub fn random_element_from_seed(&self, seed: u64) -> ZnElem {
+        let mut rng = random_primitive_ints(Self::seed_from_u64(seed));
+        self.random_element(&mut rng)
+    }
+
+    /// Generate `count` uniformly random elements using an RNG constructed
+    /// from `seed`. The same seed always gives the same sequence.
+    pub fn random_elements_from_seed(&self, seed: u64, count: usize) -> Vec<ZnElem> {
+        let mut rng = random_primitive_ints(Self::seed_from_u64(seed));
+        self.random_elements(&mut rng, count)
+    }
 }
 
 impl ZnElem {
+    /// Create the element `value mod modulus` in a new ring. Prefer
+    /// [`ZnRing::new`] to create several elements of the same ring.
     #[inline]
     pub fn new(value: Natural, modulus: Natural) -> Self {
-        Self { value, ctx: Rc::new(IntegerModContext::new(modulus)) }
+        let ctx = IntegerModContext::new(modulus);
+        Self { value: ctx.reduce(value), ctx: Rc::new(ctx) }
+    }
+
+    /// Parse a decimal integer, which may be negative, and reduce it modulo
+    /// `modulus` to create an element of a new ring.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StalagmiteError::ParseError` if `value` is not an integer and
+    /// `StalagmiteError::InvalidModulus` if the modulus is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stalagmite_zn::ZnElem;
+    /// use malachite::Natural;
+    ///
+    /// let a = ZnElem::parse("-1", &Natural::from(7u32)).unwrap();
+    /// assert_eq!(*a.value(), Natural::from(6u32));
+    /// assert!(ZnElem::parse("1.5", &Natural::from(7u32)).is_err());
+    /// ```
+    pub fn parse(value: &str, modulus: &Natural) -> Result<Self, StalagmiteError> {
+        if *modulus == 0u32 {
+            return Err(StalagmiteError::InvalidModulus(modulus.clone()));
+        }
+        let value = Integer::from_str(value).map_err(|_| {
+            StalagmiteError::ParseError(format!("invalid integer '{}'", value))
+        })?;
+        let value = Natural::try_from(value.mod_op(Integer::from(modulus))).unwrap();
+        Ok(Self::new(value, modulus.clone()))
     }
 
     #[inline]
@@ -152,24 +413,39 @@ impl ZnElem {
         &self.ctx
     }
 
+    /// The value of the element in `0..n`. It is borrowed unless the ring
+    /// stores elements in Montgomery form, where it is converted.
     #[inline]
-    pub fn value(&self) -> &Natural {
-        &self.value
+    pub fn value(&self) -> Cow<'_, Natural> {
+        self.ctx.to_standard(&self.value)
     }
 
     #[inline]
-    pub fn as_natural(&self) -> &Natural {
+    pub fn as_natural(&self) -> Cow<'_, Natural> {
+        self.value()
+    }
+
+    /// The stored representation of the element, which is the Montgomery form
+    /// `xR mod n` in a ring created with [`ZnRing::init_montgomery`] and the
+    /// value in `0..n` otherwise.
+    #[inline]
+    pub fn repr_value(&self) -> &Natural {
         &self.value
     }
 
+    /// The value of the element in `0..n`.
     #[inline]
-    pub fn to_natural(self) -> Natural {
-        self.value
+    pub fn to_natural(&self) -> Natural {
+        self.value().into_owned()
     }
 
+    /// The value of the element in `0..n`, consuming the element.
     #[inline]
-    pub fn into_natural(&self) -> Natural {
-        self.value.clone()
+    pub fn into_natural(self) -> Natural {
+        match self.ctx.to_standard(&self.value) {
+            Cow::Owned(value) => value,
+            Cow::Borrowed(_) => self.value,
+        }
     }
 
     #[inline]
@@ -177,12 +453,43 @@ impl ZnElem {
         self.ctx.modulus()
     }
 
+    /// Precomputed data for multiplying modulo n, or `None` if the modulus is
+    /// a power of two or elements are stored in Montgomery form.
     #[inline]
-    pub fn mod_mul_data(&self) -> &ModMulData {
+    pub fn mod_mul_data(&self) -> Option<&ModMulData> {
         self.ctx.mod_mul_data()
     }
 }
 
+// Serialize the value alongside the modulus so the context can be rebuilt.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ZnElem {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ZnElem", 2)?;
+        state.serialize_field("value", &*self.value())?;
+        state.serialize_field("modulus", self.modulus())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ZnElem {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct RawZnElem {
+            value: Natural,
+            modulus: Natural,
+        }
+
+        let raw = RawZnElem::deserialize(deserializer)?;
+        if raw.modulus == 0u32 {
+            return Err(serde::de::Error::custom(StalagmiteError::InvalidModulus(raw.modulus)));
+        }
+        Ok(ZnElem::new(raw.value, raw.modulus))
+    }
+}
+
 impl Parent for ZnRing {
     type Element = ZnElem;
 }
@@ -204,3 +511,250 @@ impl RingElement for ZnElem {
 }
 
 pub mod arithmetic;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_from_seed_deterministic() {
+        let ring = ZnRing::init(Natural::from(1000003u32));
+        assert_eq!(ring.random_element_from_seed(42), ring.random_element_from_seed(42));
+        assert_eq!(
+            ring.random_elements_from_seed(42, 20),
+            ring.random_elements_from_seed(42, 20)
+        );
+        assert_ne!(
+            ring.random_elements_from_seed(42, 20),
+            ring.random_elements_from_seed(43, 20)
+        );
+
+        let elements = ring.random_elements_from_seed(7, 100);
+        assert_eq!(elements.len(), 100);
+        assert!(elements.iter().all(|x| *x.value() < *ring.modulus()));
+        assert_eq!(elements[0], ring.random_element_from_seed(7));
+    }
+
+    #[test]
+    fn test_random_from_seed_uniform() {
+        let ring = ZnRing::init(Natural::from(5u32));
+        let mut counts = [0usize; 5];
+        for x in ring.random_elements_from_seed(0, 5000) {
+            counts[u64::try_from(&*x.value()).unwrap() as usize] += 1;
+        }
+        // Each residue expected 1000 times.
+        assert!(counts.iter().all(|&c| c > 850 && c < 1150), "{:?}", counts);
+    }
+
+    #[test]
+    fn test_try_init() {
+        assert_eq!(
+            ZnRing::try_init(Natural::from(0u32)),
+            Err(StalagmiteError::InvalidModulus(Natural::from(0u32)))
+        );
+        let ring = ZnRing::try_init(Natural::from(7u32)).unwrap();
+        assert_eq!(ring, ZnRing::init(Natural::from(7u32)));
+        assert!(ZnRing::try_init(Natural::from(1u32)).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid modulus: 0")]
+    fn test_init_zero() {
+        ZnRing::init(Natural::from(0u32));
+    }
+
+    #[test]
+    fn test_new_reduces() {
+        let ring = ZnRing::init(Natural::from(7u32));
+        let a = ring.new(Natural::from(10u32));
+        assert_eq!(*a.value(), 3u32);
+        assert_eq!(a, ring.new(Natural::from(3u32)));
+        assert_eq!(a.to_string(), "3");
+
+        let b = ZnElem::new(Natural::from(10u32), Natural::from(7u32));
+        assert_eq!(*b.value(), 3u32);
+        assert_eq!(b, ZnElem::new(Natural::from(3u32), Natural::from(7u32)));
+
+        let ring = ZnRing::init(Natural::from(16u32));
+        assert_eq!(ring.new(Natural::from(35u32)), ring.new(Natural::from(3u32)));
+        let ring = ZnRing::init_montgomery(Natural::from(101u32));
+        assert_eq!(ring.new(Natural::from(1000u32)), ring.new(Natural::from(91u32)));
+    }
+
+    #[test]
+    fn test_parse() {
+        let seven = Natural::from(7u32);
+        assert_eq!(ZnElem::parse("-1", &seven).unwrap(), ZnElem::new(Natural::from(6u32), seven.clone()));
+        assert_eq!(*ZnElem::parse("-15", &seven).unwrap().value(), 6u32);
+        assert_eq!(*ZnElem::parse("100", &seven).unwrap().value(), 2u32);
+        assert_eq!(*ZnElem::parse("0", &seven).unwrap().value(), 0u32);
+        assert_eq!(ZnElem::parse("123456789012345678901234567890", &seven).unwrap().modulus(), &seven);
+
+        for s in ["", "abc", "1.5", "--1", "0x10", " 3"] {
+            assert!(matches!(ZnElem::parse(s, &seven), Err(StalagmiteError::ParseError(_))), "{}", s);
+        }
+        assert_eq!(
+            ZnElem::parse("3", &Natural::from(0u32)),
+            Err(StalagmiteError::InvalidModulus(Natural::from(0u32)))
+        );
+    }
+
+    #[test]
+    fn test_ord() {
+        let ring = ZnRing::init(Natural::from(11u32));
+        let mut elems: Vec<ZnElem> =
+            [7u32, 3, 10, 0, 3, 14].iter().map(|&v| ring.new(Natural::from(v))).collect();
+        elems.sort();
+        let values: Vec<Natural> = elems.iter().map(|e| e.to_natural()).collect();
+        assert_eq!(values, [0u32, 3, 3, 3, 7, 10].map(Natural::from));
+        assert!(ring.new(Natural::from(2u32)) < ring.new(Natural::from(5u32)));
+
+        // Montgomery form orders by value, not by internal representation
+        let ring = ZnRing::init_montgomery(Natural::from(101u32));
+        let mut elems: Vec<ZnElem> = (0..101u32).rev().map(|v| ring.new(Natural::from(v))).collect();
+        elems.sort();
+        assert!(elems.iter().enumerate().all(|(i, e)| *e.value() == i));
+
+        let mut map = std::collections::BTreeMap::new();
+        map.insert(ring.new(Natural::from(50u32)), "b");
+        map.insert(ring.new(Natural::from(7u32)), "a");
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "different rings")]
+    fn test_ord_mismatched_moduli() {
+        let a = ZnRing::init(Natural::from(7u32)).new(Natural::from(1u32));
+        let b = ZnRing::init(Natural::from(11u32)).new(Natural::from(2u32));
+        let _ = a < b;
+    }
+
+    #[test]
+    fn test_power_of_2_detection() {
+        assert_eq!(ZnRing::init(Natural::from(1024u32)).context().repr, ZnRepr::PowerOf2(10));
+        assert_eq!(ZnRing::init(Natural::from(2u32)).context().repr, ZnRepr::PowerOf2(1));
+        assert_eq!(ZnRing::init(Natural::from(1u32)).context().repr, ZnRepr::PowerOf2(0));
+        assert_eq!(ZnRing::init(Natural::from(1000u32)).context().repr, ZnRepr::Standard);
+        assert_eq!(ZnRing::init(Natural::from(1023u32)).context().repr, ZnRepr::Standard);
+        assert!(ZnRing::init(Natural::from(1024u32)).mod_mul_data().is_none());
+    }
+
+    #[test]
+    fn test_power_of_2_matches_generic() {
+        let n = 1024u32;
+        let ring = ZnRing::init(Natural::from(n));
+        let values = [0u32, 1, 2, 511, 512, 777, 1000, 1023];
+        for &a in values.iter() {
+            for &b in values.iter() {
+                let x = ring.new(Natural::from(a));
+                let y = ring.new(Natural::from(b));
+                assert_eq!(*(&x + &y).value(), Natural::from((a + b) % n));
+                assert_eq!(*(&x - &y).value(), Natural::from((a + n - b) % n));
+                assert_eq!(*(&x * &y).value(), Natural::from((a as u64 * b as u64 % n as u64) as u32));
+                assert_eq!(*(-&x).value(), Natural::from((n - a) % n));
+
+                let mut z = x.clone();
+                z += &y;
+                z *= &y;
+                let expected = ((a + b) % n) as u64 * b as u64 % n as u64;
+                assert_eq!(*z.value(), Natural::from(expected));
+            }
+        }
+
+        // Large power of two.
+        let n = Natural::from(1u32) << 200u32;
+        let ring = ZnRing::init(n.clone());
+        let a = ring.new(&n - Natural::from(3u32));
+        let b = ring.new(&n - Natural::from(5u32));
+        assert_eq!(*(&a * &b).value(), Natural::from(15u32));
+        assert_eq!(*(&a + &b).value(), &n - Natural::from(8u32));
+        assert_eq!(*a.pow_u64(2).value(), Natural::from(9u32));
+    }
+
+    #[test]
+    fn test_montgomery_matches_standard() {
+        for n in [7u64, 1000003, 18446744073709551557] {
+            let standard = ZnRing::init(Natural::from(n));
+            let montgomery = ZnRing::init_montgomery(Natural::from(n));
+            assert!(matches!(montgomery.context().repr, ZnRepr::Montgomery(_)));
+            assert!(montgomery.mod_mul_data().is_none());
+            assert!(standard.mod_mul_data().is_some());
+
+            let values = [1u64, 2, 3, 12345, n - 1, n / 2];
+            let mut acc_s = standard.new(Natural::from(1u32));
+            let mut acc_m = montgomery.new(Natural::from(1u32));
+            for &v in values.iter() {
+                let a_s = standard.new(Natural::from(v % n));
+                let a_m = montgomery.new(Natural::from(v % n));
+                assert_eq!(a_m.value(), a_s.value());
+                assert_ne!(a_m.repr_value(), a_s.repr_value());
+                assert_eq!(a_m.to_string(), a_s.to_string());
+
+                acc_s *= &a_s;
+                acc_m *= &a_m;
+                assert_eq!(acc_m.value(), acc_s.value());
+
+                assert_eq!((&acc_m * &a_m).value(), (&acc_s * &a_s).value());
+                assert_eq!((&acc_m + &a_m).value(), (&acc_s + &a_s).value());
+                assert_eq!((&acc_m - &a_m).value(), (&acc_s - &a_s).value());
+                assert_eq!((-&a_m).value(), (-&a_s).value());
+                assert_eq!(a_m.pow_u64(v).value(), a_s.pow_u64(v).value());
+                assert_eq!(a_m.inverse().map(|x| x.to_natural()), a_s.inverse().map(|x| x.to_natural()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_montgomery_multi_limb() {
+        // 2^127 - 1 is prime.
+        let n = (Natural::from(1u32) << 127u32) - Natural::from(1u32);
+        let standard = ZnRing::init(n.clone());
+        let montgomery = ZnRing::init_montgomery(n.clone());
+        let mut acc_s = standard.new(Natural::from(3u32));
+        let mut acc_m = montgomery.new(Natural::from(3u32));
+        for _ in 0..50 {
+            acc_s = &acc_s * &acc_s + standard.new(Natural::from(1u32));
+            acc_m = &acc_m * &acc_m + montgomery.new(Natural::from(1u32));
+        }
+        assert_eq!(acc_m.value(), acc_s.value());
+        assert_eq!(acc_m.to_natural(), acc_s.to_natural());
+    }
+
+    #[test]
+    fn test_montgomery_even_modulus() {
+        let ring = ZnRing::init_montgomery(Natural::from(1024u32));
+        assert_eq!(ring.context().repr, ZnRepr::PowerOf2(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "different rings")]
+    fn test_montgomery_mismatched_representation() {
+        let a = ZnRing::init(Natural::from(7u32)).new(Natural::from(1u32));
+        let b = ZnRing::init_montgomery(Natural::from(7u32)).new(Natural::from(1u32));
+        let _ = a * b;
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let ring = ZnRing::init(Natural::from(101u32));
+        let a = ring.new(Natural::from(42u32));
+        let json = serde_json::to_string(&a).unwrap();
+        let b: ZnElem = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(b.modulus(), &Natural::from(101u32));
+        assert_eq!(*b.value(), Natural::from(42u32));
+
+        // The rebuilt context supports arithmetic.
+        assert_eq!(*(&b * &b).value(), Natural::from(47u32));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_invalid_modulus() {
+        let value = serde_json::to_string(&Natural::from(3u32)).unwrap();
+        let modulus = serde_json::to_string(&Natural::from(0u32)).unwrap();
+        let json = format!("{{\"value\":{},\"modulus\":{}}}", value, modulus);
+        assert!(serde_json::from_str::<ZnElem>(&json).is_err());
+    }
+}